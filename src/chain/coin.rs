@@ -1,10 +1,11 @@
-use std::{fmt, num::ParseIntError, str::FromStr};
+use std::{fmt, str::FromStr};
 
 use regex::Regex;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use super::error::ChainError;
+use super::{decimal::Decimal, error::ChainError};
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
 pub struct Coin {
@@ -46,16 +47,18 @@ impl TryFrom<cosmrs::proto::cosmos::base::v1beta1::Coin> for Coin {
     fn try_from(coin: cosmrs::proto::cosmos::base::v1beta1::Coin) -> Result<Self, Self::Error> {
         Ok(Self {
             denom: coin.denom.parse()?,
-            amount: coin
-                .amount
-                .parse()
-                .map_err(|e: ParseIntError| ChainError::ProtoDecoding {
-                    message: e.to_string(),
-                })?,
+            amount: parse_amount(&coin.amount)?,
         })
     }
 }
 
+/// Parses a cosmos-sdk `sdk.Int` proto string
+pub(crate) fn parse_amount(amount: &str) -> Result<u128, ChainError> {
+    amount.parse().map_err(|_| ChainError::Amount {
+        value: amount.to_string(),
+    })
+}
+
 impl From<Coin> for cosmrs::proto::cosmos::base::v1beta1::Coin {
     fn from(coin: Coin) -> Self {
         Self {
@@ -65,11 +68,62 @@ impl From<Coin> for cosmrs::proto::cosmos::base::v1beta1::Coin {
     }
 }
 
+/// Coin with a fixed point decimal amount, used for fractional values like staking rewards
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct DecCoin {
+    pub denom: Denom,
+    pub amount: Decimal,
+}
+
+impl fmt::Display for DecCoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<cosmrs::proto::cosmos::base::v1beta1::DecCoin> for DecCoin {
+    type Error = ChainError;
+
+    fn try_from(coin: cosmrs::proto::cosmos::base::v1beta1::DecCoin) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom: coin.denom.parse()?,
+            amount: Decimal::from_proto(&coin.amount)?,
+        })
+    }
+}
+
+impl From<DecCoin> for cosmrs::proto::cosmos::base::v1beta1::DecCoin {
+    fn from(coin: DecCoin) -> Self {
+        Self {
+            denom: coin.denom.into(),
+            amount: coin.amount.to_proto(),
+        }
+    }
+}
+
 #[derive(
     Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
 )]
 pub struct Denom(String);
 
+impl Denom {
+    /// Computes the `ibc/<HASH>` denom of a token from its full denom trace path, ie: `transfer/channel-0/uatom`.
+    /// The hash is the uppercase hex encoded SHA-256 of the path, matching ibc-go's `DenomTrace.IBCDenom()`.
+    pub fn from_ibc_trace(trace: &str) -> Self {
+        let hash = Sha256::digest(trace.as_bytes())
+            .iter()
+            .map(|b| format!("{b:02X}"))
+            .collect::<String>();
+
+        Denom(format!("ibc/{hash}"))
+    }
+
+    /// Returns true if this is an `ibc/<HASH>` voucher denom
+    pub fn is_ibc(&self) -> bool {
+        self.0.starts_with("ibc/")
+    }
+}
+
 impl AsRef<str> for Denom {
     fn as_ref(&self) -> &str {
         self.0.as_ref()
@@ -119,3 +173,20 @@ impl From<Denom> for String {
         d.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Denom;
+
+    #[test]
+    fn test_denom_from_ibc_trace() {
+        let denom = Denom::from_ibc_trace("transfer/channel-0/uatom");
+
+        assert_eq!(
+            denom.as_ref(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+        assert!(denom.is_ibc());
+        assert_eq!(denom.as_ref().parse::<Denom>().unwrap(), denom);
+    }
+}
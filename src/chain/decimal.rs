@@ -0,0 +1,171 @@
+use std::{fmt, str::FromStr};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::error::ChainError;
+
+/// Fixed point decimal with 18 fractional digits, matching the cosmos-sdk `sdk.Dec` type.
+///
+/// Used for values like commission rates, delegator shares and `DecCoin` amounts.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Default,
+    Hash,
+)]
+pub struct Decimal(u128);
+
+impl Decimal {
+    pub const DECIMAL_PLACES: u32 = 18;
+
+    const FRACTIONAL: u128 = 10u128.pow(Self::DECIMAL_PLACES);
+
+    /// Create a decimal from its raw `value * 10^18` representation
+    pub fn from_atomics(atomics: u128) -> Self {
+        Decimal(atomics)
+    }
+
+    /// Create a decimal from a whole number
+    pub fn from_int(value: u128) -> Result<Self, ChainError> {
+        value
+            .checked_mul(Self::FRACTIONAL)
+            .map(Decimal)
+            .ok_or_else(|| ChainError::Decimal {
+                value: value.to_string(),
+            })
+    }
+
+    /// Raw `value * 10^18` representation of this decimal
+    pub fn atomics(self) -> u128 {
+        self.0
+    }
+
+    /// Whole number part of this decimal, rounded down
+    pub fn to_int_floor(self) -> u128 {
+        self.0 / Self::FRACTIONAL
+    }
+
+    /// Parses a decimal from the cosmos-sdk protobuf encoding.
+    ///
+    /// `sdk.Dec` fields are serialized as their atomics without a decimal point (ie: "500000000000000000" == 0.5),
+    /// but some newer protos use plain decimal strings (ie: "0.5"), so both are accepted.
+    pub(crate) fn from_proto(s: &str) -> Result<Self, ChainError> {
+        if s.contains('.') {
+            return s.parse();
+        }
+
+        if s.is_empty() {
+            return Ok(Decimal::default());
+        }
+
+        Ok(Decimal(s.parse().map_err(|_| ChainError::Decimal {
+            value: s.to_string(),
+        })?))
+    }
+
+    /// Serializes the decimal into the cosmos-sdk `sdk.Dec` protobuf encoding
+    pub(crate) fn to_proto(self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = self.0 / Self::FRACTIONAL;
+        let fractional = self.0 % Self::FRACTIONAL;
+
+        if fractional == 0 {
+            write!(f, "{whole}")
+        } else {
+            let fractional = format!("{:0>18}", fractional);
+            write!(f, "{whole}.{}", fractional.trim_end_matches('0'))
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ChainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ChainError::Decimal {
+            value: s.to_string(),
+        };
+
+        let (whole, fractional) = s.split_once('.').unwrap_or((s, ""));
+
+        if whole.is_empty() || fractional.len() > Self::DECIMAL_PLACES as usize {
+            return Err(err());
+        }
+
+        let whole: u128 = whole.parse().map_err(|_| err())?;
+
+        let fractional: u128 = if fractional.is_empty() {
+            0
+        } else {
+            format!("{:0<18}", fractional).parse().map_err(|_| err())?
+        };
+
+        whole
+            .checked_mul(Self::FRACTIONAL)
+            .and_then(|w| w.checked_add(fractional))
+            .map(Decimal)
+            .ok_or_else(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Decimal;
+
+    #[test]
+    fn test_decimal_parse() {
+        assert_eq!(
+            "0.05".parse::<Decimal>().unwrap(),
+            Decimal::from_atomics(50_000_000_000_000_000)
+        );
+        assert_eq!(
+            "12".parse::<Decimal>().unwrap(),
+            Decimal::from_int(12).unwrap()
+        );
+        assert_eq!(
+            "1.000000000000000001".parse::<Decimal>().unwrap(),
+            Decimal::from_atomics(1_000_000_000_000_000_001)
+        );
+
+        assert!("".parse::<Decimal>().is_err());
+        assert!(".5".parse::<Decimal>().is_err());
+        assert!("0.0000000000000000001".parse::<Decimal>().is_err());
+        assert!("-1".parse::<Decimal>().is_err());
+    }
+
+    #[test]
+    fn test_decimal_display() {
+        assert_eq!(
+            Decimal::from_atomics(50_000_000_000_000_000).to_string(),
+            "0.05"
+        );
+        assert_eq!(Decimal::from_int(7).unwrap().to_string(), "7");
+        assert_eq!(
+            Decimal::from_atomics(1_000_000_000_000_000_001).to_string(),
+            "1.000000000000000001"
+        );
+    }
+
+    #[test]
+    fn test_decimal_proto() {
+        let d = Decimal::from_proto("100000000000000000").unwrap();
+        assert_eq!(d.to_string(), "0.1");
+        assert_eq!(d.to_proto(), "100000000000000000");
+
+        assert_eq!(Decimal::from_proto("0.1").unwrap(), d);
+    }
+}
@@ -1,5 +1,10 @@
 use cosmrs::proto::prost::{DecodeError, EncodeError};
+use cosmrs::rpc::error::ErrorDetail as RPCErrorDetail;
 use cosmrs::ErrorReport;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 #[cfg(feature = "os_keyring")]
@@ -7,6 +12,7 @@ pub use keyring::Error as KeyringError;
 
 pub use cosmrs::rpc::Error as TendermintRPCError;
 pub use cosmrs::tendermint::Error as TendermintError;
+pub use reqwest::Error as CosmosRESTError;
 pub use tonic::transport::Error as CosmosGRPCError;
 
 use super::response::ChainResponse;
@@ -16,12 +22,22 @@ pub enum ChainError {
     #[error("invalid denomination: {name:?}")]
     Denom { name: String },
 
+    #[error("invalid decimal: {value:?}")]
+    Decimal { value: String },
+
+    #[error("invalid integer amount: {value:?}")]
+    Amount { value: String },
+
     #[error("invalid chainId: {chain_id:?}")]
     ChainId { chain_id: String },
 
     #[error("api endpoint is not configured {api_type:?}")]
     MissingApiEndpoint { api_type: String },
 
+    /// The configured node doesn't serve an endpoint this client relies on, eg. because its cosmos-sdk version is too old
+    #[error("api endpoint {endpoint:?} is not supported by the node: {message}")]
+    UnsupportedApiEndpoint { endpoint: String, message: String },
+
     #[error("invalid mnemonic")]
     Mnemonic,
 
@@ -43,6 +59,17 @@ pub enum ChainError {
     #[error("invalid cosmos msg sent to simulate endpoint")]
     Simulation,
 
+    /// The merkle proof of a verified query doesn't prove the returned value against the header's app hash
+    #[error("proof verification failed: {message}")]
+    ProofVerification { message: String },
+
+    /// The app hash of `height` is only committed in the header of the next block, which doesn't exist yet
+    #[error("state at height {height} can't be verified until block {} is committed, latest block is {latest}", .height + 1)]
+    UnverifiableHeight { height: u64, latest: u64 },
+
+    #[error("tx {tx_hash:?} was broadcast but not found on chain after {timeout:?}")]
+    TxPollTimeout { tx_hash: String, timeout: Duration },
+
     #[cfg(feature = "os_keyring")]
     #[error(transparent)]
     Keyring(#[from] KeyringError),
@@ -50,6 +77,22 @@ pub enum ChainError {
     #[error("CosmosSDK error: {res:?}")]
     CosmosSdk { res: ChainResponse },
 
+    /// The node or a proxy in front of it is unavailable or timed out.
+    /// Returned by `FailoverClient` for gRPC `Unavailable` / `DeadlineExceeded` statuses and http 502 / 503 / 504
+    /// responses, which the single node clients return as `ChainError::CosmosSdk`
+    #[error("node unavailable: {message:?}")]
+    Unavailable { message: String },
+
+    /// Error recorded by a `RecordingClient` that is replayed by `ReplayClient`
+    #[error("recorded error: {message}")]
+    Recorded { message: String },
+
+    #[error("no recorded response left for {request}")]
+    UnmatchedRequest { request: String },
+
+    #[error("invalid client fixture: {message:?}")]
+    Fixture { message: String },
+
     #[error("Tendermint error")]
     Tendermint(#[from] TendermintError),
 
@@ -60,6 +103,10 @@ pub enum ChainError {
     /// Cosmos gRPC client errors
     #[error(transparent)]
     GRPC(#[from] CosmosGRPCError),
+
+    /// Cosmos REST client errors
+    #[error(transparent)]
+    REST(#[from] CosmosRESTError),
 }
 
 impl ChainError {
@@ -75,6 +122,12 @@ impl ChainError {
         }
     }
 
+    pub(crate) fn proto_decoding(e: ErrorReport) -> ChainError {
+        ChainError::ProtoDecoding {
+            message: e.to_string(),
+        }
+    }
+
     pub(crate) fn prost_proto_encoding(e: EncodeError) -> ChainError {
         ChainError::ProtoEncoding {
             message: e.to_string(),
@@ -87,9 +140,209 @@ impl ChainError {
         }
     }
 
+    pub(crate) fn proto_time(e: impl std::fmt::Display) -> ChainError {
+        ChainError::ProtoDecoding {
+            message: e.to_string(),
+        }
+    }
+
+    pub(crate) fn fixture(e: impl std::fmt::Display) -> ChainError {
+        ChainError::Fixture {
+            message: e.to_string(),
+        }
+    }
+
     pub(crate) fn tonic_status(e: tonic::Status) -> ChainError {
         ChainError::CosmosSdk { res: e.into() }
     }
+
+    /// Returns true if the request never got an answer from the node,
+    /// so sending it to another node might succeed
+    pub fn is_transport_error(&self) -> bool {
+        match self {
+            ChainError::Unavailable { .. } | ChainError::GRPC(_) | ChainError::REST(_) => true,
+            ChainError::RPC(e) => !matches!(
+                e.detail(),
+                RPCErrorDetail::Response(_) | RPCErrorDetail::MethodNotFound(_)
+            ),
+            _ => false,
+        }
+    }
+
+    /// Classifies a `ChainError::CosmosSdk` into a known cosmos-sdk or wasmd error
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self {
+            ChainError::CosmosSdk { res } => res.sdk_error(),
+            _ => None,
+        }
+    }
+}
+
+/// Cosmos-sdk and wasmd errors, classified by the codespace and code of a failed `ChainResponse`.
+///
+/// Responses without a codespace (gRPC errors and tendermint rpc sync/async broadcasts)
+/// are classified by the error description the chain appends to the log instead.
+#[derive(Error, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub enum SdkError {
+    #[error("tx parse error")]
+    TxDecode,
+
+    #[error("unauthorized")]
+    Unauthorized,
+
+    #[error("insufficient funds")]
+    InsufficientFunds,
+
+    #[error("invalid address")]
+    InvalidAddress,
+
+    #[error("unknown address")]
+    UnknownAddress,
+
+    #[error("invalid coins")]
+    InvalidCoins,
+
+    #[error("out of gas")]
+    OutOfGas,
+
+    #[error("insufficient fee")]
+    InsufficientFee,
+
+    #[error("invalid request")]
+    InvalidRequest,
+
+    #[error("tx already in mempool")]
+    TxInMempoolCache,
+
+    #[error("mempool is full")]
+    MempoolIsFull,
+
+    #[error("tx too large")]
+    TxTooLarge,
+
+    #[error("tx timeout height")]
+    TxTimeoutHeight,
+
+    /// `expected` is parsed from the "account sequence mismatch, expected 5, got 3" log
+    #[error("incorrect account sequence, expected {expected:?}")]
+    WrongSequence { expected: Option<u64> },
+
+    /// `message` is the error returned by the contract, eg. "Unauthorized"
+    #[error("instantiate wasm contract failed: {message}")]
+    WasmInstantiateFailed { message: String },
+
+    #[error("execute wasm contract failed: {message}")]
+    WasmExecuteFailed { message: String },
+
+    #[error("migrate wasm contract failed: {message}")]
+    WasmMigrateFailed { message: String },
+
+    #[error("query wasm contract failed: {message}")]
+    WasmQueryFailed { message: String },
+
+    /// Any error that isn't classified above
+    #[error("{codespace} error code {code}")]
+    Other { codespace: String, code: u32 },
+}
+
+const SDK_CODESPACE: &str = "sdk";
+const WASM_CODESPACE: &str = "wasm";
+
+// (codespace, code, description) of the classified errors, from cosmos-sdk `types/errors` and wasmd `x/wasm/types/errors.go`
+const KNOWN_ERRORS: &[(&str, u32, &str)] = &[
+    (SDK_CODESPACE, 2, "tx parse error"),
+    (SDK_CODESPACE, 4, "unauthorized"),
+    (SDK_CODESPACE, 5, "insufficient funds"),
+    (SDK_CODESPACE, 7, "invalid address"),
+    (SDK_CODESPACE, 9, "unknown address"),
+    (SDK_CODESPACE, 10, "invalid coins"),
+    (SDK_CODESPACE, 11, "out of gas"),
+    (SDK_CODESPACE, 13, "insufficient fee"),
+    (SDK_CODESPACE, 18, "invalid request"),
+    (SDK_CODESPACE, 19, "tx already in mempool"),
+    (SDK_CODESPACE, 20, "mempool is full"),
+    (SDK_CODESPACE, 21, "tx too large"),
+    (SDK_CODESPACE, 30, "tx timeout height"),
+    (SDK_CODESPACE, 32, "incorrect account sequence"),
+    (WASM_CODESPACE, 4, "instantiate wasm contract failed"),
+    (WASM_CODESPACE, 5, "execute wasm contract failed"),
+    (WASM_CODESPACE, 9, "query wasm contract failed"),
+    (WASM_CODESPACE, 11, "migrate wasm contract failed"),
+];
+
+impl SdkError {
+    pub(crate) fn classify(codespace: &str, code: u32, log: &str) -> SdkError {
+        let (codespace, code) = if codespace.is_empty() {
+            // errors are logged as "<context>: <description>", sometimes followed by extra info
+            let known = KNOWN_ERRORS
+                .iter()
+                .find(|(_, _, desc)| log == *desc || log.contains(&format!(": {desc}")));
+
+            match known {
+                Some((codespace, code, _)) => (*codespace, *code),
+                None => (codespace, code),
+            }
+        } else {
+            (codespace, code)
+        };
+
+        match (codespace, code) {
+            (SDK_CODESPACE, 2) => SdkError::TxDecode,
+            (SDK_CODESPACE, 4) => SdkError::Unauthorized,
+            (SDK_CODESPACE, 5) => SdkError::InsufficientFunds,
+            (SDK_CODESPACE, 7) => SdkError::InvalidAddress,
+            (SDK_CODESPACE, 9) => SdkError::UnknownAddress,
+            (SDK_CODESPACE, 10) => SdkError::InvalidCoins,
+            (SDK_CODESPACE, 11) => SdkError::OutOfGas,
+            (SDK_CODESPACE, 13) => SdkError::InsufficientFee,
+            (SDK_CODESPACE, 18) => SdkError::InvalidRequest,
+            (SDK_CODESPACE, 19) => SdkError::TxInMempoolCache,
+            (SDK_CODESPACE, 20) => SdkError::MempoolIsFull,
+            (SDK_CODESPACE, 21) => SdkError::TxTooLarge,
+            (SDK_CODESPACE, 30) => SdkError::TxTimeoutHeight,
+            (SDK_CODESPACE, 32) => SdkError::WrongSequence {
+                expected: expected_sequence(log),
+            },
+            (WASM_CODESPACE, 4) => SdkError::WasmInstantiateFailed {
+                message: contract_error(log, "instantiate wasm contract failed"),
+            },
+            (WASM_CODESPACE, 5) => SdkError::WasmExecuteFailed {
+                message: contract_error(log, "execute wasm contract failed"),
+            },
+            (WASM_CODESPACE, 9) => SdkError::WasmQueryFailed {
+                message: contract_error(log, "query wasm contract failed"),
+            },
+            (WASM_CODESPACE, 11) => SdkError::WasmMigrateFailed {
+                message: contract_error(log, "migrate wasm contract failed"),
+            },
+            (codespace, code) => SdkError::Other {
+                codespace: codespace.to_string(),
+                code,
+            },
+        }
+    }
+}
+
+/// Parses the expected sequence out of an "account sequence mismatch, expected 5, got 3" log
+fn expected_sequence(log: &str) -> Option<u64> {
+    let re = Regex::new(r"account sequence mismatch, expected (\d+)").unwrap();
+
+    re.captures(log).and_then(|c| c[1].parse().ok())
+}
+
+/// Extracts the contract's error out of a wasmd log like
+/// "failed to execute message; message index: 0: Unauthorized: execute wasm contract failed"
+fn contract_error(log: &str, desc: &str) -> String {
+    let re = Regex::new(r"message index: \d+: ").unwrap();
+
+    let msg = log
+        .find(&format!(": {desc}"))
+        .map_or(log, |end| &log[..end]);
+
+    match re.find(msg) {
+        Some(m) => msg[m.end()..].to_string(),
+        None => msg.to_string(),
+    }
 }
 
 #[derive(Error, Debug)]
@@ -100,3 +353,54 @@ pub enum DeserializeError {
     #[error(transparent)]
     Serde(#[from] serde_json::error::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::SdkError;
+
+    #[test]
+    fn test_sdk_error_classify() {
+        assert_eq!(
+            SdkError::classify(
+                "sdk",
+                5,
+                "1000utest is smaller than 5000utest: insufficient funds"
+            ),
+            SdkError::InsufficientFunds
+        );
+
+        // wasmd reuses the sdk codes in its own codespace
+        assert_eq!(
+            SdkError::classify(
+                "wasm",
+                5,
+                "failed to execute message; message index: 0: Unauthorized: execute wasm contract failed"
+            ),
+            SdkError::WasmExecuteFailed {
+                message: "Unauthorized".to_string()
+            }
+        );
+
+        // responses without a codespace are classified from the log
+        assert_eq!(
+            SdkError::classify(
+                "",
+                32,
+                "account sequence mismatch, expected 8, got 7: incorrect account sequence"
+            ),
+            SdkError::WrongSequence { expected: Some(8) }
+        );
+        assert_eq!(
+            SdkError::classify("", 19, "tx already in mempool"),
+            SdkError::TxInMempoolCache
+        );
+
+        assert_eq!(
+            SdkError::classify("ibc", 7, "invalid packet"),
+            SdkError::Other {
+                codespace: "ibc".to_string(),
+                code: 7
+            }
+        );
+    }
+}
@@ -4,11 +4,15 @@ pub mod response;
 
 pub mod coin;
 
+pub mod decimal;
+
 pub mod fee;
 
 pub mod error;
 
 pub mod msg;
 
+pub(crate) mod proof;
+
 pub use cosmrs::proto::traits::Message;
 pub use cosmrs::{proto::traits::TypeUrl, tx::MessageExt, Any};
@@ -1,5 +1,7 @@
 use core::fmt::Debug;
 use cosmrs::{proto::traits::TypeUrl, tx::MessageExt, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
 use super::error::ChainError;
@@ -33,3 +35,57 @@ pub trait Msg:
             .map_err(ChainError::prost_proto_encoding)?)
     }
 }
+
+/// Serializable protobuf [`Any`] message, used when a tx wraps other arbitrary msgs
+/// (ie: gov v1 proposals and authz grants).
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct AnyMsg {
+    /// example: "/cosmos.bank.v1beta1.MsgSend"
+    pub type_url: String,
+
+    /// protobuf encoded msg bytes
+    pub value: Vec<u8>,
+}
+
+impl AnyMsg {
+    /// Encode a typed [`Msg`] into an `AnyMsg`
+    pub fn from_msg<M: Msg>(msg: &M) -> Result<Self, M::Err> {
+        Ok(msg.to_any()?.into())
+    }
+
+    /// Decode this `AnyMsg` into a typed [`Msg`]
+    pub fn to_msg<M: Msg>(&self) -> Result<M, M::Err> {
+        M::from_any(&self.clone().into())
+    }
+
+    /// Encode a raw protobuf message that has no `TypeUrl` impl (ie: authz authorizations)
+    pub(crate) fn from_proto<P: prost::Message>(type_url: &str, proto: P) -> Self {
+        Self {
+            type_url: type_url.to_string(),
+            value: proto.encode_to_vec(),
+        }
+    }
+
+    /// Decode the raw protobuf message, without checking its `type_url`
+    pub(crate) fn to_proto<P: prost::Message + Default>(&self) -> Result<P, ChainError> {
+        P::decode(self.value.as_slice()).map_err(ChainError::prost_proto_decoding)
+    }
+}
+
+impl From<Any> for AnyMsg {
+    fn from(any: Any) -> Self {
+        Self {
+            type_url: any.type_url,
+            value: any.value,
+        }
+    }
+}
+
+impl From<AnyMsg> for Any {
+    fn from(msg: AnyMsg) -> Self {
+        Self {
+            type_url: msg.type_url,
+            value: msg.value,
+        }
+    }
+}
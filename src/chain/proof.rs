@@ -0,0 +1,532 @@
+//! ICS-23 merkle proof verification for `/store/<store>/key` abci queries.
+//!
+//! cosmos-sdk answers proven store queries with two proof ops: an `ics23:iavl` proof of the key in the module's
+//! IAVL store, and an `ics23:simple` proof of that store's root hash in the multistore, whose root is the app hash.
+//! Only the two proof specs used by cosmos-sdk are supported, so this mirrors the parts of the `ics23` crate we need.
+
+use cosmrs::proto::traits::Message;
+use cosmrs::tendermint::merkle::proof::Proof;
+use sha2::{Digest, Sha256};
+
+use super::error::ChainError;
+
+const NO_HASH: i32 = 0;
+const SHA256: i32 = 1;
+const VAR_PROTO: i32 = 1;
+
+/// Parameters of the trees cosmos-sdk proves against, both are binary trees hashed with sha256
+struct ProofSpec {
+    leaf_prefix: &'static [u8],
+    child_size: usize,
+    min_prefix_length: usize,
+    max_prefix_length: usize,
+}
+
+const IAVL_SPEC: ProofSpec = ProofSpec {
+    leaf_prefix: &[0],
+    child_size: 33,
+    min_prefix_length: 4,
+    max_prefix_length: 12,
+};
+
+const TENDERMINT_SPEC: ProofSpec = ProofSpec {
+    leaf_prefix: &[0],
+    child_size: 32,
+    min_prefix_length: 1,
+    max_prefix_length: 1,
+};
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct CommitmentProof {
+    #[prost(oneof = "commitment_proof::Proof", tags = "1, 2, 3, 4")]
+    proof: Option<commitment_proof::Proof>,
+}
+
+mod commitment_proof {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub(super) enum Proof {
+        #[prost(message, tag = "1")]
+        Exist(super::ExistenceProof),
+        #[prost(message, tag = "2")]
+        Nonexist(super::NonExistenceProof),
+        /// Batch proofs are never returned by abci queries, so they are left encoded
+        #[prost(bytes, tag = "3")]
+        Batch(Vec<u8>),
+        #[prost(bytes, tag = "4")]
+        Compressed(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct ExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    leaf: Option<LeafOp>,
+    #[prost(message, repeated, tag = "4")]
+    path: Vec<InnerOp>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct NonExistenceProof {
+    #[prost(bytes = "vec", tag = "1")]
+    key: Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    left: Option<ExistenceProof>,
+    #[prost(message, optional, tag = "3")]
+    right: Option<ExistenceProof>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct LeafOp {
+    #[prost(int32, tag = "1")]
+    hash: i32,
+    #[prost(int32, tag = "2")]
+    prehash_key: i32,
+    #[prost(int32, tag = "3")]
+    prehash_value: i32,
+    #[prost(int32, tag = "4")]
+    length: i32,
+    #[prost(bytes = "vec", tag = "5")]
+    prefix: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+struct InnerOp {
+    #[prost(int32, tag = "1")]
+    hash: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    prefix: Vec<u8>,
+    #[prost(bytes = "vec", tag = "3")]
+    suffix: Vec<u8>,
+}
+
+/// Verifies the proof of a `/store/<store>/key` abci query against the `app_hash` of the header
+/// that committed the queried height. `value` is `None` to verify that `key` is absent from the store.
+pub(crate) fn verify_store_proof(
+    proof: Option<&Proof>,
+    app_hash: &[u8],
+    store: &str,
+    key: &[u8],
+    value: Option<&[u8]>,
+) -> Result<(), ChainError> {
+    let ops = match proof {
+        Some(proof) => &proof.ops,
+        None => return Err(invalid_proof("node did not return a proof")),
+    };
+
+    let (iavl_op, store_op) = match ops.as_slice() {
+        [iavl_op, store_op] => (iavl_op, store_op),
+        _ => {
+            return Err(invalid_proof(format!(
+                "expected 2 proof ops, got {}",
+                ops.len()
+            )))
+        }
+    };
+
+    if iavl_op.field_type != "ics23:iavl" || store_op.field_type != "ics23:simple" {
+        return Err(invalid_proof(format!(
+            "unexpected proof op types {:?}, {:?}",
+            iavl_op.field_type, store_op.field_type
+        )));
+    }
+    if iavl_op.key != key || store_op.key != store.as_bytes() {
+        return Err(invalid_proof("proof ops don't match the queried store key"));
+    }
+
+    let store_root = match (decode_proof(&iavl_op.data)?, value) {
+        (commitment_proof::Proof::Exist(proof), Some(value)) => {
+            if proof.key != key || proof.value != value {
+                return Err(invalid_proof("existence proof is for another key or value"));
+            }
+            existence_root(&proof, &IAVL_SPEC)?
+        }
+        (commitment_proof::Proof::Nonexist(proof), None) => {
+            if proof.key != key {
+                return Err(invalid_proof("non-existence proof is for another key"));
+            }
+            non_existence_root(&proof, &IAVL_SPEC)?
+        }
+        _ => return Err(invalid_proof("proof type doesn't match the returned value")),
+    };
+
+    let root = match decode_proof(&store_op.data)? {
+        commitment_proof::Proof::Exist(proof) => {
+            if proof.key != store.as_bytes() || proof.value != store_root {
+                return Err(invalid_proof("store proof doesn't match the store root"));
+            }
+            existence_root(&proof, &TENDERMINT_SPEC)?
+        }
+        _ => return Err(invalid_proof("expected an existence proof of the store")),
+    };
+
+    if root != app_hash {
+        return Err(invalid_proof("proof root doesn't match the app hash"));
+    }
+
+    Ok(())
+}
+
+fn invalid_proof(message: impl Into<String>) -> ChainError {
+    ChainError::ProofVerification {
+        message: message.into(),
+    }
+}
+
+fn decode_proof(data: &[u8]) -> Result<commitment_proof::Proof, ChainError> {
+    CommitmentProof::decode(data)
+        .map_err(ChainError::prost_proto_decoding)?
+        .proof
+        .ok_or_else(|| invalid_proof("empty commitment proof"))
+}
+
+/// Root hash of the tree that contains `proof.key`, after checking the proof follows `spec`
+fn existence_root(proof: &ExistenceProof, spec: &ProofSpec) -> Result<Vec<u8>, ChainError> {
+    let leaf = proof
+        .leaf
+        .as_ref()
+        .ok_or_else(|| invalid_proof("existence proof without leaf"))?;
+
+    if leaf.hash != SHA256
+        || leaf.prehash_key != NO_HASH
+        || leaf.prehash_value != SHA256
+        || leaf.length != VAR_PROTO
+        || !leaf.prefix.starts_with(spec.leaf_prefix)
+    {
+        return Err(invalid_proof("leaf doesn't match the proof spec"));
+    }
+    if proof.key.is_empty() || proof.value.is_empty() {
+        return Err(invalid_proof("leaf without key or value"));
+    }
+
+    let max_prefix_length = spec.max_prefix_length + spec.child_size;
+    for op in &proof.path {
+        if op.hash != SHA256
+            || op.prefix.starts_with(spec.leaf_prefix)
+            || op.prefix.len() < spec.min_prefix_length
+            || op.prefix.len() > max_prefix_length
+            || op.suffix.len() % spec.child_size != 0
+        {
+            return Err(invalid_proof("inner node doesn't match the proof spec"));
+        }
+    }
+
+    let mut leaf_data = leaf.prefix.clone();
+    leaf_data.extend(length_prefixed(&proof.key));
+    leaf_data.extend(length_prefixed(&Sha256::digest(&proof.value)));
+
+    let root = proof.path.iter().fold(sha256(&leaf_data), |child, op| {
+        sha256(&[op.prefix.as_slice(), &child, &op.suffix].concat())
+    });
+
+    Ok(root)
+}
+
+/// Root hash of the tree proven to not contain `proof.key`, because its neighbors are adjacent leaves
+fn non_existence_root(proof: &NonExistenceProof, spec: &ProofSpec) -> Result<Vec<u8>, ChainError> {
+    let left = proof
+        .left
+        .as_ref()
+        .map(|left| existence_root(left, spec).map(|root| (left, root)))
+        .transpose()?;
+    let right = proof
+        .right
+        .as_ref()
+        .map(|right| existence_root(right, spec).map(|root| (right, root)))
+        .transpose()?;
+
+    match (left, right) {
+        (Some((left, left_root)), Some((right, right_root))) => {
+            if left_root != right_root {
+                return Err(invalid_proof("neighbors are in different trees"));
+            }
+            if left.key >= proof.key || right.key <= proof.key {
+                return Err(invalid_proof("key is not between its neighbors"));
+            }
+            if !is_left_neighbor(spec, &left.path, &right.path) {
+                return Err(invalid_proof("neighbors are not adjacent"));
+            }
+            Ok(left_root)
+        }
+        (Some((left, root)), None) => {
+            if left.key >= proof.key || !is_extreme(spec, &left.path, 1) {
+                return Err(invalid_proof("key is not after the right most leaf"));
+            }
+            Ok(root)
+        }
+        (None, Some((right, root))) => {
+            if right.key <= proof.key || !is_extreme(spec, &right.path, 0) {
+                return Err(invalid_proof("key is not before the left most leaf"));
+            }
+            Ok(root)
+        }
+        (None, None) => Err(invalid_proof("non-existence proof without neighbors")),
+    }
+}
+
+/// True if `op` hashes its child as the `branch`th child of the node
+fn has_padding(spec: &ProofSpec, op: &InnerOp, branch: usize) -> bool {
+    let prefix = branch * spec.child_size;
+    let suffix = (1 - branch) * spec.child_size;
+
+    op.prefix.len() >= prefix + spec.min_prefix_length
+        && op.prefix.len() <= prefix + spec.max_prefix_length
+        && op.suffix.len() == suffix
+}
+
+/// True if every step of `path` goes down the same `branch`, ie. the leaf is the left (0) or right (1) most one
+fn is_extreme(spec: &ProofSpec, path: &[InnerOp], branch: usize) -> bool {
+    path.iter().all(|op| has_padding(spec, op, branch))
+}
+
+fn is_left_neighbor(spec: &ProofSpec, left: &[InnerOp], right: &[InnerOp]) -> bool {
+    // skip the nodes shared by both paths, from the root down
+    let shared = left
+        .iter()
+        .rev()
+        .zip(right.iter().rev())
+        .take_while(|(l, r)| l.prefix == r.prefix && l.suffix == r.suffix)
+        .count();
+
+    if shared >= left.len() || shared >= right.len() {
+        return false;
+    }
+
+    let (left, left_top) = (
+        &left[..left.len() - shared - 1],
+        &left[left.len() - shared - 1],
+    );
+    let (right, right_top) = (
+        &right[..right.len() - shared - 1],
+        &right[right.len() - shared - 1],
+    );
+
+    // the paths split at the same node, then the left leaf is the right most one of the left subtree and vice versa
+    has_padding(spec, left_top, 0)
+        && has_padding(spec, right_top, 1)
+        && is_extreme(spec, left, 1)
+        && is_extreme(spec, right, 0)
+}
+
+fn length_prefixed(data: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(data.len() + 2);
+    prost::encoding::encode_varint(data.len() as u64, &mut buf);
+    buf.extend_from_slice(data);
+    buf
+}
+
+fn sha256(data: &[u8]) -> Vec<u8> {
+    Sha256::digest(data).to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::traits::Message;
+    use cosmrs::tendermint::merkle::proof::{Proof, ProofOp};
+
+    use crate::chain::error::ChainError;
+
+    use super::{
+        commitment_proof, length_prefixed, sha256, verify_store_proof, CommitmentProof,
+        ExistenceProof, InnerOp, LeafOp, NonExistenceProof,
+    };
+
+    fn leaf(prefix: &[u8], key: &[u8], value: &[u8]) -> (LeafOp, Vec<u8>) {
+        let op = LeafOp {
+            hash: 1,
+            prehash_key: 0,
+            prehash_value: 1,
+            length: 1,
+            prefix: prefix.to_vec(),
+        };
+        let mut data = op.prefix.clone();
+        data.extend(length_prefixed(key));
+        data.extend(length_prefixed(&sha256(value)));
+
+        (op, sha256(&data))
+    }
+
+    /// Existence proofs of both leaves of a 2 leaf tree, and its root
+    fn tree(
+        leaf_prefix: &[u8],
+        inner_prefix: &[u8],
+        child_prefix: &[u8],
+        (k1, v1): (&[u8], &[u8]),
+        (k2, v2): (&[u8], &[u8]),
+    ) -> (ExistenceProof, ExistenceProof, Vec<u8>) {
+        let (leaf1, hash1) = leaf(leaf_prefix, k1, v1);
+        let (leaf2, hash2) = leaf(leaf_prefix, k2, v2);
+
+        let child1 = [child_prefix, &hash1].concat();
+        let child2 = [child_prefix, &hash2].concat();
+        let root = sha256(&[inner_prefix, &child1, &child2].concat());
+
+        let proof1 = ExistenceProof {
+            key: k1.to_vec(),
+            value: v1.to_vec(),
+            leaf: Some(leaf1),
+            path: vec![InnerOp {
+                hash: 1,
+                prefix: [inner_prefix, child_prefix].concat(),
+                suffix: child2,
+            }],
+        };
+        let proof2 = ExistenceProof {
+            key: k2.to_vec(),
+            value: v2.to_vec(),
+            leaf: Some(leaf2),
+            path: vec![InnerOp {
+                hash: 1,
+                prefix: [inner_prefix, &child1, child_prefix].concat(),
+                suffix: vec![],
+            }],
+        };
+
+        (proof1, proof2, root)
+    }
+
+    fn proof_op(field_type: &str, key: &[u8], proof: commitment_proof::Proof) -> ProofOp {
+        ProofOp {
+            field_type: field_type.to_string(),
+            key: key.to_vec(),
+            data: CommitmentProof { proof: Some(proof) }.encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_verify_store_proof() {
+        // IAVL inner nodes are prefixed with their height, size and version varints, children with their length
+        let (alice, carol, bank_root) = tree(
+            &[0, 2, 2],
+            &[2, 4, 2],
+            &[32],
+            (b"alice", b"100"),
+            (b"carol", b"300"),
+        );
+        let (bank, _, app_hash) = tree(
+            &[0],
+            &[1],
+            &[],
+            (b"bank", &bank_root),
+            (b"wasm", b"wasm root"),
+        );
+        let store_op = proof_op(
+            "ics23:simple",
+            b"bank",
+            commitment_proof::Proof::Exist(bank),
+        );
+
+        let exists = |key: &[u8], proof: &ExistenceProof| Proof {
+            ops: vec![
+                proof_op(
+                    "ics23:iavl",
+                    key,
+                    commitment_proof::Proof::Exist(proof.clone()),
+                ),
+                store_op.clone(),
+            ],
+        };
+        let absent =
+            |key: &[u8], left: Option<&ExistenceProof>, right: Option<&ExistenceProof>| Proof {
+                ops: vec![
+                    proof_op(
+                        "ics23:iavl",
+                        key,
+                        commitment_proof::Proof::Nonexist(NonExistenceProof {
+                            key: key.to_vec(),
+                            left: left.cloned(),
+                            right: right.cloned(),
+                        }),
+                    ),
+                    store_op.clone(),
+                ],
+            };
+
+        let proof = exists(b"alice", &alice);
+        verify_store_proof(Some(&proof), &app_hash, "bank", b"alice", Some(b"100")).unwrap();
+        let proof = exists(b"carol", &carol);
+        verify_store_proof(Some(&proof), &app_hash, "bank", b"carol", Some(b"300")).unwrap();
+
+        let proof = absent(b"bob", Some(&alice), Some(&carol));
+        verify_store_proof(Some(&proof), &app_hash, "bank", b"bob", None).unwrap();
+        let proof = absent(b"aaron", None, Some(&alice));
+        verify_store_proof(Some(&proof), &app_hash, "bank", b"aaron", None).unwrap();
+        let proof = absent(b"dave", Some(&carol), None);
+        verify_store_proof(Some(&proof), &app_hash, "bank", b"dave", None).unwrap();
+
+        // tampered values, keys and roots are rejected
+        let invalid = |res: Result<(), ChainError>| {
+            assert!(matches!(res, Err(ChainError::ProofVerification { .. })))
+        };
+
+        let proof = exists(b"alice", &alice);
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "bank",
+            b"alice",
+            Some(b"1000"),
+        ));
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "wasm",
+            b"alice",
+            Some(b"100"),
+        ));
+        invalid(verify_store_proof(
+            Some(&proof),
+            &bank_root,
+            "bank",
+            b"alice",
+            Some(b"100"),
+        ));
+        invalid(verify_store_proof(
+            None,
+            &app_hash,
+            "bank",
+            b"alice",
+            Some(b"100"),
+        ));
+
+        let mut forged = alice.clone();
+        forged.value = b"1000".to_vec();
+        let proof = exists(b"alice", &forged);
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "bank",
+            b"alice",
+            Some(b"1000"),
+        ));
+
+        // neighbors must surround the key and be adjacent
+        let proof = absent(b"alice", Some(&alice), Some(&carol));
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "bank",
+            b"alice",
+            None,
+        ));
+        let proof = absent(b"dave", None, Some(&carol));
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "bank",
+            b"dave",
+            None,
+        ));
+        let proof = absent(b"bob", Some(&alice), None);
+        invalid(verify_store_proof(
+            Some(&proof),
+            &app_hash,
+            "bank",
+            b"bob",
+            None,
+        ));
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 
 use super::fee::Fee;
+use crate::modules::auth::model::Address;
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
 pub struct PaginationRequest {
@@ -103,7 +104,17 @@ pub struct TxOptions {
     /// Adjust account sequence for cases:
     /// - Chain errors with "account sequence mismatch, expected 2, got 1"
     /// - multiple batched signed txns, such that you want inclusion within same block
+    ///
+    /// Overrides the `SequenceManager`, if one is set
     pub sequence: Option<u64>,
+
+    /// Pay the tx fee out of the fee allowance `fee_granter` gave to the signer (see the `feegrant` module).
+    ///
+    /// Also applied to a user supplied `fee`, unless it already sets its own granter
+    pub fee_granter: Option<Address>,
+
+    /// Account paying the tx fee instead of the signer. `fee_payer` must also sign the tx
+    pub fee_payer: Option<Address>,
 }
 
 impl Default for TxOptions {
@@ -113,6 +124,8 @@ impl Default for TxOptions {
             timeout_height: Some(0),
             memo: "Made with cosm-tome client".to_string(),
             sequence: None,
+            fee_granter: None,
+            fee_payer: None,
         }
     }
 }
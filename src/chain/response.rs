@@ -11,18 +11,23 @@ use cosmrs::rpc::endpoint::{
     broadcast::tx_async::Response as AsyncTendermintResponse,
     broadcast::tx_commit::{Response as BlockingTendermintResponse, TxResult},
     broadcast::tx_sync::Response as SyncTendermintResponse,
+    tx::Response as TxQueryTendermintResponse,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use super::error::{ChainError, DeserializeError};
+use super::error::{ChainError, DeserializeError, SdkError};
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Default)]
 pub struct ChainResponse {
     pub code: Code,
     pub data: Option<Vec<u8>>,
     pub log: String,
+    /// Module namespace of a failing `code`, eg. "sdk" or "wasm".
+    /// Empty for successful responses, gRPC errors and tendermint rpc sync/async broadcasts, which don't return one
+    #[serde(default)]
+    pub codespace: String,
 }
 
 impl ChainResponse {
@@ -35,6 +40,14 @@ impl ChainResponse {
         )?;
         Ok(r)
     }
+
+    /// Classifies a failed response into a known cosmos-sdk or wasmd error. Returns `None` for successful responses.
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self.code {
+            Code::Ok => None,
+            Code::Err(code) => Some(SdkError::classify(&self.codespace, code, &self.log)),
+        }
+    }
 }
 
 impl From<AbciQuery> for ChainResponse {
@@ -43,6 +56,7 @@ impl From<AbciQuery> for ChainResponse {
             code: res.code.into(),
             data: Some(res.value),
             log: res.log.to_string(),
+            codespace: res.codespace,
         }
     }
 }
@@ -53,6 +67,7 @@ impl From<TxResult> for ChainResponse {
             code: res.code.into(),
             data: res.data.map(|d| d.into()),
             log: res.log.to_string(),
+            codespace: res.codespace.as_ref().to_string(),
         }
     }
 }
@@ -63,6 +78,7 @@ impl From<tonic::Status> for ChainResponse {
             code: res.code().into(),
             data: Some(res.details().into()),
             log: res.message().into(),
+            codespace: String::new(),
         }
     }
 }
@@ -87,6 +103,7 @@ impl From<CosmosResponse> for AsyncChainTxResponse {
                 code: res.code.into(),
                 data: Some(res.data.into()), // TODO
                 log: res.raw_log,
+                codespace: res.codespace,
             },
             tx_hash: res.txhash,
         }
@@ -100,6 +117,7 @@ impl From<AsyncTendermintResponse> for AsyncChainTxResponse {
                 code: res.code.into(),
                 data: Some(res.data.into()),
                 log: res.log.to_string(),
+                codespace: String::new(),
             },
             tx_hash: res.hash.to_string(),
         }
@@ -113,12 +131,24 @@ impl From<SyncTendermintResponse> for AsyncChainTxResponse {
                 code: res.code.into(),
                 data: Some(res.data.into()),
                 log: res.log.to_string(),
+                codespace: String::new(),
             },
             tx_hash: res.hash.to_string(),
         }
     }
 }
 
+/// Value of a raw store key, proven against the app hash of the header that committed `height`
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Default)]
+pub struct VerifiedStoreResponse {
+    /// `None` if the key is proven to be absent from the store
+    pub value: Option<Vec<u8>>,
+    pub height: u64,
+
+    /// App hash of the header at `height + 1`, which the proof was verified against
+    pub app_hash: Vec<u8>,
+}
+
 /// ChainTxResponse is returned from the blocking `tx_broadcast_block()` api.
 /// Since we wait for the tx to be commited in the next block, we get the full tx data.
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Default)]
@@ -160,6 +190,7 @@ impl From<BlockingTendermintResponse> for ChainTxResponse {
                 code: res.deliver_tx.code.into(),
                 data: res.deliver_tx.data.map(|d| d.into()),
                 log: res.deliver_tx.log.to_string(),
+                codespace: res.deliver_tx.codespace.as_ref().to_string(),
             },
             events: res.deliver_tx.events.into_iter().map(Into::into).collect(),
             gas_used: res.deliver_tx.gas_used.into(),
@@ -170,6 +201,24 @@ impl From<BlockingTendermintResponse> for ChainTxResponse {
     }
 }
 
+impl From<TxQueryTendermintResponse> for ChainTxResponse {
+    fn from(res: TxQueryTendermintResponse) -> Self {
+        ChainTxResponse {
+            res: ChainResponse {
+                code: res.tx_result.code.into(),
+                data: Some(res.tx_result.data.into()),
+                log: res.tx_result.log.to_string(),
+                codespace: res.tx_result.codespace.as_ref().to_string(),
+            },
+            events: res.tx_result.events.into_iter().map(Into::into).collect(),
+            gas_used: res.tx_result.gas_used.into(),
+            gas_wanted: res.tx_result.gas_wanted.into(),
+            tx_hash: res.hash.to_string(),
+            height: res.height.into(),
+        }
+    }
+}
+
 impl TryFrom<CosmosResponse> for ChainTxResponse {
     type Error = ChainError;
 
@@ -179,6 +228,7 @@ impl TryFrom<CosmosResponse> for ChainTxResponse {
                 code: res.code.into(),
                 data: Some(res.data.into()), // TODO
                 log: res.raw_log,
+                codespace: res.codespace,
             },
             events: res
                 .events
@@ -1,13 +1,21 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use cosmrs::proto::traits::Message;
+use serde::{Deserialize, Serialize};
 
 use crate::chain::error::ChainError;
 use crate::chain::fee::GasInfo;
 use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse};
 use crate::config::cfg::ChainConfig;
 use crate::modules::tx::model::{BroadcastMode, RawTx};
+use crate::modules::tx::retry::RetryPolicy;
+use crate::modules::tx::sequence::{SequenceManager, SequenceOptions};
 
 use super::cosmos_grpc::CosmosgRPC;
+use super::cosmos_rest::CosmosREST;
+use super::failover::{FailoverClient, FailoverOptions, NodeClient};
 use super::tendermint_rpc::TendermintRPC;
 
 #[cfg(feature = "mocks")]
@@ -24,6 +32,14 @@ pub trait CosmosClient {
         I: Message + Default + tonic::IntoRequest<I> + 'static,
         O: Message + Default + 'static;
 
+    /// Same as `query()`, but reads the chain state as of the block at `height`.
+    /// Nodes only keep the state of recent heights, unless they run with `pruning = "nothing"`.
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        Self: Sized,
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static;
+
     async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError>;
 
     async fn broadcast_tx(
@@ -32,22 +48,116 @@ pub trait CosmosClient {
         mode: BroadcastMode,
     ) -> Result<AsyncChainTxResponse, ChainError>;
 
-    /// TODO: Block BroadcastMode support is being dropped from future Cosmos-Sdk versions.
-    /// Cosm-tome will continue to support it by broadcasting with the Sync mode
-    /// and then polling the GetTransaction endpoint until it has been committed in the block.
+    /// Polls the chain until the tx with the hex encoded `tx_hash` has been committed in a block.
+    /// Returns an error if the tx failed, or if it is not found before the client's `TxPollOptions` timeout.
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError>;
+
+    /// Block BroadcastMode support is dropped from newer Cosmos-Sdk versions.
+    /// Instead, cosm-tome broadcasts with the Sync mode and then polls
+    /// the GetTx endpoint until the tx has been committed in a block.
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError>;
 }
 
+/// Controls how `CosmosClient::wait_for_tx()` and `broadcast_tx_block()` wait for a tx to be committed
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct TxPollOptions {
+    /// Time to wait between each GetTx query
+    pub interval: Duration,
+
+    /// Max time to wait for the tx to be committed, before returning `ChainError::TxPollTimeout`
+    pub timeout: Duration,
+}
+
+impl Default for TxPollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Calls `get_tx` until it returns the committed tx, or `options.timeout` has elapsed.
+/// `get_tx` should return `Ok(None)` while the tx is not yet found on chain.
+pub(crate) async fn poll_for_tx<F, Fut>(
+    tx_hash: &str,
+    options: &TxPollOptions,
+    get_tx: F,
+) -> Result<ChainTxResponse, ChainError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<Option<ChainTxResponse>, ChainError>>,
+{
+    let start = Instant::now();
+
+    loop {
+        if let Some(res) = get_tx().await? {
+            if res.res.code.is_err() {
+                return Err(ChainError::CosmosSdk { res: res.res });
+            }
+
+            return Ok(res);
+        }
+
+        if start.elapsed() >= options.timeout {
+            return Err(ChainError::TxPollTimeout {
+                tx_hash: tx_hash.to_string(),
+                timeout: options.timeout,
+            });
+        }
+
+        tokio::time::sleep(options.interval).await;
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CosmTome<T: CosmosClient> {
     pub(crate) cfg: ChainConfig,
     pub client: T,
+    pub(crate) sequences: Option<SequenceManager>,
+    pub(crate) retry: Option<RetryPolicy>,
 }
 
 impl<T: CosmosClient> CosmTome<T> {
     /// General usage CosmClient constructor accepting any client that impls `CosmosClient` trait
     pub fn new(cfg: ChainConfig, client: T) -> Self {
-        Self { cfg, client }
+        Self {
+            cfg,
+            client,
+            sequences: None,
+            retry: None,
+        }
+    }
+
+    /// Signs concurrent txs from the same key with increasing sequences, instead of
+    /// querying each tx's sequence from chain. See `SequenceManager`.
+    pub fn with_sequence_manager(mut self, options: SequenceOptions) -> Self {
+        self.sequences = Some(SequenceManager::new(options));
+        self
+    }
+
+    /// Re-signs and re-broadcasts module txs that ran out of gas or hit an account sequence mismatch.
+    /// See `RetryPolicy`.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Queries the latest state, or the state at `height` if set
+    pub(crate) async fn query_at_height<I, O>(
+        &self,
+        msg: I,
+        path: &str,
+        height: Option<u64>,
+    ) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        match height {
+            Some(height) => self.client.query_at(msg, path, height).await,
+            None => self.client.query(msg, path).await,
+        }
     }
 }
 
@@ -60,10 +170,7 @@ impl CosmTome<TendermintRPC> {
                 api_type: "tendermint_rpc".to_string(),
             })?;
 
-        Ok(CosmTome {
-            client: TendermintRPC::new(&rpc_endpoint)?,
-            cfg,
-        })
+        Ok(CosmTome::new(cfg, TendermintRPC::new(&rpc_endpoint)?))
     }
 }
 
@@ -76,16 +183,105 @@ impl CosmTome<CosmosgRPC> {
                 api_type: "cosmos_grpc".to_string(),
             })?;
 
-        Ok(CosmTome {
-            client: CosmosgRPC::new(grpc_endpoint),
-            cfg,
-        })
+        Ok(CosmTome::new(cfg, CosmosgRPC::try_new(grpc_endpoint)?))
+    }
+}
+
+impl CosmTome<CosmosREST> {
+    pub fn with_cosmos_rest(cfg: ChainConfig) -> Result<CosmTome<CosmosREST>, ChainError> {
+        let rest_endpoint = cfg
+            .rest_endpoint
+            .clone()
+            .ok_or(ChainError::MissingApiEndpoint {
+                api_type: "cosmos_rest".to_string(),
+            })?;
+
+        Ok(CosmTome::new(cfg, CosmosREST::new(rest_endpoint)))
+    }
+}
+
+impl CosmTome<FailoverClient> {
+    /// Spreads requests over several nodes, which can use different api types.
+    /// The endpoints in `cfg` are ignored. See `FailoverClient`.
+    pub fn with_failover(
+        cfg: ChainConfig,
+        nodes: Vec<NodeClient>,
+        options: FailoverOptions,
+    ) -> Result<CosmTome<FailoverClient>, ChainError> {
+        Ok(CosmTome::new(cfg, FailoverClient::new(nodes, options)?))
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CosmosClient;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    use crate::chain::error::ChainError;
+    use crate::chain::response::{ChainResponse, ChainTxResponse, Code};
+
+    use super::{poll_for_tx, CosmosClient, TxPollOptions};
 
     const _MESSAGE_IS_OBJECT_SAFE: Option<&dyn CosmosClient> = None;
+
+    const OPTIONS: TxPollOptions = TxPollOptions {
+        interval: Duration::from_millis(1),
+        timeout: Duration::from_millis(50),
+    };
+
+    #[tokio::test]
+    async fn test_poll_for_tx() {
+        let calls = AtomicU32::new(0);
+
+        let res = poll_for_tx("TX_HASH_0", &OPTIONS, || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                Ok(None)
+            } else {
+                Ok(Some(ChainTxResponse {
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                    ..Default::default()
+                }))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(res.height, 1337);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_poll_for_tx_failed() {
+        let err = poll_for_tx("TX_HASH_0", &OPTIONS, || async {
+            Ok(Some(ChainTxResponse {
+                res: ChainResponse {
+                    code: Code::Err(5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }))
+        })
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(err, ChainError::CosmosSdk { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_poll_for_tx_timeout() {
+        let err = poll_for_tx("TX_HASH_0", &OPTIONS, || async { Ok(None) })
+            .await
+            .err()
+            .unwrap();
+
+        match err {
+            ChainError::TxPollTimeout { tx_hash, timeout } => {
+                assert_eq!(tx_hash, "TX_HASH_0");
+                assert_eq!(timeout, OPTIONS.timeout);
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
 }
@@ -1,9 +1,13 @@
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
 use async_trait::async_trait;
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
-use cosmrs::proto::cosmos::tx::v1beta1::{
-    BroadcastMode as ProtoBroadcastMode, BroadcastTxRequest, SimulateRequest,
-};
+use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastTxRequest, GetTxRequest, SimulateRequest};
+use serde::{Deserialize, Serialize};
 use tonic::codec::ProstCodec;
+use tonic::metadata::MetadataValue;
+use tonic::transport::{Channel, Endpoint};
 
 use cosmrs::proto::traits::Message;
 
@@ -12,16 +16,129 @@ use crate::chain::response::{AsyncChainTxResponse, ChainResponse, Code};
 use crate::chain::{error::ChainError, response::ChainTxResponse};
 use crate::modules::tx::model::{BroadcastMode, RawTx};
 
-use super::client::CosmosClient;
+use super::client::{poll_for_tx, CosmosClient, TxPollOptions};
+
+/// gRPC metadata key that makes cosmos-sdk nodes answer a query with the state at the given height
+const BLOCK_HEIGHT_HEADER: &str = "x-cosmos-block-height";
+
+/// Connection settings for the pooled gRPC `Channel` used by `CosmosgRPC`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CosmosgRPCOptions {
+    /// Max time to wait while establishing the underlying connection
+    pub connect_timeout: Option<Duration>,
+
+    /// Max time to wait for a response to a single request
+    pub request_timeout: Option<Duration>,
+
+    /// Interval between HTTP2 keep-alive pings
+    pub keep_alive_interval: Option<Duration>,
+
+    /// Max time to wait for a keep-alive ping acknowledgement before closing the connection
+    pub keep_alive_timeout: Option<Duration>,
+
+    /// Send keep-alive pings even when there are no in-flight requests
+    pub keep_alive_while_idle: bool,
+
+    /// Max number of concurrent in-flight requests on the channel
+    pub concurrency_limit: Option<usize>,
+}
+
+impl Default for CosmosgRPCOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Some(Duration::from_secs(10)),
+            request_timeout: Some(Duration::from_secs(60)),
+            keep_alive_interval: Some(Duration::from_secs(30)),
+            keep_alive_timeout: Some(Duration::from_secs(10)),
+            keep_alive_while_idle: true,
+            concurrency_limit: None,
+        }
+    }
+}
 
+/// Cosmos SDK gRPC client.
+///
+/// Holds a single lazily connected `Channel` that is reused for every call.
+/// Cloning a `CosmosgRPC` shares the same underlying connection.
 #[derive(Clone, Debug)]
 pub struct CosmosgRPC {
     grpc_endpoint: String,
+    options: CosmosgRPCOptions,
+    channel: Arc<OnceLock<Channel>>,
+    tx_poll_options: TxPollOptions,
 }
 
 impl CosmosgRPC {
+    /// An invalid `grpc_endpoint` is only reported once the client is used, see `try_new()`
     pub fn new(grpc_endpoint: String) -> Self {
-        Self { grpc_endpoint }
+        Self {
+            grpc_endpoint,
+            options: CosmosgRPCOptions::default(),
+            channel: Arc::new(OnceLock::new()),
+            tx_poll_options: TxPollOptions::default(),
+        }
+    }
+
+    /// Same as `new()`, but fails right away if `grpc_endpoint` is not a valid uri
+    pub fn try_new(grpc_endpoint: String) -> Result<Self, ChainError> {
+        Self::with_options(grpc_endpoint, CosmosgRPCOptions::default())
+    }
+
+    pub fn with_options(
+        grpc_endpoint: String,
+        options: CosmosgRPCOptions,
+    ) -> Result<Self, ChainError> {
+        let client = Self {
+            options,
+            ..Self::new(grpc_endpoint)
+        };
+
+        client.endpoint()?;
+
+        Ok(client)
+    }
+
+    /// Override how long `wait_for_tx()` and `broadcast_tx_block()` poll for the committed tx
+    pub fn with_tx_poll_options(mut self, options: TxPollOptions) -> Self {
+        self.tx_poll_options = options;
+        self
+    }
+
+    fn endpoint(&self) -> Result<Endpoint, ChainError> {
+        let options = &self.options;
+
+        let mut endpoint = Endpoint::from_shared(self.grpc_endpoint.clone())?
+            .keep_alive_while_idle(options.keep_alive_while_idle);
+
+        if let Some(timeout) = options.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = options.request_timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(interval) = options.keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = options.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+        if let Some(limit) = options.concurrency_limit {
+            endpoint = endpoint.concurrency_limit(limit);
+        }
+
+        Ok(endpoint)
+    }
+
+    // NOTE: The channel is created on first use instead of in the constructor,
+    // because tonic needs to be inside of a tokio runtime to spawn the connection worker.
+    fn channel(&self) -> Result<Channel, ChainError> {
+        if let Some(channel) = self.channel.get() {
+            return Ok(channel.clone());
+        }
+
+        let channel = self.endpoint()?.connect_lazy();
+
+        Ok(self.channel.get_or_init(|| channel).clone())
     }
 
     // Uses underlying grpc client to make calls to any gRPC service
@@ -35,11 +152,7 @@ impl CosmosgRPC {
         I: Message + 'static,
         O: Message + Default + 'static,
     {
-        let conn = tonic::transport::Endpoint::new(self.grpc_endpoint.clone())?
-            .connect()
-            .await?;
-
-        let mut client = tonic::client::Grpc::new(conn);
+        let mut client = tonic::client::Grpc::new(self.channel()?);
 
         client.ready().await?;
 
@@ -58,6 +171,26 @@ impl CosmosgRPC {
 
         Ok(res.into_inner())
     }
+
+    // Returns `None` if the tx has not been committed yet
+    async fn get_tx(&self, tx_hash: &str) -> Result<Option<ChainTxResponse>, ChainError> {
+        let mut client = ServiceClient::new(self.channel()?);
+
+        let req = GetTxRequest {
+            hash: tx_hash.to_string(),
+        };
+
+        let res = match client.get_tx(req).await {
+            Ok(res) => res.into_inner(),
+            // NOTE: cosmos-sdk < v0.46 returns `Unknown` instead of `NotFound` for missing txs
+            Err(e) if e.code() == tonic::Code::NotFound || e.message().contains("not found") => {
+                return Ok(None)
+            }
+            Err(e) => return Err(ChainError::tonic_status(e)),
+        };
+
+        res.tx_response.map(TryInto::try_into).transpose()
+    }
 }
 
 #[async_trait]
@@ -72,9 +205,23 @@ impl CosmosClient for CosmosgRPC {
         Ok(res)
     }
 
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let mut req = msg.into_request();
+        req.metadata_mut()
+            .insert(BLOCK_HEIGHT_HEADER, MetadataValue::from(height));
+
+        let res = self.grpc_call::<I, O>(req, path).await?;
+
+        Ok(res)
+    }
+
     #[allow(deprecated)]
     async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let mut client = ServiceClient::new(self.channel()?);
 
         let req = SimulateRequest {
             tx: None,
@@ -103,7 +250,7 @@ impl CosmosClient for CosmosgRPC {
         tx: &RawTx,
         mode: BroadcastMode,
     ) -> Result<AsyncChainTxResponse, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let mut client = ServiceClient::new(self.channel()?);
 
         let req = BroadcastTxRequest {
             tx_bytes: tx.to_bytes()?,
@@ -125,26 +272,158 @@ impl CosmosClient for CosmosgRPC {
         Ok(res)
     }
 
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        poll_for_tx(tx_hash, &self.tx_poll_options, || self.get_tx(tx_hash)).await
+    }
+
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
 
-        let req = BroadcastTxRequest {
-            tx_bytes: tx.to_bytes()?,
-            mode: ProtoBroadcastMode::Block.into(),
-        };
+        self.wait_for_tx(&res.tx_hash).await
+    }
+}
 
-        let res = client
-            .broadcast_tx(req)
-            .await
-            .map_err(ChainError::tonic_status)?
-            .into_inner();
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
-        let res: ChainTxResponse = res.tx_response.unwrap().try_into()?;
+    use cosmrs::proto::cosmos::auth::v1beta1::query_server::{Query, QueryServer};
+    use cosmrs::proto::cosmos::auth::v1beta1::{
+        BaseAccount, QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest,
+        QueryAccountsResponse, QueryParamsRequest, QueryParamsResponse,
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+    use tokio::net::TcpListener;
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tokio_stream::StreamExt;
+    use tonic::{Request, Response, Status};
 
-        if res.res.code.is_err() {
-            return Err(ChainError::CosmosSdk { res: res.res });
+    use crate::chain::error::ChainError;
+    use crate::clients::client::CosmosClient;
+
+    use super::{CosmosgRPC, BLOCK_HEIGHT_HEADER};
+
+    // Auth query service that answers with the block height it was asked for as the account sequence
+    struct Node;
+
+    #[tonic::async_trait]
+    impl Query for Node {
+        async fn accounts(
+            &self,
+            _: Request<QueryAccountsRequest>,
+        ) -> Result<Response<QueryAccountsResponse>, Status> {
+            Err(Status::unimplemented("accounts"))
         }
 
-        Ok(res)
+        async fn account(
+            &self,
+            req: Request<QueryAccountRequest>,
+        ) -> Result<Response<QueryAccountResponse>, Status> {
+            let height = req
+                .metadata()
+                .get(BLOCK_HEIGHT_HEADER)
+                .map(|h| h.to_str().unwrap().parse().unwrap())
+                .unwrap_or_default();
+
+            Ok(Response::new(QueryAccountResponse {
+                account: Some(cosmrs::proto::Any {
+                    type_url: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+                    value: BaseAccount {
+                        address: req.into_inner().address,
+                        pub_key: None,
+                        account_number: 1337,
+                        sequence: height,
+                    }
+                    .to_bytes()
+                    .unwrap(),
+                }),
+            }))
+        }
+
+        async fn params(
+            &self,
+            _: Request<QueryParamsRequest>,
+        ) -> Result<Response<QueryParamsResponse>, Status> {
+            Err(Status::unimplemented("params"))
+        }
+    }
+
+    // Serves `Node` on a local port, counting the accepted connections
+    async fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let connections = Arc::new(AtomicUsize::new(0));
+        let accepted = connections.clone();
+
+        let incoming = TcpListenerStream::new(listener).map(move |conn| {
+            accepted.fetch_add(1, Ordering::SeqCst);
+            conn
+        });
+
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(QueryServer::new(Node))
+                .serve_with_incoming(incoming),
+        );
+
+        (format!("http://{addr}"), connections)
+    }
+
+    async fn query_sequence(client: &CosmosgRPC, height: Option<u64>) -> u64 {
+        let req = QueryAccountRequest {
+            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+        };
+        let path = "/cosmos.auth.v1beta1.Query/Account";
+
+        let res: QueryAccountResponse = match height {
+            Some(height) => client.query_at(req, path, height).await,
+            None => client.query(req, path).await,
+        }
+        .unwrap();
+
+        BaseAccount::decode(res.account.unwrap().value.as_slice())
+            .unwrap()
+            .sequence
+    }
+
+    #[tokio::test]
+    async fn test_query_at() {
+        let (endpoint, _) = serve().await;
+        let client = CosmosgRPC::new(endpoint);
+
+        assert_eq!(query_sequence(&client, Some(42)).await, 42);
+        assert_eq!(query_sequence(&client, None).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_shared_channel() {
+        let (endpoint, connections) = serve().await;
+        let client = CosmosgRPC::try_new(endpoint).unwrap();
+
+        query_sequence(&client, None).await;
+        query_sequence(&client, Some(1)).await;
+        query_sequence(&client.clone(), None).await;
+
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_endpoint() {
+        assert!(CosmosgRPC::try_new("not a uri".to_string()).is_err());
+
+        let err = CosmosgRPC::new("not a uri".to_string())
+            .query::<_, QueryAccountResponse>(
+                QueryAccountRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                },
+                "/cosmos.auth.v1beta1.Query/Account",
+            )
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, ChainError::GRPC(..)));
     }
 }
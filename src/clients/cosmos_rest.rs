@@ -0,0 +1,619 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use cosmrs::proto::cosmos::base::abci::v1beta1::{
+    GasInfo as ProtoGasInfo, TxResponse as CosmosResponse,
+};
+use cosmrs::proto::traits::Message;
+use reqwest::{RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::chain::error::ChainError;
+use crate::chain::fee::GasInfo;
+use crate::chain::response::{
+    AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code, Event, Tag,
+};
+use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+use super::client::{poll_for_tx, CosmosClient, TxPollOptions};
+
+const ABCI_QUERY_ROUTE: &str = "/cosmos/base/tendermint/v1beta1/abci_query";
+
+/// Cosmos SDK REST (gRPC-gateway / LCD) client.
+///
+/// Queries are routed through the generic `/cosmos/base/tendermint/v1beta1/abci_query` endpoint,
+/// so that every module query can reuse the same protobuf request / response types
+/// as the other `CosmosClient` backends.
+///
+/// NOTE: That endpoint was added in cosmos-sdk v0.46, queries against older nodes
+/// fail with `ChainError::UnsupportedApiEndpoint`. Tx simulation, broadcasting and lookups
+/// work with any cosmos-sdk version serving the `/cosmos/tx/v1beta1` routes.
+#[derive(Clone, Debug)]
+pub struct CosmosREST {
+    rest_endpoint: String,
+    client: reqwest::Client,
+    tx_poll_options: TxPollOptions,
+}
+
+impl CosmosREST {
+    pub fn new(rest_endpoint: String) -> Self {
+        Self {
+            rest_endpoint: rest_endpoint.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            tx_poll_options: TxPollOptions::default(),
+        }
+    }
+
+    /// Override how long `wait_for_tx()` and `broadcast_tx_block()` poll for the committed tx
+    pub fn with_tx_poll_options(mut self, options: TxPollOptions) -> Self {
+        self.tx_poll_options = options;
+        self
+    }
+
+    fn url(&self, route: &str) -> String {
+        format!("{}{}", self.rest_endpoint, route)
+    }
+
+    // Sends the http request and decodes the json body,
+    // converting gRPC-gateway error bodies into `ChainError::CosmosSdk`
+    async fn send<O: DeserializeOwned>(&self, req: RequestBuilder) -> Result<O, ChainError> {
+        self.decode(req.send().await?).await
+    }
+
+    async fn decode<O: DeserializeOwned>(&self, res: Response) -> Result<O, ChainError> {
+        if !res.status().is_success() {
+            let status = res.status();
+            let body = res.text().await?;
+
+            let err: RestErrorResponse =
+                serde_json::from_str(&body).unwrap_or_else(|_| RestErrorResponse {
+                    code: status.as_u16().into(),
+                    message: body,
+                });
+
+            return Err(ChainError::CosmosSdk {
+                res: ChainResponse {
+                    code: err.code.into(),
+                    log: err.message,
+                    ..Default::default()
+                },
+            });
+        }
+
+        Ok(res.json::<O>().await?)
+    }
+
+    async fn abci_query<I: Message, O: Message + Default>(
+        &self,
+        msg: I,
+        path: &str,
+        height: Option<u64>,
+    ) -> Result<O, ChainError> {
+        let data = base64::encode(msg.encode_to_vec());
+
+        let mut req = self
+            .client
+            .get(self.url(ABCI_QUERY_ROUTE))
+            .query(&[("path", path), ("data", &data)]);
+
+        if let Some(height) = height {
+            req = req.query(&[("height", height)]);
+        }
+
+        let res = req.send().await?;
+
+        // nodes running cosmos-sdk < v0.46 don't register the route at all,
+        // which the gateway reports as a 404 / 501 instead of a query error
+        if matches!(
+            res.status(),
+            StatusCode::NOT_FOUND | StatusCode::NOT_IMPLEMENTED
+        ) {
+            return Err(ChainError::UnsupportedApiEndpoint {
+                endpoint: ABCI_QUERY_ROUTE.to_string(),
+                message: format!(
+                    "{} (the REST client requires cosmos-sdk >= v0.46)",
+                    res.status()
+                ),
+            });
+        }
+
+        let res: AbciQueryResponse = self.decode(res).await?;
+
+        let value = decode_base64(res.value.as_deref().unwrap_or_default())?;
+
+        if res.code != 0 {
+            return Err(ChainError::CosmosSdk {
+                res: ChainResponse {
+                    code: res.code.into(),
+                    data: Some(value),
+                    log: res.log,
+                    codespace: res.codespace,
+                },
+            });
+        }
+
+        let proto_res = O::decode(value.as_slice()).map_err(ChainError::prost_proto_decoding)?;
+
+        Ok(proto_res)
+    }
+
+    // Returns `None` if the tx has not been committed yet
+    async fn get_tx(&self, tx_hash: &str) -> Result<Option<ChainTxResponse>, ChainError> {
+        let res = self
+            .send::<GetTxResponse>(
+                self.client
+                    .get(self.url(&format!("/cosmos/tx/v1beta1/txs/{tx_hash}"))),
+            )
+            .await;
+
+        match res {
+            Ok(res) => Ok(Some(CosmosResponse::from(res.tx_response).try_into()?)),
+            // NOTE: gRPC `NotFound` code, older cosmos-sdk versions only set the message
+            Err(ChainError::CosmosSdk { res })
+                if res.code == Code::Err(5) || res.log.contains("not found") =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn broadcast(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<CosmosResponse, ChainError> {
+        let req = BroadcastTxRequest {
+            tx_bytes: base64::encode(tx.to_bytes()?),
+            mode: mode.as_ref().to_string(),
+        };
+
+        let res: BroadcastTxResponse = self
+            .send(
+                self.client
+                    .post(self.url("/cosmos/tx/v1beta1/txs"))
+                    .json(&req),
+            )
+            .await?;
+
+        Ok(res.tx_response.into())
+    }
+}
+
+#[async_trait]
+impl CosmosClient for CosmosREST {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.abci_query(msg, path, None).await
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.abci_query(msg, path, Some(height)).await
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let req = SimulateRequest {
+            tx_bytes: base64::encode(tx.to_bytes()?),
+        };
+
+        let res: SimulateResponse = self
+            .send(
+                self.client
+                    .post(self.url("/cosmos/tx/v1beta1/simulate"))
+                    .json(&req),
+            )
+            .await?;
+
+        let gas_info = res.gas_info.ok_or(ChainError::Simulation)?;
+
+        Ok(ProtoGasInfo {
+            gas_wanted: gas_info.gas_wanted,
+            gas_used: gas_info.gas_used,
+        }
+        .into())
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let res: AsyncChainTxResponse = self.broadcast(tx, mode).await?.into();
+
+        if res.res.code.is_err() {
+            return Err(ChainError::CosmosSdk { res: res.res });
+        }
+
+        Ok(res)
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        poll_for_tx(tx_hash, &self.tx_poll_options, || self.get_tx(tx_hash)).await
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
+
+        self.wait_for_tx(&res.tx_hash).await
+    }
+}
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, ChainError> {
+    base64::decode(s).map_err(|e| ChainError::ProtoDecoding {
+        message: e.to_string(),
+    })
+}
+
+// gRPC-gateway encodes 64 bit integers as json strings
+fn from_str<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(d)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+#[derive(Deserialize)]
+struct RestErrorResponse {
+    code: u32,
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct AbciQueryResponse {
+    code: u32,
+    #[serde(default)]
+    log: String,
+    value: Option<String>,
+    #[serde(default)]
+    codespace: String,
+}
+
+#[derive(Serialize)]
+struct SimulateRequest {
+    tx_bytes: String,
+}
+
+#[derive(Deserialize)]
+struct SimulateResponse {
+    gas_info: Option<RestGasInfo>,
+}
+
+#[derive(Deserialize)]
+struct RestGasInfo {
+    #[serde(deserialize_with = "from_str")]
+    gas_wanted: u64,
+    #[serde(deserialize_with = "from_str")]
+    gas_used: u64,
+}
+
+#[derive(Serialize)]
+struct BroadcastTxRequest {
+    tx_bytes: String,
+    mode: String,
+}
+
+#[derive(Deserialize)]
+struct BroadcastTxResponse {
+    tx_response: RestTxResponse,
+}
+
+#[derive(Deserialize)]
+struct GetTxResponse {
+    tx_response: RestTxResponse,
+}
+
+#[derive(Deserialize)]
+struct RestTxResponse {
+    #[serde(deserialize_with = "from_str")]
+    height: i64,
+    txhash: String,
+    #[serde(default)]
+    codespace: String,
+    code: u32,
+    #[serde(default)]
+    data: String,
+    #[serde(default)]
+    raw_log: String,
+    #[serde(deserialize_with = "from_str")]
+    gas_wanted: i64,
+    #[serde(deserialize_with = "from_str")]
+    gas_used: i64,
+    #[serde(default)]
+    events: Vec<RestEvent>,
+}
+
+#[derive(Deserialize)]
+struct RestEvent {
+    #[serde(rename = "type")]
+    type_str: String,
+    #[serde(default)]
+    attributes: Vec<RestEventAttribute>,
+}
+
+#[derive(Deserialize)]
+struct RestEventAttribute {
+    key: String,
+    #[serde(default)]
+    value: String,
+}
+
+impl From<RestTxResponse> for CosmosResponse {
+    fn from(res: RestTxResponse) -> Self {
+        CosmosResponse {
+            height: res.height,
+            txhash: res.txhash,
+            codespace: res.codespace,
+            code: res.code,
+            data: res.data,
+            raw_log: res.raw_log,
+            gas_wanted: res.gas_wanted,
+            gas_used: res.gas_used,
+            events: res
+                .events
+                .into_iter()
+                .map(|e| {
+                    Event {
+                        type_str: e.type_str,
+                        attributes: e
+                            .attributes
+                            .into_iter()
+                            .map(|a| Tag {
+                                key: a.key,
+                                value: a.value,
+                            })
+                            .collect(),
+                    }
+                    .into()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+    use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+    use cosmrs::proto::traits::Message;
+    use std::time::Duration;
+
+    use crate::chain::error::{ChainError, SdkError};
+    use crate::chain::response::Code;
+    use crate::clients::client::{CosmosClient, TxPollOptions};
+    use crate::clients::test_server::{serve, serve_all, serve_recorded};
+    use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+    use super::{CosmosREST, ABCI_QUERY_ROUTE};
+
+    fn raw_tx() -> RawTx {
+        TxRaw {
+            body_bytes: vec![1],
+            auth_info_bytes: vec![2],
+            signatures: vec![vec![3]],
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let value = QueryBalanceResponse {
+            balance: Some(Coin {
+                denom: "utest".to_string(),
+                amount: "1337".to_string(),
+            }),
+        }
+        .encode_to_vec();
+
+        let endpoint = serve(
+            "200 OK",
+            format!(
+                r#"{{"code":0,"log":"","info":"","index":"0","key":null,"value":"{}","proof_ops":null,"height":"10","codespace":""}}"#,
+                base64::encode(value)
+            ),
+        )
+        .await;
+
+        let client = CosmosREST::new(endpoint);
+
+        let res: QueryBalanceResponse = client
+            .query(
+                QueryBalanceRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                    denom: "utest".to_string(),
+                },
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.balance.unwrap().amount, "1337");
+    }
+
+    #[tokio::test]
+    async fn test_query_at() {
+        let value = QueryBalanceResponse {
+            balance: Some(Coin {
+                denom: "utest".to_string(),
+                amount: "1337".to_string(),
+            }),
+        }
+        .encode_to_vec();
+
+        let (endpoint, requests) = serve_recorded(vec![(
+            "200 OK",
+            format!(
+                r#"{{"code":0,"log":"","info":"","index":"0","key":null,"value":"{}","proof_ops":null,"height":"42","codespace":""}}"#,
+                base64::encode(value)
+            ),
+        )])
+        .await;
+
+        let res: QueryBalanceResponse = CosmosREST::new(endpoint)
+            .query_at(
+                QueryBalanceRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                    denom: "utest".to_string(),
+                },
+                "/cosmos.bank.v1beta1.Query/Balance",
+                42,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.balance.unwrap().amount, "1337");
+
+        let requests = requests.lock().unwrap();
+        let request_line = requests[0].lines().next().unwrap();
+        assert!(request_line.starts_with(&format!("GET {ABCI_QUERY_ROUTE}?")));
+        assert!(request_line.contains("&height=42 "));
+    }
+
+    #[tokio::test]
+    async fn test_query_unsupported_sdk_version() {
+        let endpoint = serve("404 Not Found", "404 page not found".to_string()).await;
+
+        let err = CosmosREST::new(endpoint)
+            .query::<_, QueryBalanceResponse>(
+                QueryBalanceRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                    denom: "utest".to_string(),
+                },
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            err,
+            ChainError::UnsupportedApiEndpoint { endpoint, .. } if endpoint == ABCI_QUERY_ROUTE
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_simulate_tx() {
+        let endpoint = serve(
+            "200 OK",
+            r#"{"gas_info":{"gas_wanted":"0","gas_used":"81234"},"result":null}"#.to_string(),
+        )
+        .await;
+
+        let gas_info = CosmosREST::new(endpoint)
+            .simulate_tx(&raw_tx())
+            .await
+            .unwrap();
+
+        assert_eq!(gas_info.gas_used.value(), 81234);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_tx_err() {
+        let endpoint = serve(
+            "400 Bad Request",
+            r#"{"code":13,"message":"insufficient fee","details":[]}"#.to_string(),
+        )
+        .await;
+
+        let err = CosmosREST::new(endpoint)
+            .simulate_tx(&raw_tx())
+            .await
+            .err()
+            .unwrap();
+
+        match err {
+            ChainError::CosmosSdk { res } => {
+                assert_eq!(res.code, Code::Err(13));
+                assert_eq!(res.log, "insufficient fee");
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_tx() {
+        let endpoint = serve(
+            "200 OK",
+            r#"{"tx_response":{"height":"0","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"[]","logs":[],"info":"","gas_wanted":"0","gas_used":"0","tx":null,"timestamp":"","events":[]}}"#.to_string(),
+        )
+        .await;
+
+        let res = CosmosREST::new(endpoint)
+            .broadcast_tx(&raw_tx(), BroadcastMode::Sync)
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+        assert!(res.res.code.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_tx_err_codespace() {
+        // the log alone doesn't name the error, it has to be classified by codespace and code
+        let endpoint = serve(
+            "200 OK",
+            r#"{"tx_response":{"height":"0","txhash":"TX_HASH_0","codespace":"sdk","code":32,"data":"","raw_log":"account sequence mismatch, expected 7, got 5","logs":[],"info":"","gas_wanted":"0","gas_used":"0","tx":null,"timestamp":"","events":[]}}"#.to_string(),
+        )
+        .await;
+
+        let err = CosmosREST::new(endpoint)
+            .broadcast_tx(&raw_tx(), BroadcastMode::Sync)
+            .await
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            err.sdk_error(),
+            Some(SdkError::WrongSequence { expected: Some(7) })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_tx_block() {
+        let endpoint = serve_all(vec![
+            (
+                "200 OK",
+                r#"{"tx_response":{"height":"0","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"[]","logs":[],"info":"","gas_wanted":"0","gas_used":"0","tx":null,"timestamp":"","events":[]}}"#.to_string(),
+            ),
+            (
+                "404 Not Found",
+                r#"{"code":5,"message":"tx not found: TX_HASH_0","details":[]}"#.to_string(),
+            ),
+            (
+                "200 OK",
+                r#"{"tx":null,"tx_response":{"height":"1337","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"[]","logs":[],"info":"","gas_wanted":"200","gas_used":"100","tx":null,"timestamp":"","events":[{"type":"store_code","attributes":[{"key":"code_id","value":"7","index":true}]}]}}"#.to_string(),
+            ),
+        ])
+        .await;
+
+        let res = CosmosREST::new(endpoint)
+            .with_tx_poll_options(TxPollOptions {
+                interval: Duration::from_millis(1),
+                timeout: Duration::from_secs(5),
+            })
+            .broadcast_tx_block(&raw_tx())
+            .await
+            .unwrap();
+
+        assert_eq!(res.height, 1337);
+        assert_eq!(res.gas_used, 100);
+        assert_eq!(
+            res.find_event_tags("store_code".to_string(), "code_id".to_string())[0].value,
+            "7"
+        );
+    }
+}
@@ -0,0 +1,740 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use cosmrs::proto::cosmos::base::tendermint::v1beta1::{GetSyncingRequest, GetSyncingResponse};
+use cosmrs::proto::traits::Message;
+use cosmrs::rpc::error::ErrorDetail as RPCErrorDetail;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::chain::error::{ChainError, SdkError};
+use crate::chain::fee::GasInfo;
+use crate::chain::response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code};
+use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+use super::client::CosmosClient;
+use super::cosmos_grpc::CosmosgRPC;
+use super::cosmos_rest::CosmosREST;
+use super::tendermint_rpc::TendermintRPC;
+
+/// Max number of broadcast txs whose node is remembered for `wait_for_tx()`
+const MAX_PINNED_TXS: usize = 1024;
+
+/// Weight of the newest request when updating a node's average latency
+const LATENCY_WEIGHT: f64 = 0.3;
+
+/// Any of the built-in backends, so that a `FailoverClient` can mix endpoints of different api types
+#[derive(Clone, Debug)]
+pub enum NodeClient {
+    TendermintRPC(TendermintRPC),
+    CosmosgRPC(CosmosgRPC),
+    CosmosREST(CosmosREST),
+}
+
+impl From<TendermintRPC> for NodeClient {
+    fn from(client: TendermintRPC) -> Self {
+        NodeClient::TendermintRPC(client)
+    }
+}
+
+impl From<CosmosgRPC> for NodeClient {
+    fn from(client: CosmosgRPC) -> Self {
+        NodeClient::CosmosgRPC(client)
+    }
+}
+
+impl From<CosmosREST> for NodeClient {
+    fn from(client: CosmosREST) -> Self {
+        NodeClient::CosmosREST(client)
+    }
+}
+
+impl NodeClient {
+    /// `CosmosgRPC` and `CosmosREST` return gRPC `Unavailable` / `DeadlineExceeded` statuses and
+    /// http 502 / 503 / 504 responses as `ChainError::CosmosSdk`, like any other error from the node.
+    /// The node never answered the request though, so they are failed over as `ChainError::Unavailable`.
+    fn unavailable(&self, e: ChainError) -> ChainError {
+        let codes: &[u32] = match self {
+            NodeClient::TendermintRPC(_) => return e,
+            NodeClient::CosmosgRPC(_) => &[4, 14],
+            NodeClient::CosmosREST(_) => &[502, 503, 504],
+        };
+
+        match e {
+            // chain errors always carry a codespace
+            ChainError::CosmosSdk { res }
+                if res.codespace.is_empty()
+                    && matches!(res.code, Code::Err(code) if codes.contains(&code)) =>
+            {
+                ChainError::Unavailable { message: res.log }
+            }
+            e => e,
+        }
+    }
+}
+
+#[async_trait]
+impl CosmosClient for NodeClient {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.query(msg, path).await,
+            NodeClient::CosmosgRPC(c) => c.query(msg, path).await,
+            NodeClient::CosmosREST(c) => c.query(msg, path).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.query_at(msg, path, height).await,
+            NodeClient::CosmosgRPC(c) => c.query_at(msg, path, height).await,
+            NodeClient::CosmosREST(c) => c.query_at(msg, path, height).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.simulate_tx(tx).await,
+            NodeClient::CosmosgRPC(c) => c.simulate_tx(tx).await,
+            NodeClient::CosmosREST(c) => c.simulate_tx(tx).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.broadcast_tx(tx, mode).await,
+            NodeClient::CosmosgRPC(c) => c.broadcast_tx(tx, mode).await,
+            NodeClient::CosmosREST(c) => c.broadcast_tx(tx, mode).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.wait_for_tx(tx_hash).await,
+            NodeClient::CosmosgRPC(c) => c.wait_for_tx(tx_hash).await,
+            NodeClient::CosmosREST(c) => c.wait_for_tx(tx_hash).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let res = match self {
+            NodeClient::TendermintRPC(c) => c.broadcast_tx_block(tx).await,
+            NodeClient::CosmosgRPC(c) => c.broadcast_tx_block(tx).await,
+            NodeClient::CosmosREST(c) => c.broadcast_tx_block(tx).await,
+        };
+
+        res.map_err(|e| self.unavailable(e))
+    }
+}
+
+/// How a `FailoverClient` picks the node for each request
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub enum Routing {
+    /// Rotate through the healthy nodes
+    RoundRobin,
+
+    /// Prefer the healthy node with the lowest average response time
+    LowestLatency,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct FailoverOptions {
+    pub routing: Routing,
+
+    /// Time a node is skipped for after a transport error or failed health check.
+    /// It is tried again afterwards, and skipped nodes are still used once every other node failed.
+    pub cooldown: Duration,
+}
+
+impl Default for FailoverOptions {
+    fn default() -> Self {
+        Self {
+            routing: Routing::RoundRobin,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Health of a single `FailoverClient` node, as seen by the requests sent to it
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct NodeHealth {
+    /// Moving average of the node's response time, `None` until it answered a request
+    pub latency: Option<Duration>,
+
+    /// Number of transport errors or failed health checks since the node last answered
+    pub failures: u32,
+
+    /// Time of the last failure, the node is skipped until `FailoverOptions::cooldown` has elapsed
+    pub down_since: Option<Instant>,
+}
+
+impl NodeHealth {
+    pub fn is_healthy(&self, cooldown: Duration) -> bool {
+        !matches!(self.down_since, Some(t) if t.elapsed() < cooldown)
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    health: Vec<NodeHealth>,
+    next: usize,
+
+    /// Node each recently broadcast tx was sent to, by tx hash
+    pinned: VecDeque<(String, usize)>,
+}
+
+/// `CosmosClient` that spreads requests over several nodes of the same chain.
+///
+/// Queries and simulations are routed according to `FailoverOptions::routing`,
+/// and are sent to the next node whenever one fails with a transport error (see `ChainError::is_transport_error()`).
+/// Errors returned by the chain itself are not retried on other nodes.
+///
+/// Broadcasts are pinned: `wait_for_tx()` polls the node the tx was broadcast to,
+/// since other nodes may not have the tx in their mempool yet.
+/// It only falls back to other nodes if the pinned one becomes unreachable.
+/// A broadcast that is re-sent after a transport error succeeds if the next node already has the tx in its mempool.
+///
+/// Clones share the same nodes and health state.
+#[derive(Debug)]
+pub struct FailoverClient<T: CosmosClient = NodeClient> {
+    nodes: Arc<Vec<T>>,
+    state: Arc<Mutex<State>>,
+    options: FailoverOptions,
+}
+
+impl<T: CosmosClient> Clone for FailoverClient<T> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            state: self.state.clone(),
+            options: self.options,
+        }
+    }
+}
+
+impl<T: CosmosClient> FailoverClient<T> {
+    pub fn new(nodes: Vec<T>, options: FailoverOptions) -> Result<Self, ChainError> {
+        if nodes.is_empty() {
+            return Err(ChainError::MissingApiEndpoint {
+                api_type: "failover".to_string(),
+            });
+        }
+
+        Ok(Self {
+            state: Arc::new(Mutex::new(State {
+                health: vec![NodeHealth::default(); nodes.len()],
+                next: 0,
+                pinned: VecDeque::new(),
+            })),
+            nodes: Arc::new(nodes),
+            options,
+        })
+    }
+
+    pub fn nodes(&self) -> &[T] {
+        &self.nodes
+    }
+
+    /// Health of every node, in the same order as `nodes()`
+    pub fn health(&self) -> Vec<NodeHealth> {
+        self.state.lock().unwrap().health.clone()
+    }
+
+    /// Sends a `GetSyncing` query to every node, updating their health and latency.
+    /// Nodes that fail to answer or are still catching up are skipped for the cooldown.
+    ///
+    /// Failed requests already mark nodes as down, so this only needs to be called
+    /// periodically to detect recovered or syncing nodes early.
+    pub async fn health_check(&self) -> Vec<NodeHealth> {
+        for (i, node) in self.nodes.iter().enumerate() {
+            let start = Instant::now();
+
+            let res = node
+                .query::<_, GetSyncingResponse>(
+                    GetSyncingRequest {},
+                    "/cosmos.base.tendermint.v1beta1.Service/GetSyncing",
+                )
+                .await;
+
+            match res {
+                Ok(res) if !res.syncing => self.on_success(i, start.elapsed()),
+                _ => self.on_failure(i),
+            }
+        }
+
+        self.health()
+    }
+
+    /// Node indexes in the order they should be tried for the next request
+    fn route(&self) -> Vec<usize> {
+        let mut state = self.state.lock().unwrap();
+
+        let start = state.next % self.nodes.len();
+        state.next = state.next.wrapping_add(1);
+
+        let mut order: Vec<usize> = (0..self.nodes.len())
+            .map(|i| (start + i) % self.nodes.len())
+            .collect();
+
+        // stable sort, so ties keep their round robin order
+        order.sort_by_key(|&i| {
+            let health = &state.health[i];
+            let latency = match self.options.routing {
+                Routing::RoundRobin => Duration::ZERO,
+                // unmeasured nodes go first, to measure them
+                Routing::LowestLatency => health.latency.unwrap_or_default(),
+            };
+
+            (!health.is_healthy(self.options.cooldown), latency)
+        });
+
+        order
+    }
+
+    fn on_success(&self, i: usize, elapsed: Duration) {
+        let health = &mut self.state.lock().unwrap().health[i];
+
+        health.latency = Some(match health.latency {
+            Some(avg) => avg.mul_f64(1.0 - LATENCY_WEIGHT) + elapsed.mul_f64(LATENCY_WEIGHT),
+            None => elapsed,
+        });
+        health.failures = 0;
+        health.down_since = None;
+    }
+
+    fn on_failure(&self, i: usize) {
+        let health = &mut self.state.lock().unwrap().health[i];
+
+        health.failures += 1;
+        health.down_since = Some(Instant::now());
+    }
+
+    fn pin(&self, tx_hash: &str, i: usize) {
+        let pinned = &mut self.state.lock().unwrap().pinned;
+
+        if pinned.len() >= MAX_PINNED_TXS {
+            pinned.pop_front();
+        }
+        pinned.push_back((tx_hash.to_string(), i));
+    }
+
+    fn unpin(&self, tx_hash: &str) -> Option<usize> {
+        let pinned = &mut self.state.lock().unwrap().pinned;
+
+        let pos = pinned.iter().position(|(hash, _)| hash == tx_hash)?;
+        pinned.remove(pos).map(|(_, i)| i)
+    }
+
+    async fn query_routed<I, O>(
+        &self,
+        msg: I,
+        path: &str,
+        height: Option<u64>,
+    ) -> Result<O, ChainError>
+    where
+        T: Sync,
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        // `I` isn't `Clone`, so every attempt decodes its own copy of the request
+        let bytes = msg.encode_to_vec();
+
+        let (_, res) = self
+            .failover(self.route(), |node| {
+                let bytes = &bytes;
+                async move {
+                    let msg =
+                        I::decode(bytes.as_slice()).map_err(ChainError::prost_proto_decoding)?;
+
+                    match height {
+                        Some(height) => node.query_at::<I, O>(msg, path, height).await,
+                        None => node.query::<I, O>(msg, path).await,
+                    }
+                }
+            })
+            .await?;
+
+        Ok(res)
+    }
+
+    /// Sends the request to the nodes in `order` until one of them answers.
+    /// Returns the answer along with the index of the node that sent it.
+    async fn failover<'a, R, F, Fut>(
+        &'a self,
+        order: Vec<usize>,
+        request: F,
+    ) -> Result<(usize, R), ChainError>
+    where
+        F: Fn(&'a T) -> Fut,
+        Fut: Future<Output = Result<R, ChainError>>,
+    {
+        let mut last_err = None;
+
+        for i in order {
+            let start = Instant::now();
+
+            match request(&self.nodes[i]).await {
+                Err(e) if e.is_transport_error() => {
+                    self.on_failure(i);
+                    last_err = Some(e);
+                }
+                res => {
+                    self.on_success(i, start.elapsed());
+                    return res.map(|r| (i, r));
+                }
+            }
+        }
+
+        Err(last_err.expect("failover client has at least one node"))
+    }
+}
+
+#[async_trait]
+impl<T: CosmosClient + Send + Sync> CosmosClient for FailoverClient<T> {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.query_routed(msg, path, None).await
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.query_routed(msg, path, Some(height)).await
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let (_, res) = self
+            .failover(self.route(), |node| node.simulate_tx(tx))
+            .await?;
+
+        Ok(res)
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let tx_hash = Sha256::digest(tx.to_bytes()?)
+            .iter()
+            .map(|b| format!("{b:02X}"))
+            .collect::<String>();
+        let resent = AtomicBool::new(false);
+
+        let (i, res) = self
+            .failover(self.route(), |node| {
+                let resend = resent.swap(true, Ordering::Relaxed);
+                let tx_hash = &tx_hash;
+
+                async move {
+                    match node.broadcast_tx(tx, mode).await {
+                        // an earlier node failed after the tx got into its mempool, and it was gossiped to this one
+                        Err(e) if resend && in_mempool(&e) => Ok(AsyncChainTxResponse {
+                            res: ChainResponse {
+                                code: Code::Ok,
+                                ..Default::default()
+                            },
+                            tx_hash: tx_hash.clone(),
+                        }),
+                        res => res,
+                    }
+                }
+            })
+            .await?;
+
+        self.pin(&res.tx_hash, i);
+
+        Ok(res)
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        let mut order = self.route();
+
+        if let Some(pinned) = self.unpin(tx_hash) {
+            order.retain(|&i| i != pinned);
+            order.insert(0, pinned);
+        }
+
+        let (_, res) = self
+            .failover(order, |node| node.wait_for_tx(tx_hash))
+            .await?;
+
+        Ok(res)
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
+
+        self.wait_for_tx(&res.tx_hash).await
+    }
+}
+
+/// Returns true if the node rejected the tx because the same tx is already in its mempool
+fn in_mempool(e: &ChainError) -> bool {
+    match e {
+        // tendermint rpc returns the mempool error as is, the cosmos-sdk wraps it into `SdkError::TxInMempoolCache`
+        ChainError::RPC(e) => matches!(
+            e.detail(),
+            RPCErrorDetail::Response(d) if d.source.to_string().contains("tx already exists in cache")
+        ),
+        e => e.sdk_error() == Some(SdkError::TxInMempoolCache),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+    use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+
+    use crate::chain::error::ChainError;
+    use crate::chain::response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code};
+    use crate::clients::client::{CosmosClient, MockCosmosClient};
+    use crate::clients::cosmos_grpc::CosmosgRPC;
+    use crate::clients::cosmos_rest::CosmosREST;
+    use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+    use super::{FailoverClient, FailoverOptions, NodeClient};
+
+    const PATH: &str = "/cosmos.bank.v1beta1.Query/Balance";
+
+    fn unavailable() -> ChainError {
+        ChainError::Unavailable {
+            message: "connection refused".to_string(),
+        }
+    }
+
+    fn balance(amount: &str) -> QueryBalanceResponse {
+        QueryBalanceResponse {
+            balance: Some(Coin {
+                denom: "utest".to_string(),
+                amount: amount.to_string(),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failover_client() {
+        let mut down = MockCosmosClient::new();
+        down.expect_query::<QueryBalanceRequest, QueryBalanceResponse>()
+            .times(1)
+            .returning(|_, _| Err(unavailable()));
+        down.expect_broadcast_tx().never();
+
+        let mut up = MockCosmosClient::new();
+        up.expect_query::<QueryBalanceRequest, QueryBalanceResponse>()
+            .times(3)
+            .returning(|req, path| {
+                assert_eq!(req.address, "addr");
+                assert_eq!(path, PATH);
+                Ok(balance("100"))
+            });
+        up.expect_broadcast_tx().times(1).returning(|_, mode| {
+            assert_eq!(mode, BroadcastMode::Sync);
+            Ok(AsyncChainTxResponse {
+                res: ChainResponse::default(),
+                tx_hash: "TX_HASH_0".to_string(),
+            })
+        });
+        up.expect_wait_for_tx().times(1).returning(|tx_hash| {
+            Ok(ChainTxResponse {
+                tx_hash: tx_hash.to_string(),
+                height: 1337,
+                ..Default::default()
+            })
+        });
+
+        let client = FailoverClient::new(vec![down, up], FailoverOptions::default()).unwrap();
+        let req = || QueryBalanceRequest {
+            address: "addr".to_string(),
+            denom: "utest".to_string(),
+        };
+
+        // first node is down, the query fails over to the second one
+        let res: QueryBalanceResponse = client.query(req(), PATH).await.unwrap();
+        assert_eq!(res, balance("100"));
+
+        let health = client.health();
+        assert_eq!(health[0].failures, 1);
+        assert!(!health[0].is_healthy(FailoverOptions::default().cooldown));
+        assert!(health[1].latency.is_some());
+
+        // the down node is skipped until its cooldown elapsed
+        let _: QueryBalanceResponse = client.query(req(), PATH).await.unwrap();
+        let _: QueryBalanceResponse = client.query(req(), PATH).await.unwrap();
+
+        // broadcast and polling go to the same node
+        let tx = RawTx::from(TxRaw::default());
+        let res = client.broadcast_tx_block(&tx).await.unwrap();
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+        assert_eq!(res.height, 1337);
+    }
+
+    #[tokio::test]
+    async fn test_failover_client_chain_error() {
+        let mut first = MockCosmosClient::new();
+        first
+            .expect_query::<QueryBalanceRequest, QueryBalanceResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Err(ChainError::CosmosSdk {
+                    res: ChainResponse {
+                        code: Code::Err(3),
+                        log: "invalid address".to_string(),
+                        ..Default::default()
+                    },
+                })
+            });
+
+        let mut second = MockCosmosClient::new();
+        second
+            .expect_query::<QueryBalanceRequest, QueryBalanceResponse>()
+            .never();
+
+        let client = FailoverClient::new(vec![first, second], FailoverOptions::default()).unwrap();
+
+        // errors returned by the chain are not retried on other nodes
+        let err = client
+            .query::<_, QueryBalanceResponse>(QueryBalanceRequest::default(), PATH)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ChainError::CosmosSdk { .. }));
+        assert_eq!(client.health()[0].failures, 0);
+
+        assert!(matches!(
+            FailoverClient::<MockCosmosClient>::new(vec![], FailoverOptions::default()),
+            Err(ChainError::MissingApiEndpoint { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_failover_client_resent_broadcast() {
+        let in_mempool = || {
+            Err(ChainError::CosmosSdk {
+                res: ChainResponse {
+                    code: Code::Err(19),
+                    log: "tx already exists in cache: tx already in mempool".to_string(),
+                    codespace: "sdk".to_string(),
+                    ..Default::default()
+                },
+            })
+        };
+
+        let mut timed_out = MockCosmosClient::new();
+        timed_out
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(|_, _| Err(unavailable()));
+        timed_out.expect_wait_for_tx().never();
+
+        let mut next = MockCosmosClient::new();
+        next.expect_broadcast_tx()
+            .times(1)
+            .returning(move |_, _| in_mempool());
+        next.expect_wait_for_tx().times(1).returning(|tx_hash| {
+            Ok(ChainTxResponse {
+                tx_hash: tx_hash.to_string(),
+                height: 1337,
+                ..Default::default()
+            })
+        });
+
+        let client =
+            FailoverClient::new(vec![timed_out, next], FailoverOptions::default()).unwrap();
+        let tx = RawTx::from(TxRaw::default());
+
+        // the first node got the tx into the mempool before timing out
+        let res = client.broadcast_tx_block(&tx).await.unwrap();
+        assert_eq!(
+            res.tx_hash,
+            "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855"
+        );
+        assert_eq!(res.height, 1337);
+
+        // the tx wasn't re-sent, so it's still an error
+        let mut only = MockCosmosClient::new();
+        only.expect_broadcast_tx()
+            .times(1)
+            .returning(move |_, _| in_mempool());
+
+        let client = FailoverClient::new(vec![only], FailoverOptions::default()).unwrap();
+        let err = client
+            .broadcast_tx(&tx, BroadcastMode::Sync)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ChainError::CosmosSdk { .. }));
+    }
+
+    #[test]
+    fn test_node_client_unavailable() {
+        let err = |code, codespace: &str| ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(code),
+                log: "upstream unavailable".to_string(),
+                codespace: codespace.to_string(),
+                ..Default::default()
+            },
+        };
+
+        let grpc = NodeClient::from(CosmosgRPC::new("http://localhost:9090".to_string()));
+        assert!(matches!(
+            grpc.unavailable(err(14, "")),
+            ChainError::Unavailable { message } if message == "upstream unavailable"
+        ));
+        assert!(matches!(
+            grpc.unavailable(err(4, "")),
+            ChainError::Unavailable { .. }
+        ));
+        // sdk errors with the same code are chain errors
+        assert!(matches!(
+            grpc.unavailable(err(4, "sdk")),
+            ChainError::CosmosSdk { .. }
+        ));
+        assert!(matches!(
+            grpc.unavailable(err(503, "")),
+            ChainError::CosmosSdk { .. }
+        ));
+
+        let rest = NodeClient::from(CosmosREST::new("http://localhost:1317".to_string()));
+        assert!(matches!(
+            rest.unavailable(err(503, "")),
+            ChainError::Unavailable { .. }
+        ));
+        assert!(matches!(
+            rest.unavailable(err(400, "")),
+            ChainError::CosmosSdk { .. }
+        ));
+        assert!(rest.unavailable(err(502, "")).is_transport_error());
+    }
+}
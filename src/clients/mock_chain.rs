@@ -0,0 +1,1886 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cosmrs::bip32::secp256k1::ecdsa::signature::Verifier;
+use cosmrs::crypto::secp256k1::{Signature, VerifyingKey};
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::auth::v1beta1::{
+    BaseAccount, QueryAccountRequest, QueryAccountResponse,
+};
+use cosmrs::proto::cosmos::bank::v1beta1::{
+    MsgSend, QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest,
+    QueryBalanceResponse,
+};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance,
+};
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    AuthInfo, GetTxRequest, GetTxResponse, SignDoc, Tx, TxBody, TxRaw,
+};
+use cosmrs::proto::cosmwasm::wasm::v1::{
+    ContractInfo, MsgExecuteContract, MsgInstantiateContract, MsgStoreCode,
+    QueryContractInfoRequest, QueryContractInfoResponse, QueryRawContractStateRequest,
+    QueryRawContractStateResponse, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+};
+use cosmrs::proto::traits::{Message, TypeUrl};
+use cosmrs::Any;
+use sha2::{Digest, Sha256};
+
+use crate::chain::coin::Coin;
+use crate::chain::error::ChainError;
+use crate::chain::fee::GasInfo;
+use crate::chain::response::{
+    AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code, Event, Tag,
+};
+use crate::config::cfg::ChainConfig;
+use crate::modules::auth::model::Address;
+use crate::modules::cosmwasm::model::instantiate2_address;
+use crate::modules::cosmwasm::proto::MsgInstantiateContract2;
+use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+use super::client::CosmosClient;
+
+/// Gas charged for every tx, on top of `GAS_PER_MSG_BYTE`
+const BASE_GAS: u64 = 50_000;
+
+/// Gas charged for every byte of the encoded tx msgs.
+/// The memo and signatures are not charged, so simulated txs use exactly as much gas as the signed ones.
+const GAS_PER_MSG_BYTE: u64 = 10;
+
+/// Raw key-value storage of a `MockContract` instance
+pub type MockStorage = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// Contract logic that `MockChain` runs in place of the stored wasm bytecode.
+///
+/// Contracts keep their state in the instance's `storage`,
+/// which is rolled back along with the rest of the tx if it fails.
+/// Errors are reported the way wasmd reports contract errors, eg. as `SdkError::WasmExecuteFailed`.
+pub trait MockContract: Send + Sync {
+    fn instantiate(
+        &self,
+        storage: &mut MockStorage,
+        env: &MockEnv,
+        msg: &[u8],
+    ) -> Result<MockContractResponse, String>;
+
+    fn execute(
+        &self,
+        storage: &mut MockStorage,
+        env: &MockEnv,
+        msg: &[u8],
+    ) -> Result<MockContractResponse, String>;
+
+    fn query(&self, storage: &MockStorage, msg: &[u8]) -> Result<Vec<u8>, String>;
+}
+
+/// Context of a `MockContract` instantiate or execute call
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MockEnv {
+    pub block_height: u64,
+    pub contract: Address,
+    pub sender: Address,
+    /// Funds sent with the msg, already transferred to `contract`
+    pub funds: Vec<Coin>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MockContractResponse {
+    /// Returned as the tx response data
+    pub data: Option<Vec<u8>>,
+
+    /// Emitted in a `wasm` event after the contract address
+    pub attributes: Vec<Tag>,
+}
+
+/// In-process fake chain for testing `CosmTome` flows without a node.
+///
+/// Tracks accounts, sequences, bank balances, fee allowances and wasm codes / contracts, where contract logic is provided
+/// by the `MockContract` registered for the stored bytecode. Broadcast txs are decoded, their signature
+/// and sequence are verified, and they are committed right away in their own block with events modeled
+/// after cosmos-sdk v0.45 and wasmd.
+///
+/// Supports `MsgSend`, `MsgStoreCode`, `MsgInstantiateContract`, `MsgInstantiateContract2`, `MsgExecuteContract`,
+/// `MsgGrantAllowance` and `MsgRevokeAllowance` txs, and the auth account, bank balance, wasm contract and `GetTx` queries.
+/// Contracts can't send submessages. Only `BasicAllowance` fee grants are supported, and their expiration is ignored
+/// since mock blocks have no time.
+///
+/// Clones share the same chain state.
+#[derive(Clone)]
+pub struct MockChain {
+    chain_id: String,
+    prefix: String,
+    state: Arc<Mutex<State>>,
+}
+
+impl fmt::Debug for MockChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockChain")
+            .field("chain_id", &self.chain_id)
+            .field("prefix", &self.prefix)
+            .field("height", &self.height())
+            .finish_non_exhaustive()
+    }
+}
+
+impl MockChain {
+    /// Creates an empty chain with the `chain_id` and address `prefix` of `cfg`
+    pub fn new(cfg: &ChainConfig) -> Self {
+        Self {
+            chain_id: cfg.chain_id.clone(),
+            prefix: cfg.prefix.clone(),
+            state: Arc::default(),
+        }
+    }
+
+    /// Adds `coins` to the balance of `address`, creating its account if needed
+    pub fn mint(&self, address: &Address, coins: Vec<Coin>) {
+        let coins: Vec<ProtoCoin> = coins.into_iter().map(Into::into).collect();
+
+        self.state
+            .lock()
+            .unwrap()
+            .world
+            .add_coins(address.as_ref(), &coins)
+            .expect("minted coins are valid");
+    }
+
+    /// Runs `contract` for every code stored with the `wasm` bytecode
+    pub fn register_contract(&self, wasm: &[u8], contract: impl MockContract + 'static) {
+        self.state
+            .lock()
+            .unwrap()
+            .contracts
+            .insert(Sha256::digest(wasm).to_vec(), Arc::new(contract));
+    }
+
+    pub fn balance(&self, address: &Address, denom: &str) -> u128 {
+        self.state
+            .lock()
+            .unwrap()
+            .world
+            .balance(address.as_ref(), denom)
+    }
+
+    pub fn sequence(&self, address: &Address) -> Option<u64> {
+        self.state
+            .lock()
+            .unwrap()
+            .world
+            .accounts
+            .get(address.as_ref())
+            .map(|a| a.sequence)
+    }
+
+    pub fn height(&self) -> u64 {
+        self.state.lock().unwrap().world.height
+    }
+
+    fn decode_tx(&self, tx: &RawTx) -> Result<DecodedTx, ChainError> {
+        let bytes = tx.to_bytes()?;
+        DecodedTx::decode(&bytes).map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl CosmosClient for MockChain {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let res = self
+            .state
+            .lock()
+            .unwrap()
+            .query(path, &msg.encode_to_vec(), None)
+            .map_err(ChainError::tonic_status)?;
+
+        O::decode(res.as_slice()).map_err(ChainError::prost_proto_decoding)
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let res = self
+            .state
+            .lock()
+            .unwrap()
+            .query(path, &msg.encode_to_vec(), Some(height))
+            .map_err(ChainError::tonic_status)?;
+
+        O::decode(res.as_slice()).map_err(ChainError::prost_proto_decoding)
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let tx = self.decode_tx(tx)?;
+        let state = self.state.lock().unwrap();
+
+        let mut world = state.world.clone();
+        world.ante(&tx, &self.chain_id, &self.prefix, true)?;
+        world.height += 1;
+        world.run_msgs(&tx, &state.contracts, &self.prefix)?;
+
+        Ok(GasInfo {
+            gas_wanted: tx.gas_limit().into(),
+            gas_used: tx.gas_used().into(),
+        })
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        _mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let tx = self.decode_tx(tx)?;
+        let mut state = self.state.lock().unwrap();
+
+        // CheckTx
+        let mut world = state.world.clone();
+        world.ante(&tx, &self.chain_id, &self.prefix, false)?;
+
+        // DeliverTx, the fee and sequence are kept even if the msgs fail
+        let previous = state.world.clone();
+        state.history.insert(previous.height, previous);
+        world.height += 1;
+        let committed = world.clone();
+
+        let mut events = tx.tx_events();
+        let res = if tx.gas_used() > tx.gas_limit() {
+            Err(TxFailure::sdk(
+                11,
+                format!(
+                    "out of gas in location: mock chain; gasWanted: {}, gasUsed: {}: out of gas",
+                    tx.gas_limit(),
+                    tx.gas_used()
+                ),
+            ))
+        } else {
+            world.run_msgs(&tx, &state.contracts, &self.prefix)
+        };
+
+        let res = match res {
+            Ok((msg_events, data)) => {
+                events.extend(msg_events);
+
+                ChainResponse {
+                    code: Code::Ok,
+                    data,
+                    log: "[]".to_string(),
+                    codespace: String::new(),
+                }
+            }
+            Err(e) => {
+                world = committed;
+                e.into()
+            }
+        };
+
+        let res = ChainTxResponse {
+            res,
+            events,
+            gas_wanted: tx.gas_limit(),
+            gas_used: tx.gas_used(),
+            tx_hash: tx.hash.clone(),
+            height: world.height,
+        };
+
+        state.world = world;
+        state.txs.insert(tx.hash.clone(), (tx.raw, res));
+
+        Ok(AsyncChainTxResponse {
+            res: ChainResponse {
+                code: Code::Ok,
+                data: None,
+                log: "[]".to_string(),
+                codespace: String::new(),
+            },
+            tx_hash: tx.hash,
+        })
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        let state = self.state.lock().unwrap();
+
+        // txs are committed as soon as they are broadcast
+        let (_, res) = state
+            .txs
+            .get(tx_hash)
+            .ok_or_else(|| ChainError::TxPollTimeout {
+                tx_hash: tx_hash.to_string(),
+                timeout: Duration::ZERO,
+            })?;
+
+        if res.res.code.is_err() {
+            return Err(ChainError::CosmosSdk {
+                res: res.res.clone(),
+            });
+        }
+
+        Ok(res.clone())
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
+
+        self.wait_for_tx(&res.tx_hash).await
+    }
+}
+
+#[derive(Default)]
+struct State {
+    world: World,
+    contracts: BTreeMap<Vec<u8>, Arc<dyn MockContract>>,
+    txs: BTreeMap<String, (TxRaw, ChainTxResponse)>,
+
+    /// State as of the end of every past height, for `query_at()`
+    history: BTreeMap<u64, World>,
+}
+
+impl State {
+    fn query(&self, path: &str, req: &[u8], height: Option<u64>) -> Result<Vec<u8>, tonic::Status> {
+        let world = match height {
+            Some(height) if height > self.world.height => {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "cannot query with height in the future; please provide a valid height: {height}"
+                )))
+            }
+            Some(height) if height < self.world.height => &self.history[&height],
+            _ => &self.world,
+        };
+
+        let res = match path {
+            "/cosmos.auth.v1beta1.Query/Account" => {
+                let req = QueryAccountRequest::decode(req).map_err(invalid_request)?;
+                let account = world
+                    .accounts
+                    .get(&req.address)
+                    .ok_or_else(|| not_found(format!("account {} not found", req.address)))?;
+
+                let base_account = BaseAccount {
+                    address: req.address,
+                    pub_key: account
+                        .pubkey
+                        .map(|k| k.to_any())
+                        .transpose()
+                        .ok()
+                        .flatten(),
+                    account_number: account.account_number,
+                    sequence: account.sequence,
+                };
+
+                QueryAccountResponse {
+                    account: Some(Any {
+                        type_url: "/cosmos.auth.v1beta1.BaseAccount".to_string(),
+                        value: base_account.encode_to_vec(),
+                    }),
+                }
+                .encode_to_vec()
+            }
+
+            "/cosmos.bank.v1beta1.Query/Balance" => {
+                let req = QueryBalanceRequest::decode(req).map_err(invalid_request)?;
+
+                QueryBalanceResponse {
+                    balance: Some(ProtoCoin {
+                        amount: world.balance(&req.address, &req.denom).to_string(),
+                        denom: req.denom,
+                    }),
+                }
+                .encode_to_vec()
+            }
+
+            "/cosmos.bank.v1beta1.Query/AllBalances" => {
+                let req = QueryAllBalancesRequest::decode(req).map_err(invalid_request)?;
+
+                QueryAllBalancesResponse {
+                    balances: world
+                        .balances
+                        .get(&req.address)
+                        .into_iter()
+                        .flatten()
+                        .filter(|(_, amount)| **amount > 0)
+                        .map(|(denom, amount)| ProtoCoin {
+                            denom: denom.clone(),
+                            amount: amount.to_string(),
+                        })
+                        .collect(),
+                    pagination: None,
+                }
+                .encode_to_vec()
+            }
+
+            "/cosmwasm.wasm.v1.Query/SmartContractState" => {
+                let req = QuerySmartContractStateRequest::decode(req).map_err(invalid_request)?;
+                let instance = world.instance(&req.address)?;
+                let contract = self.contract(
+                    world.codes[instance.code_id as usize - 1]
+                        .checksum
+                        .as_slice(),
+                )?;
+
+                let data = contract
+                    .query(&instance.storage, &req.query_data)
+                    .map_err(|e| {
+                        tonic::Status::unknown(format!("{e}: query wasm contract failed"))
+                    })?;
+
+                QuerySmartContractStateResponse { data }.encode_to_vec()
+            }
+
+            "/cosmwasm.wasm.v1.Query/RawContractState" => {
+                let req = QueryRawContractStateRequest::decode(req).map_err(invalid_request)?;
+                let instance = world.instance(&req.address)?;
+
+                QueryRawContractStateResponse {
+                    data: instance
+                        .storage
+                        .get(&req.query_data)
+                        .cloned()
+                        .unwrap_or_default(),
+                }
+                .encode_to_vec()
+            }
+
+            "/cosmwasm.wasm.v1.Query/ContractInfo" => {
+                let req = QueryContractInfoRequest::decode(req).map_err(invalid_request)?;
+                let instance = world.instance(&req.address)?;
+
+                QueryContractInfoResponse {
+                    address: req.address,
+                    contract_info: Some(ContractInfo {
+                        code_id: instance.code_id,
+                        creator: instance.creator.clone(),
+                        admin: instance.admin.clone(),
+                        label: instance.label.clone(),
+                        ..Default::default()
+                    }),
+                }
+                .encode_to_vec()
+            }
+
+            "/cosmos.tx.v1beta1.Service/GetTx" => {
+                let req = GetTxRequest::decode(req).map_err(invalid_request)?;
+                let (raw, res) = self
+                    .txs
+                    .get(&req.hash)
+                    .ok_or_else(|| not_found(format!("tx not found: {}", req.hash)))?;
+
+                GetTxResponse {
+                    tx: Some(Tx {
+                        body: TxBody::decode(raw.body_bytes.as_slice()).ok(),
+                        auth_info: AuthInfo::decode(raw.auth_info_bytes.as_slice()).ok(),
+                        signatures: raw.signatures.clone(),
+                    }),
+                    tx_response: Some(TxResponse {
+                        height: res.height as i64,
+                        txhash: res.tx_hash.clone(),
+                        codespace: res.res.codespace.clone(),
+                        code: res.res.code.value(),
+                        data: String::from_utf8(res.res.data.clone().unwrap_or_default())
+                            .unwrap_or_default(),
+                        raw_log: res.res.log.clone(),
+                        gas_wanted: res.gas_wanted as i64,
+                        gas_used: res.gas_used as i64,
+                        events: res.events.iter().cloned().map(Into::into).collect(),
+                        ..Default::default()
+                    }),
+                }
+                .encode_to_vec()
+            }
+
+            path => {
+                return Err(tonic::Status::unimplemented(format!(
+                    "unknown query path: {path}"
+                )))
+            }
+        };
+
+        Ok(res)
+    }
+
+    fn contract(&self, checksum: &[u8]) -> Result<&Arc<dyn MockContract>, tonic::Status> {
+        self.contracts
+            .get(checksum)
+            .ok_or_else(|| tonic::Status::unknown("no MockContract registered for the code"))
+    }
+}
+
+#[derive(Clone, Default)]
+struct World {
+    height: u64,
+    accounts: BTreeMap<String, MockAccount>,
+    balances: BTreeMap<String, BTreeMap<String, u128>>,
+    codes: Vec<MockCode>,
+    instances: BTreeMap<String, MockInstance>,
+    instance_count: u64,
+    /// Spend limit of the fee allowance of every (granter, grantee), `None` for no limit
+    allowances: BTreeMap<(String, String), Option<BTreeMap<String, u128>>>,
+}
+
+#[derive(Clone)]
+struct MockAccount {
+    account_number: u64,
+    sequence: u64,
+    pubkey: Option<PublicKey>,
+}
+
+#[derive(Clone)]
+struct MockCode {
+    checksum: Vec<u8>,
+}
+
+#[derive(Clone)]
+struct MockInstance {
+    code_id: u64,
+    creator: String,
+    admin: String,
+    label: String,
+    storage: MockStorage,
+}
+
+impl World {
+    /// Checks the signer, sequence and signature and charges the fee, like the cosmos-sdk `AnteHandler`
+    fn ante(
+        &mut self,
+        tx: &DecodedTx,
+        chain_id: &str,
+        prefix: &str,
+        simulate: bool,
+    ) -> Result<(), TxFailure> {
+        let account = self.accounts.get(&tx.signer).cloned().ok_or_else(|| {
+            TxFailure::sdk(
+                9,
+                format!("account {} not found: unknown address", tx.signer),
+            )
+        })?;
+
+        let signer_info = tx
+            .auth_info
+            .signer_infos
+            .first()
+            .ok_or_else(|| TxFailure::sdk(15, "no signatures supplied"))?;
+
+        if signer_info.sequence != account.sequence {
+            return Err(TxFailure::sdk(
+                32,
+                format!(
+                    "account sequence mismatch, expected {}, got {}: incorrect account sequence",
+                    account.sequence, signer_info.sequence
+                ),
+            ));
+        }
+
+        let mut pubkey = account.pubkey;
+
+        // simulated txs are sent without a signature
+        if !simulate {
+            if let Some(key) = &signer_info.public_key {
+                pubkey = Some(
+                    PublicKey::try_from(key)
+                        .map_err(|e| TxFailure::sdk(8, format!("{e}: invalid pubkey")))?,
+                );
+            }
+
+            let key = pubkey
+                .ok_or_else(|| TxFailure::sdk(8, "pubkey on account is not set: invalid pubkey"))?;
+
+            if key
+                .account_id(prefix)
+                .map(|id| id.to_string())
+                .ok()
+                .as_ref()
+                != Some(&tx.signer)
+            {
+                return Err(TxFailure::sdk(
+                    8,
+                    format!(
+                        "pubKey does not match signer address {}: invalid pubkey",
+                        tx.signer
+                    ),
+                ));
+            }
+
+            let sign_doc = SignDoc {
+                body_bytes: tx.raw.body_bytes.clone(),
+                auth_info_bytes: tx.raw.auth_info_bytes.clone(),
+                chain_id: chain_id.to_string(),
+                account_number: account.account_number,
+            };
+
+            let verified = VerifyingKey::from_sec1_bytes(&key.to_bytes())
+                .ok()
+                .zip(
+                    tx.raw
+                        .signatures
+                        .first()
+                        .and_then(|s| Signature::try_from(s.as_slice()).ok()),
+                )
+                .is_some_and(|(vk, sig)| vk.verify(&sign_doc.encode_to_vec(), &sig).is_ok());
+
+            if !verified {
+                return Err(TxFailure::sdk(
+                    4,
+                    format!(
+                        "signature verification failed; please verify account number ({}) and chain-id ({}): unauthorized",
+                        account.account_number, chain_id
+                    ),
+                ));
+            }
+        }
+
+        // simulated txs carry a zero fee, but still need a fee allowance from the granter
+        if let Some(fee) = &tx.auth_info.fee {
+            let fee_payer = if fee.payer.is_empty() {
+                &tx.signer
+            } else {
+                &fee.payer
+            };
+
+            let deduct_from = if fee.granter.is_empty() {
+                fee_payer
+            } else {
+                if fee.granter != *fee_payer {
+                    self.use_granted_fees(&fee.granter, fee_payer, &fee.amount)?;
+                }
+                &fee.granter
+            };
+
+            self.sub_coins(deduct_from, &fee.amount)?;
+        }
+
+        let account = self.accounts.get_mut(&tx.signer).unwrap();
+        account.pubkey = pubkey;
+        account.sequence += 1;
+
+        Ok(())
+    }
+
+    /// Deducts `fee` from the allowance `granter` gave to `grantee`, like the feegrant keeper's `UseGrantedFees`
+    fn use_granted_fees(
+        &mut self,
+        granter: &str,
+        grantee: &str,
+        fee: &[ProtoCoin],
+    ) -> Result<(), TxFailure> {
+        let key = (granter.to_string(), grantee.to_string());
+        let denied =
+            |log: &str| format!("{granter} does not not allow to pay fees for {grantee}: {log}");
+
+        let spend_limit = match self.allowances.get_mut(&key) {
+            Some(Some(spend_limit)) => spend_limit,
+            Some(None) => return Ok(()),
+            None => {
+                return Err(TxFailure::feegrant(
+                    5,
+                    denied("fee-grant not found: no allowance"),
+                ))
+            }
+        };
+
+        let mut left = spend_limit.clone();
+        for coin in fee {
+            let amount = parse_amount(coin)?;
+
+            match left.get_mut(&coin.denom) {
+                Some(limit) if *limit >= amount => *limit -= amount,
+                _ => {
+                    return Err(TxFailure::feegrant(
+                        2,
+                        denied("basic allowance: fee limit exceeded"),
+                    ))
+                }
+            }
+        }
+        left.retain(|_, amount| *amount > 0);
+
+        // spent allowances are removed
+        if left.is_empty() {
+            self.allowances.remove(&key);
+        } else {
+            *spend_limit = left;
+        }
+
+        Ok(())
+    }
+
+    /// Runs every msg of `tx`, returning their events and the data of the last msg that returned some
+    fn run_msgs(
+        &mut self,
+        tx: &DecodedTx,
+        contracts: &BTreeMap<Vec<u8>, Arc<dyn MockContract>>,
+        prefix: &str,
+    ) -> Result<(Vec<Event>, Option<Vec<u8>>), TxFailure> {
+        let mut events = vec![];
+        let mut data = None;
+
+        for (i, msg) in tx.msgs.iter().enumerate() {
+            events.push(event(
+                "message",
+                [
+                    ("action", msg.type_url()),
+                    ("module", msg.module()),
+                    ("sender", &tx.signer),
+                ],
+            ));
+
+            let (msg_events, msg_data) =
+                self.run_msg(msg, contracts, prefix)
+                    .map_err(|e| TxFailure {
+                        log: format!("failed to execute message; message index: {i}: {}", e.log),
+                        ..e
+                    })?;
+
+            events.extend(msg_events);
+            data = msg_data.or(data);
+        }
+
+        Ok((events, data))
+    }
+
+    fn run_msg(
+        &mut self,
+        msg: &MockMsg,
+        contracts: &BTreeMap<Vec<u8>, Arc<dyn MockContract>>,
+        prefix: &str,
+    ) -> Result<(Vec<Event>, Option<Vec<u8>>), TxFailure> {
+        match msg {
+            MockMsg::Send(msg) => {
+                let events = self.transfer(&msg.from_address, &msg.to_address, &msg.amount)?;
+                Ok((events, None))
+            }
+
+            MockMsg::GrantAllowance(msg) => {
+                let key = (msg.granter.clone(), msg.grantee.clone());
+                if self.allowances.contains_key(&key) {
+                    return Err(TxFailure::sdk(
+                        18,
+                        "fee allowance already exists: invalid request",
+                    ));
+                }
+
+                let allowance = msg.allowance.as_ref().ok_or_else(|| {
+                    TxFailure::feegrant(5, "failed to get allowance: no allowance")
+                })?;
+
+                if allowance.type_url != BasicAllowance::TYPE_URL {
+                    return Err(TxFailure::sdk(
+                        18,
+                        format!(
+                            "{} is not supported by MockChain: invalid request",
+                            allowance.type_url
+                        ),
+                    ));
+                }
+
+                let allowance = BasicAllowance::decode(allowance.value.as_slice())
+                    .map_err(|e| TxFailure::sdk(2, format!("{e}: tx parse error")))?;
+
+                let spend_limit = if allowance.spend_limit.is_empty() {
+                    None
+                } else {
+                    let mut spend_limit = BTreeMap::new();
+                    for coin in &allowance.spend_limit {
+                        *spend_limit.entry(coin.denom.clone()).or_default() += parse_amount(coin)?;
+                    }
+                    Some(spend_limit)
+                };
+
+                self.allowances.insert(key, spend_limit);
+
+                let event = event(
+                    "set_feegrant",
+                    [("granter", msg.granter.as_str()), ("grantee", &msg.grantee)],
+                );
+
+                Ok((vec![event], None))
+            }
+
+            MockMsg::RevokeAllowance(msg) => {
+                self.allowances
+                    .remove(&(msg.granter.clone(), msg.grantee.clone()))
+                    .ok_or_else(|| TxFailure::feegrant(5, "fee-grant not found: no allowance"))?;
+
+                let event = event(
+                    "revoke_feegrant",
+                    [("granter", msg.granter.as_str()), ("grantee", &msg.grantee)],
+                );
+
+                Ok((vec![event], None))
+            }
+
+            MockMsg::StoreCode(msg) => {
+                let checksum = Sha256::digest(&msg.wasm_byte_code).to_vec();
+                let code_checksum = checksum
+                    .iter()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>();
+
+                self.codes.push(MockCode { checksum });
+
+                let event = event(
+                    "store_code",
+                    [
+                        ("code_checksum", code_checksum.as_str()),
+                        ("code_id", &self.codes.len().to_string()),
+                    ],
+                );
+
+                Ok((vec![event], None))
+            }
+
+            MockMsg::Instantiate(msg) => {
+                self.instance_count += 1;
+
+                let mut key = msg.code_id.to_be_bytes().to_vec();
+                key.extend_from_slice(&self.instance_count.to_be_bytes());
+
+                // wasmd `BuildContractAddressClassic`
+                let addr = Sha256::new()
+                    .chain_update(Sha256::digest(b"module"))
+                    .chain_update(b"wasm\0")
+                    .chain_update(&key)
+                    .finalize();
+
+                let contract = Address::new(prefix, &addr)
+                    .map_err(|e| TxFailure::sdk(7, format!("{e}: invalid address")))?;
+
+                self.instantiate(
+                    contract,
+                    &msg.sender,
+                    &msg.admin,
+                    msg.code_id,
+                    &msg.label,
+                    &msg.msg,
+                    &msg.funds,
+                    contracts,
+                )
+            }
+
+            MockMsg::Instantiate2(msg) => {
+                if msg.fix_msg {
+                    return Err(TxFailure::sdk(
+                        18,
+                        "fix_msg is not supported by MockChain: invalid request",
+                    ));
+                }
+
+                let checksum = self.code(msg.code_id)?.checksum.clone();
+                let creator = parse_addr(&msg.sender)?;
+
+                let contract = instantiate2_address(&checksum, &creator, &msg.salt, prefix)
+                    .map_err(|e| TxFailure::sdk(18, format!("{e}: invalid request")))?;
+
+                if self.instances.contains_key(contract.as_ref()) {
+                    return Err(TxFailure::wasm(
+                        15,
+                        format!("contract address {contract}: duplicate"),
+                    ));
+                }
+
+                self.instantiate(
+                    contract,
+                    &msg.sender,
+                    &msg.admin,
+                    msg.code_id,
+                    &msg.label,
+                    &msg.msg,
+                    &msg.funds,
+                    contracts,
+                )
+            }
+
+            MockMsg::Execute(msg) => {
+                let instance = self
+                    .instances
+                    .get(&msg.contract)
+                    .ok_or_else(|| TxFailure::wasm(8, "contract: not found"))?;
+                let contract = mock_contract(contracts, &self.code(instance.code_id)?.checksum)
+                    .map_err(|e| {
+                        TxFailure::wasm(5, format!("{e}: execute wasm contract failed"))
+                    })?;
+
+                let mut events = self.transfer(&msg.sender, &msg.contract, &msg.funds)?;
+
+                let env = self.env(&msg.contract, &msg.sender, &msg.funds)?;
+                let instance = self.instances.get_mut(&msg.contract).unwrap();
+
+                let res = contract
+                    .execute(&mut instance.storage, &env, &msg.msg)
+                    .map_err(|e| {
+                        TxFailure::wasm(5, format!("{e}: execute wasm contract failed"))
+                    })?;
+
+                events.push(event(
+                    "execute",
+                    [("_contract_address", msg.contract.as_str())],
+                ));
+                events.extend(wasm_event(&msg.contract, res.attributes));
+
+                Ok((events, res.data))
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn instantiate(
+        &mut self,
+        contract: Address,
+        sender: &str,
+        admin: &str,
+        code_id: u64,
+        label: &str,
+        msg: &[u8],
+        funds: &[ProtoCoin],
+        contracts: &BTreeMap<Vec<u8>, Arc<dyn MockContract>>,
+    ) -> Result<(Vec<Event>, Option<Vec<u8>>), TxFailure> {
+        let handler = mock_contract(contracts, &self.code(code_id)?.checksum)
+            .map_err(|e| TxFailure::wasm(4, format!("{e}: instantiate wasm contract failed")))?;
+
+        let contract_addr = contract.to_string();
+        let mut events = self.transfer(sender, &contract_addr, funds)?;
+
+        let env = self.env(&contract_addr, sender, funds)?;
+        let mut storage = MockStorage::new();
+
+        let res = handler
+            .instantiate(&mut storage, &env, msg)
+            .map_err(|e| TxFailure::wasm(4, format!("{e}: instantiate wasm contract failed")))?;
+
+        self.instances.insert(
+            contract_addr.clone(),
+            MockInstance {
+                code_id,
+                creator: sender.to_string(),
+                admin: admin.to_string(),
+                label: label.to_string(),
+                storage,
+            },
+        );
+
+        events.push(event(
+            "instantiate",
+            [
+                ("_contract_address", contract_addr.as_str()),
+                ("code_id", &code_id.to_string()),
+            ],
+        ));
+        events.extend(wasm_event(&contract_addr, res.attributes));
+
+        Ok((events, res.data))
+    }
+
+    fn env(&self, contract: &str, sender: &str, funds: &[ProtoCoin]) -> Result<MockEnv, TxFailure> {
+        Ok(MockEnv {
+            block_height: self.height,
+            contract: parse_addr(contract)?,
+            sender: parse_addr(sender)?,
+            funds: funds
+                .iter()
+                .cloned()
+                .map(Coin::try_from)
+                .collect::<Result<_, _>>()
+                .map_err(|e| TxFailure::sdk(10, format!("{e}: invalid coins")))?,
+        })
+    }
+
+    fn code(&self, code_id: u64) -> Result<&MockCode, TxFailure> {
+        code_id
+            .checked_sub(1)
+            .and_then(|i| self.codes.get(i as usize))
+            .ok_or_else(|| TxFailure::wasm(8, format!("code id {code_id}: not found")))
+    }
+
+    fn instance(&self, address: &str) -> Result<&MockInstance, tonic::Status> {
+        self.instances
+            .get(address)
+            .ok_or_else(|| not_found(format!("contract {address}: not found")))
+    }
+
+    fn balance(&self, address: &str, denom: &str) -> u128 {
+        self.balances
+            .get(address)
+            .and_then(|b| b.get(denom))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Moves `coins` from `from` to `to`, returning the bank events
+    fn transfer(
+        &mut self,
+        from: &str,
+        to: &str,
+        coins: &[ProtoCoin],
+    ) -> Result<Vec<Event>, TxFailure> {
+        parse_addr(to)?;
+
+        self.sub_coins(from, coins)?;
+        self.add_coins(to, coins)?;
+
+        let amount = coins_str(coins);
+
+        Ok(vec![
+            event("coin_spent", [("spender", from), ("amount", &amount)]),
+            event("coin_received", [("receiver", to), ("amount", &amount)]),
+            event(
+                "transfer",
+                [("recipient", to), ("sender", from), ("amount", &amount)],
+            ),
+            event("message", [("sender", from)]),
+        ])
+    }
+
+    fn add_coins(&mut self, address: &str, coins: &[ProtoCoin]) -> Result<(), TxFailure> {
+        if !self.accounts.contains_key(address) {
+            let account_number = self.accounts.len() as u64;
+
+            self.accounts.insert(
+                address.to_string(),
+                MockAccount {
+                    account_number,
+                    sequence: 0,
+                    pubkey: None,
+                },
+            );
+        }
+
+        let balances = self.balances.entry(address.to_string()).or_default();
+
+        for coin in coins {
+            *balances.entry(coin.denom.clone()).or_default() += parse_amount(coin)?;
+        }
+
+        Ok(())
+    }
+
+    fn sub_coins(&mut self, address: &str, coins: &[ProtoCoin]) -> Result<(), TxFailure> {
+        for coin in coins {
+            let amount = parse_amount(coin)?;
+            let balance = self.balance(address, &coin.denom);
+
+            if balance < amount {
+                return Err(TxFailure::sdk(
+                    5,
+                    format!(
+                        "{balance}{} is smaller than {amount}{}: insufficient funds",
+                        coin.denom, coin.denom
+                    ),
+                ));
+            }
+
+            self.balances
+                .entry(address.to_string())
+                .or_default()
+                .insert(coin.denom.clone(), balance - amount);
+        }
+
+        Ok(())
+    }
+}
+
+/// A broadcast tx decoded into the msgs `MockChain` supports
+struct DecodedTx {
+    raw: TxRaw,
+    hash: String,
+    auth_info: AuthInfo,
+    msgs: Vec<MockMsg>,
+    msgs_len: usize,
+    signer: String,
+}
+
+impl DecodedTx {
+    fn decode(bytes: &[u8]) -> Result<Self, TxFailure> {
+        let tx_decode = |e: prost::DecodeError| TxFailure::sdk(2, format!("{e}: tx parse error"));
+
+        let raw = TxRaw::decode(bytes).map_err(tx_decode)?;
+        let body = TxBody::decode(raw.body_bytes.as_slice()).map_err(tx_decode)?;
+        let auth_info = AuthInfo::decode(raw.auth_info_bytes.as_slice()).map_err(tx_decode)?;
+
+        let msgs = body
+            .messages
+            .iter()
+            .map(MockMsg::decode)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // only single signer txs are supported
+        let signer = match msgs.first() {
+            Some(msg) => msg.signer().to_string(),
+            None => {
+                return Err(TxFailure::sdk(
+                    18,
+                    "must contain at least one message: invalid request",
+                ))
+            }
+        };
+
+        if let Some(msg) = msgs.iter().find(|m| m.signer() != signer) {
+            return Err(TxFailure::sdk(
+                4,
+                format!(
+                    "MockChain only supports a single signer, got {}: unauthorized",
+                    msg.signer()
+                ),
+            ));
+        }
+
+        Ok(Self {
+            hash: Sha256::digest(bytes)
+                .iter()
+                .map(|b| format!("{b:02X}"))
+                .collect(),
+            msgs_len: body.messages.iter().map(|m| m.value.len()).sum(),
+            raw,
+            auth_info,
+            msgs,
+            signer,
+        })
+    }
+
+    fn gas_limit(&self) -> u64 {
+        self.auth_info
+            .fee
+            .as_ref()
+            .map(|f| f.gas_limit)
+            .unwrap_or_default()
+    }
+
+    fn gas_used(&self) -> u64 {
+        BASE_GAS + GAS_PER_MSG_BYTE * self.msgs_len as u64
+    }
+
+    fn tx_events(&self) -> Vec<Event> {
+        let sequence = self
+            .auth_info
+            .signer_infos
+            .first()
+            .map(|s| s.sequence)
+            .unwrap_or_default();
+
+        let fee = self
+            .auth_info
+            .fee
+            .as_ref()
+            .map(|f| coins_str(&f.amount))
+            .unwrap_or_default();
+
+        vec![
+            event("tx", [("fee", fee.as_str())]),
+            event(
+                "tx",
+                [("acc_seq", format!("{}/{sequence}", self.signer).as_str())],
+            ),
+        ]
+    }
+}
+
+enum MockMsg {
+    Send(MsgSend),
+    GrantAllowance(MsgGrantAllowance),
+    RevokeAllowance(MsgRevokeAllowance),
+    StoreCode(MsgStoreCode),
+    Instantiate(MsgInstantiateContract),
+    Instantiate2(MsgInstantiateContract2),
+    Execute(MsgExecuteContract),
+}
+
+impl MockMsg {
+    fn decode(any: &Any) -> Result<Self, TxFailure> {
+        let tx_decode = |e: prost::DecodeError| TxFailure::sdk(2, format!("{e}: tx parse error"));
+        let value = any.value.as_slice();
+
+        let msg = match any.type_url.as_str() {
+            MsgSend::TYPE_URL => MockMsg::Send(MsgSend::decode(value).map_err(tx_decode)?),
+            MsgGrantAllowance::TYPE_URL => {
+                MockMsg::GrantAllowance(MsgGrantAllowance::decode(value).map_err(tx_decode)?)
+            }
+            MsgRevokeAllowance::TYPE_URL => {
+                MockMsg::RevokeAllowance(MsgRevokeAllowance::decode(value).map_err(tx_decode)?)
+            }
+            MsgStoreCode::TYPE_URL => {
+                MockMsg::StoreCode(MsgStoreCode::decode(value).map_err(tx_decode)?)
+            }
+            MsgInstantiateContract::TYPE_URL => {
+                MockMsg::Instantiate(MsgInstantiateContract::decode(value).map_err(tx_decode)?)
+            }
+            MsgInstantiateContract2::TYPE_URL => {
+                MockMsg::Instantiate2(MsgInstantiateContract2::decode(value).map_err(tx_decode)?)
+            }
+            MsgExecuteContract::TYPE_URL => {
+                MockMsg::Execute(MsgExecuteContract::decode(value).map_err(tx_decode)?)
+            }
+            type_url => {
+                return Err(TxFailure::sdk(
+                    6,
+                    format!("MockChain does not support {type_url}: unknown request"),
+                ))
+            }
+        };
+
+        Ok(msg)
+    }
+
+    fn type_url(&self) -> &'static str {
+        match self {
+            MockMsg::Send(_) => MsgSend::TYPE_URL,
+            MockMsg::GrantAllowance(_) => MsgGrantAllowance::TYPE_URL,
+            MockMsg::RevokeAllowance(_) => MsgRevokeAllowance::TYPE_URL,
+            MockMsg::StoreCode(_) => MsgStoreCode::TYPE_URL,
+            MockMsg::Instantiate(_) => MsgInstantiateContract::TYPE_URL,
+            MockMsg::Instantiate2(_) => MsgInstantiateContract2::TYPE_URL,
+            MockMsg::Execute(_) => MsgExecuteContract::TYPE_URL,
+        }
+    }
+
+    fn module(&self) -> &'static str {
+        match self {
+            MockMsg::Send(_) => "bank",
+            MockMsg::GrantAllowance(_) | MockMsg::RevokeAllowance(_) => "feegrant",
+            _ => "wasm",
+        }
+    }
+
+    fn signer(&self) -> &str {
+        match self {
+            MockMsg::Send(msg) => &msg.from_address,
+            MockMsg::GrantAllowance(msg) => &msg.granter,
+            MockMsg::RevokeAllowance(msg) => &msg.granter,
+            MockMsg::StoreCode(msg) => &msg.sender,
+            MockMsg::Instantiate(msg) => &msg.sender,
+            MockMsg::Instantiate2(msg) => &msg.sender,
+            MockMsg::Execute(msg) => &msg.sender,
+        }
+    }
+}
+
+/// Failed CheckTx / DeliverTx result
+#[derive(Debug)]
+struct TxFailure {
+    codespace: &'static str,
+    code: u32,
+    log: String,
+}
+
+impl TxFailure {
+    fn sdk(code: u32, log: impl Into<String>) -> Self {
+        Self {
+            codespace: "sdk",
+            code,
+            log: log.into(),
+        }
+    }
+
+    fn wasm(code: u32, log: impl Into<String>) -> Self {
+        Self {
+            codespace: "wasm",
+            code,
+            log: log.into(),
+        }
+    }
+
+    fn feegrant(code: u32, log: impl Into<String>) -> Self {
+        Self {
+            codespace: "feegrant",
+            code,
+            log: log.into(),
+        }
+    }
+}
+
+impl From<TxFailure> for ChainResponse {
+    fn from(e: TxFailure) -> Self {
+        ChainResponse {
+            code: Code::Err(e.code),
+            data: None,
+            log: e.log,
+            codespace: e.codespace.to_string(),
+        }
+    }
+}
+
+impl From<TxFailure> for ChainError {
+    fn from(e: TxFailure) -> Self {
+        ChainError::CosmosSdk { res: e.into() }
+    }
+}
+
+fn mock_contract<'a>(
+    contracts: &'a BTreeMap<Vec<u8>, Arc<dyn MockContract>>,
+    checksum: &[u8],
+) -> Result<&'a Arc<dyn MockContract>, String> {
+    contracts
+        .get(checksum)
+        .ok_or_else(|| "no MockContract registered for the code".to_string())
+}
+
+fn event<'a>(type_str: &str, attributes: impl IntoIterator<Item = (&'a str, &'a str)>) -> Event {
+    Event {
+        type_str: type_str.to_string(),
+        attributes: attributes
+            .into_iter()
+            .map(|(key, value)| Tag {
+                key: key.to_string(),
+                value: value.to_string(),
+            })
+            .collect(),
+    }
+}
+
+fn wasm_event(contract: &str, attributes: Vec<Tag>) -> Option<Event> {
+    if attributes.is_empty() {
+        return None;
+    }
+
+    let mut event = event("wasm", [("_contract_address", contract)]);
+    event.attributes.extend(attributes);
+
+    Some(event)
+}
+
+fn coins_str(coins: &[ProtoCoin]) -> String {
+    coins
+        .iter()
+        .map(|c| format!("{}{}", c.amount, c.denom))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn parse_amount(coin: &ProtoCoin) -> Result<u128, TxFailure> {
+    coin.amount
+        .parse()
+        .map_err(|_| TxFailure::sdk(10, format!("{}{}: invalid coins", coin.amount, coin.denom)))
+}
+
+fn parse_addr(address: &str) -> Result<Address, TxFailure> {
+    address
+        .parse()
+        .map_err(|e| TxFailure::sdk(7, format!("{e}: invalid address")))
+}
+
+fn invalid_request(e: prost::DecodeError) -> tonic::Status {
+    tonic::Status::invalid_argument(e.to_string())
+}
+
+fn not_found(message: String) -> tonic::Status {
+    tonic::Status::not_found(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::chain::coin::Coin;
+    use crate::chain::error::{ChainError, SdkError};
+    use crate::chain::fee::Fee;
+    use crate::chain::msg::Msg;
+    use crate::chain::request::TxOptions;
+    use crate::clients::client::{CosmTome, CosmosClient};
+    use crate::config::cfg::ChainConfig;
+    use crate::modules::bank::{error::BankError, model::SendRequest};
+    use crate::modules::cosmwasm::error::CosmwasmError;
+    use crate::modules::cosmwasm::model::{ExecRequest, InstantiateRequest, StoreCodeRequest};
+    use crate::modules::feegrant::error::FeegrantError;
+    use crate::modules::feegrant::model::{
+        Allowance, BasicAllowance, GrantAllowanceRequest, RevokeAllowanceRequest,
+    };
+    use crate::modules::tx::model::BroadcastMode;
+    use crate::signing_key::key::SigningKey;
+
+    use crate::modules::auth::model::Address;
+
+    use super::{MockChain, MockContract, MockContractResponse, MockEnv, MockStorage};
+
+    struct Counter;
+
+    impl MockContract for Counter {
+        fn instantiate(
+            &self,
+            storage: &mut MockStorage,
+            _env: &MockEnv,
+            msg: &[u8],
+        ) -> Result<MockContractResponse, String> {
+            let msg: Value = serde_json::from_slice(msg).map_err(|e| e.to_string())?;
+            storage.insert(b"count".to_vec(), msg["count"].to_string().into_bytes());
+
+            Ok(MockContractResponse::default())
+        }
+
+        fn execute(
+            &self,
+            storage: &mut MockStorage,
+            _env: &MockEnv,
+            msg: &[u8],
+        ) -> Result<MockContractResponse, String> {
+            let msg: Value = serde_json::from_slice(msg).map_err(|e| e.to_string())?;
+            if msg.get("increment").is_none() {
+                return Err("Unauthorized".to_string());
+            }
+
+            let count: u64 = serde_json::from_slice(&storage[b"count".as_slice()]).unwrap();
+            storage.insert(b"count".to_vec(), (count + 1).to_string().into_bytes());
+
+            Ok(MockContractResponse {
+                data: Some(json!({ "count": count + 1 }).to_string().into_bytes()),
+                attributes: vec![],
+            })
+        }
+
+        fn query(&self, storage: &MockStorage, _msg: &[u8]) -> Result<Vec<u8>, String> {
+            let count: u64 = serde_json::from_slice(&storage[b"count".as_slice()]).unwrap();
+            Ok(json!({ "count": count }).to_string().into_bytes())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let tx_options = TxOptions::default();
+
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let addr = key.to_addr(&cfg.prefix).await.unwrap();
+        let recipient =
+            SigningKey::random_mnemonic("recipient".to_string(), cfg.derivation_path.clone())
+                .to_addr(&cfg.prefix)
+                .await
+                .unwrap();
+
+        let chain = MockChain::new(&cfg);
+        chain.mint(
+            &addr,
+            vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 1_000_000,
+            }],
+        );
+        chain.register_contract(b"counter", Counter);
+
+        let cosm_tome = CosmTome::new(cfg.clone(), chain.clone());
+
+        let res = cosm_tome
+            .bank_send(
+                SendRequest {
+                    from: addr.clone(),
+                    to: recipient.clone(),
+                    amounts: vec![Coin {
+                        denom: cfg.denom.parse().unwrap(),
+                        amount: 100,
+                    }],
+                },
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.res
+                .find_event_tags("transfer".to_string(), "amount".to_string())[0]
+                .value,
+            "100utest"
+        );
+        assert_eq!(chain.balance(&recipient, "utest"), 100);
+
+        // simulated gas * gas_adjustment * gas_price was paid as fee
+        let fee = (((res.res.gas_used as f64 * 1.5).ceil() * 0.1).ceil()) as u128;
+        assert_eq!(chain.balance(&addr, "utest"), 1_000_000 - 100 - fee);
+
+        let code_id = cosm_tome
+            .wasm_store(
+                StoreCodeRequest {
+                    wasm_data: b"counter".to_vec(),
+                    instantiate_perms: None,
+                },
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap()
+            .code_id;
+
+        let contract = cosm_tome
+            .wasm_instantiate(
+                InstantiateRequest {
+                    code_id,
+                    msg: json!({ "count": 1 }),
+                    label: "counter".to_string(),
+                    admin: None,
+                    funds: vec![],
+                },
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap()
+            .address;
+
+        let res = cosm_tome
+            .wasm_execute(
+                ExecRequest {
+                    address: contract.clone(),
+                    msg: json!({ "increment": {} }),
+                    funds: vec![],
+                },
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.data::<Value>().unwrap(), json!({ "count": 2 }));
+        let exec_height = res.res.height;
+
+        let res = cosm_tome
+            .wasm_query(contract.clone(), &json!({ "get_count": {} }))
+            .await
+            .unwrap();
+        assert_eq!(res.data::<Value>().unwrap(), json!({ "count": 2 }));
+
+        // historical state
+        let res = cosm_tome
+            .wasm_query_at(
+                contract.clone(),
+                &json!({ "get_count": {} }),
+                exec_height - 1,
+            )
+            .await
+            .unwrap();
+        assert_eq!(res.data::<Value>().unwrap(), json!({ "count": 1 }));
+
+        let res = cosm_tome
+            .bank_query_balance_at(recipient.clone(), cfg.denom.parse().unwrap(), 0)
+            .await
+            .unwrap();
+        assert_eq!(res.balance.amount, 0);
+
+        assert!(cosm_tome
+            .bank_query_balance_at(
+                recipient.clone(),
+                cfg.denom.parse().unwrap(),
+                exec_height + 1
+            )
+            .await
+            .is_err());
+
+        // contract errors are classified with the contract's error message
+        let err = cosm_tome
+            .wasm_execute(
+                ExecRequest {
+                    address: contract,
+                    msg: json!({ "reset": {} }),
+                    funds: vec![],
+                },
+                &key,
+                &tx_options,
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            CosmwasmError::TxError(e) if e.sdk_error() == Some(SdkError::WasmExecuteFailed { message: "Unauthorized".to_string() })
+        ));
+
+        // stale sequences are rejected
+        assert_eq!(chain.sequence(&addr), Some(4));
+
+        let err = cosm_tome
+            .bank_send(
+                SendRequest {
+                    from: addr.clone(),
+                    to: recipient,
+                    amounts: vec![Coin {
+                        denom: cfg.denom.parse().unwrap(),
+                        amount: 100,
+                    }],
+                },
+                &key,
+                &TxOptions {
+                    sequence: Some(1),
+                    ..Default::default()
+                },
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::WrongSequence { expected: Some(4) })
+        ));
+    }
+
+    fn cfg() -> ChainConfig {
+        ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        }
+    }
+
+    fn utest(amount: u128) -> Vec<Coin> {
+        vec![Coin {
+            denom: "utest".parse().unwrap(),
+            amount,
+        }]
+    }
+
+    async fn funded_key(chain: &MockChain, name: &str, amount: u128) -> (SigningKey, Address) {
+        let cfg = cfg();
+        let key = SigningKey::random_mnemonic(name.to_string(), cfg.derivation_path);
+        let addr = key.to_addr(&cfg.prefix).await.unwrap();
+        chain.mint(&addr, utest(amount));
+
+        (key, addr)
+    }
+
+    fn send(from: &Address, to: &Address, amount: u128) -> SendRequest {
+        SendRequest {
+            from: from.clone(),
+            to: to.clone(),
+            amounts: utest(amount),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain_fee_grant() {
+        let chain = MockChain::new(&cfg());
+        let cosm_tome = CosmTome::new(cfg(), chain.clone());
+
+        let (granter_key, granter) = funded_key(&chain, "granter", 1_000_000).await;
+        let (grantee_key, grantee) = funded_key(&chain, "grantee", 2).await;
+
+        let tx_options = TxOptions {
+            fee_granter: Some(granter.clone()),
+            ..Default::default()
+        };
+
+        // no allowance yet
+        let err = cosm_tome
+            .bank_send(send(&grantee, &granter, 1), &grantee_key, &tx_options)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::Other { codespace: "feegrant".to_string(), code: 5 })
+        ));
+
+        let res = cosm_tome
+            .feegrant_grant_allowance(
+                GrantAllowanceRequest {
+                    granter: granter.clone(),
+                    grantee: grantee.clone(),
+                    allowance: Allowance::Basic(BasicAllowance {
+                        spend_limit: utest(10_000),
+                        expiration: None,
+                    }),
+                },
+                &granter_key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            res.res
+                .find_event_tags("set_feegrant".to_string(), "grantee".to_string())[0]
+                .value,
+            grantee.to_string()
+        );
+        let granter_balance = chain.balance(&granter, "utest");
+
+        // the granter pays the fee out of the allowance
+        let res = cosm_tome
+            .bank_send(send(&grantee, &granter, 1), &grantee_key, &tx_options)
+            .await
+            .unwrap();
+        let fee = (((res.res.gas_used as f64 * 1.5).ceil() * 0.1).ceil()) as u128;
+        assert_eq!(chain.balance(&grantee, "utest"), 1);
+        assert_eq!(chain.balance(&granter, "utest"), granter_balance + 1 - fee);
+
+        // the rest of the spend limit doesn't cover another fee
+        let err = cosm_tome
+            .bank_send(send(&grantee, &granter, 1), &grantee_key, &tx_options)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::Other { codespace: "feegrant".to_string(), code: 2 })
+        ));
+        assert_eq!(chain.balance(&grantee, "utest"), 1);
+
+        cosm_tome
+            .feegrant_revoke_allowance(
+                RevokeAllowanceRequest {
+                    granter: granter.clone(),
+                    grantee: grantee.clone(),
+                },
+                &granter_key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        let err = cosm_tome
+            .feegrant_revoke_allowance(
+                RevokeAllowanceRequest { granter, grantee },
+                &granter_key,
+                &TxOptions::default(),
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            FeegrantError::TxError(e) if e.sdk_error() == Some(SdkError::Other { codespace: "feegrant".to_string(), code: 5 })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain_bad_signature() {
+        let chain = MockChain::new(&cfg());
+        let (key, addr) = funded_key(&chain, "test_key", 1_000_000).await;
+        let (_, recipient) = funded_key(&chain, "recipient", 0).await;
+
+        // signed for another chain
+        let cosm_tome = CosmTome::new(
+            ChainConfig {
+                chain_id: "other-1".to_string(),
+                ..cfg()
+            },
+            chain.clone(),
+        );
+
+        let err = cosm_tome
+            .bank_send(send(&addr, &recipient, 100), &key, &TxOptions::default())
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::Unauthorized)
+        ));
+
+        assert_eq!(chain.sequence(&addr), Some(0));
+        assert_eq!(chain.balance(&addr, "utest"), 1_000_000);
+        assert_eq!(chain.balance(&recipient, "utest"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain_wrong_sequence() {
+        let chain = MockChain::new(&cfg());
+        let cosm_tome = CosmTome::new(cfg(), chain.clone());
+        let (key, addr) = funded_key(&chain, "test_key", 1_000_000).await;
+        let (_, recipient) = funded_key(&chain, "recipient", 0).await;
+
+        let err = cosm_tome
+            .bank_send(
+                send(&addr, &recipient, 100),
+                &key,
+                &TxOptions {
+                    sequence: Some(3),
+                    ..Default::default()
+                },
+            )
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::WrongSequence { expected: Some(0) })
+        ));
+
+        // rejected in CheckTx, so no fee is charged
+        assert_eq!(chain.sequence(&addr), Some(0));
+        assert_eq!(chain.balance(&addr, "utest"), 1_000_000);
+        assert_eq!(chain.balance(&recipient, "utest"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain_insufficient_fee_funds() {
+        let chain = MockChain::new(&cfg());
+        let cosm_tome = CosmTome::new(cfg(), chain.clone());
+        let (key, addr) = funded_key(&chain, "test_key", 1).await;
+        let (_, recipient) = funded_key(&chain, "recipient", 0).await;
+
+        // simulating is free, but the signed tx can't pay its fee
+        let err = cosm_tome
+            .bank_send(send(&addr, &recipient, 1), &key, &TxOptions::default())
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            BankError::TxError(e) if e.sdk_error() == Some(SdkError::InsufficientFunds)
+        ));
+
+        assert_eq!(chain.sequence(&addr), Some(0));
+        assert_eq!(chain.balance(&addr, "utest"), 1);
+        assert_eq!(chain.balance(&recipient, "utest"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_mock_chain_failed_msg_rolls_back() {
+        let cfg = cfg();
+        let chain = MockChain::new(&cfg);
+        let cosm_tome = CosmTome::new(cfg.clone(), chain.clone());
+        let (key, addr) = funded_key(&chain, "test_key", 1_000_000).await;
+        let (_, recipient) = funded_key(&chain, "recipient", 0).await;
+
+        // the second send fails after the first one moved funds. Signed by hand,
+        // since simulating the tx would already reject it
+        let account = cosm_tome
+            .auth_query_account(addr.clone())
+            .await
+            .unwrap()
+            .account;
+        let msgs = vec![
+            send(&addr, &recipient, 100).to_any().unwrap(),
+            send(&addr, &recipient, 2_000_000).to_any().unwrap(),
+        ];
+        let fee = Fee::new(
+            Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 5_000,
+            },
+            1_000_000u64,
+            None,
+            None,
+        );
+
+        let tx = key.sign_any(msgs, 0, "", account, fee, &cfg).await.unwrap();
+
+        // passes CheckTx, fails in DeliverTx
+        let res = chain.broadcast_tx(&tx, BroadcastMode::Sync).await.unwrap();
+        let err = chain.wait_for_tx(&res.tx_hash).await.err().unwrap();
+        assert!(matches!(
+            &err,
+            ChainError::CosmosSdk { res } if res.log.contains("message index: 1")
+        ));
+        assert_eq!(err.sdk_error(), Some(SdkError::InsufficientFunds));
+
+        // the first send is rolled back, the fee and sequence are kept
+        assert_eq!(chain.balance(&recipient, "utest"), 0);
+        assert_eq!(chain.balance(&addr, "utest"), 1_000_000 - 5_000);
+        assert_eq!(chain.sequence(&addr), Some(1));
+    }
+}
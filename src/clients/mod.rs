@@ -1,4 +1,15 @@
 pub mod client;
 
 pub mod cosmos_grpc;
+pub mod cosmos_rest;
 pub mod tendermint_rpc;
+
+pub mod failover;
+
+pub mod replay;
+
+#[cfg(feature = "mocks")]
+pub mod mock_chain;
+
+#[cfg(test)]
+mod test_server;
@@ -0,0 +1,518 @@
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use cosmrs::proto::traits::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::chain::error::ChainError;
+use crate::chain::fee::GasInfo;
+use crate::chain::response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse};
+use crate::modules::cosmwasm::model::binary;
+use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+use super::client::CosmosClient;
+
+/// A `CosmosClient` call, as recorded by `RecordingClient`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case", tag = "method")]
+pub enum RecordedRequest {
+    Query {
+        path: String,
+        #[serde(with = "binary")]
+        msg: Vec<u8>,
+        /// Set for `query_at()` calls
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        height: Option<u64>,
+    },
+    SimulateTx {
+        #[serde(with = "binary")]
+        tx: Vec<u8>,
+    },
+    BroadcastTx {
+        #[serde(with = "binary")]
+        tx: Vec<u8>,
+        mode: BroadcastMode,
+    },
+    WaitForTx {
+        tx_hash: String,
+    },
+    BroadcastTxBlock {
+        #[serde(with = "binary")]
+        tx: Vec<u8>,
+    },
+}
+
+impl RecordedRequest {
+    fn describe(&self) -> String {
+        match self {
+            RecordedRequest::Query {
+                path,
+                height: Some(height),
+                ..
+            } => format!("query {path} at height {height}"),
+            RecordedRequest::Query { path, .. } => format!("query {path}"),
+            RecordedRequest::SimulateTx { tx } => format!("simulate_tx {}", tx_hash(tx)),
+            RecordedRequest::BroadcastTx { tx, .. } => format!("broadcast_tx {}", tx_hash(tx)),
+            RecordedRequest::WaitForTx { tx_hash } => format!("wait_for_tx {tx_hash}"),
+            RecordedRequest::BroadcastTxBlock { tx } => {
+                format!("broadcast_tx_block {}", tx_hash(tx))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedResponse {
+    /// Proto encoded query response
+    Query(#[serde(with = "binary")] Vec<u8>),
+    SimulateTx(GasInfo),
+    BroadcastTx(AsyncChainTxResponse),
+    WaitForTx(ChainTxResponse),
+    BroadcastTxBlock(ChainTxResponse),
+}
+
+/// Serializable `ChainError`. Errors other than chain responses and poll timeouts only keep their message.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordedError {
+    CosmosSdk { res: ChainResponse },
+    TxPollTimeout { tx_hash: String, timeout: Duration },
+    Other { message: String },
+}
+
+impl From<&ChainError> for RecordedError {
+    fn from(e: &ChainError) -> Self {
+        match e {
+            ChainError::CosmosSdk { res } => RecordedError::CosmosSdk { res: res.clone() },
+            ChainError::TxPollTimeout { tx_hash, timeout } => RecordedError::TxPollTimeout {
+                tx_hash: tx_hash.clone(),
+                timeout: *timeout,
+            },
+            e => RecordedError::Other {
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+impl From<RecordedError> for ChainError {
+    fn from(e: RecordedError) -> Self {
+        match e {
+            RecordedError::CosmosSdk { res } => ChainError::CosmosSdk { res },
+            RecordedError::TxPollTimeout { tx_hash, timeout } => {
+                ChainError::TxPollTimeout { tx_hash, timeout }
+            }
+            RecordedError::Other { message } => ChainError::Recorded { message },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: Result<RecordedResponse, RecordedError>,
+}
+
+/// Proxies another `CosmosClient`, recording every call and its result.
+/// `save()` writes them to a json fixture file that `ReplayClient` can serve offline.
+///
+/// Clones share the same recording.
+#[derive(Clone, Debug)]
+pub struct RecordingClient<T: CosmosClient> {
+    pub inner: T,
+    interactions: Arc<Mutex<Vec<Interaction>>>,
+}
+
+impl<T: CosmosClient> RecordingClient<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            interactions: Arc::default(),
+        }
+    }
+
+    /// Every call recorded so far, in the order they completed
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions.lock().unwrap().clone()
+    }
+
+    /// Writes the recorded calls to a json fixture file at `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ChainError> {
+        let json = serde_json::to_string_pretty(&*self.interactions.lock().unwrap())
+            .map_err(ChainError::fixture)?;
+
+        fs::write(path, json).map_err(ChainError::fixture)
+    }
+
+    fn record<R>(
+        &self,
+        request: RecordedRequest,
+        res: &Result<R, ChainError>,
+        response: impl FnOnce(&R) -> RecordedResponse,
+    ) {
+        let response = match res {
+            Ok(r) => Ok(response(r)),
+            Err(e) => Err(e.into()),
+        };
+
+        self.interactions
+            .lock()
+            .unwrap()
+            .push(Interaction { request, response });
+    }
+}
+
+#[async_trait]
+impl<T: CosmosClient + Send + Sync> CosmosClient for RecordingClient<T> {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let request = RecordedRequest::Query {
+            path: path.to_string(),
+            msg: msg.encode_to_vec(),
+            height: None,
+        };
+
+        let res = self.inner.query::<I, O>(msg, path).await;
+        self.record(request, &res, |r| {
+            RecordedResponse::Query(r.encode_to_vec())
+        });
+
+        res
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let request = RecordedRequest::Query {
+            path: path.to_string(),
+            msg: msg.encode_to_vec(),
+            height: Some(height),
+        };
+
+        let res = self.inner.query_at::<I, O>(msg, path, height).await;
+        self.record(request, &res, |r| {
+            RecordedResponse::Query(r.encode_to_vec())
+        });
+
+        res
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let request = RecordedRequest::SimulateTx { tx: tx.to_bytes()? };
+
+        let res = self.inner.simulate_tx(tx).await;
+        self.record(request, &res, |r| RecordedResponse::SimulateTx(r.clone()));
+
+        res
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let request = RecordedRequest::BroadcastTx {
+            tx: tx.to_bytes()?,
+            mode,
+        };
+
+        let res = self.inner.broadcast_tx(tx, mode).await;
+        self.record(request, &res, |r| RecordedResponse::BroadcastTx(r.clone()));
+
+        res
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        let request = RecordedRequest::WaitForTx {
+            tx_hash: tx_hash.to_string(),
+        };
+
+        let res = self.inner.wait_for_tx(tx_hash).await;
+        self.record(request, &res, |r| RecordedResponse::WaitForTx(r.clone()));
+
+        res
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let request = RecordedRequest::BroadcastTxBlock { tx: tx.to_bytes()? };
+
+        let res = self.inner.broadcast_tx_block(tx).await;
+        self.record(request, &res, |r| {
+            RecordedResponse::BroadcastTxBlock(r.clone())
+        });
+
+        res
+    }
+}
+
+/// Serves the calls recorded by a `RecordingClient` without a network connection.
+///
+/// Each call is answered with the first unused recorded response to the exact same request,
+/// so repeated requests get their responses in the recorded order.
+/// Calls that weren't recorded, or were already replayed as many times as recorded,
+/// fail with `ChainError::UnmatchedRequest`.
+///
+/// Txs have to be signed by the same keys as in the recording, since they are matched byte for byte.
+/// Clones share the same fixtures.
+#[derive(Clone, Debug)]
+pub struct ReplayClient {
+    interactions: Arc<Mutex<Vec<(Interaction, bool)>>>,
+}
+
+impl ReplayClient {
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Self {
+            interactions: Arc::new(Mutex::new(
+                interactions.into_iter().map(|i| (i, false)).collect(),
+            )),
+        }
+    }
+
+    /// Loads a json fixture file written by `RecordingClient::save()`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ChainError> {
+        let json = fs::read_to_string(path).map_err(ChainError::fixture)?;
+        let interactions = serde_json::from_str(&json).map_err(ChainError::fixture)?;
+
+        Ok(Self::new(interactions))
+    }
+
+    /// Recorded interactions that haven't been replayed yet
+    pub fn unused(&self) -> Vec<Interaction> {
+        self.interactions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, used)| !used)
+            .map(|(i, _)| i.clone())
+            .collect()
+    }
+
+    fn replay(&self, request: RecordedRequest) -> Result<RecordedResponse, ChainError> {
+        let mut interactions = self.interactions.lock().unwrap();
+
+        let (interaction, used) = interactions
+            .iter_mut()
+            .find(|(i, used)| !used && i.request == request)
+            .ok_or_else(|| ChainError::UnmatchedRequest {
+                request: request.describe(),
+            })?;
+
+        *used = true;
+
+        interaction.response.clone().map_err(Into::into)
+    }
+
+    fn replay_query<O: Message + Default>(
+        &self,
+        request: RecordedRequest,
+    ) -> Result<O, ChainError> {
+        match self.replay(request.clone())? {
+            RecordedResponse::Query(res) => {
+                O::decode(res.as_slice()).map_err(ChainError::prost_proto_decoding)
+            }
+            res => Err(Self::unexpected(&request, &res)),
+        }
+    }
+
+    fn unexpected(request: &RecordedRequest, res: &RecordedResponse) -> ChainError {
+        ChainError::Fixture {
+            message: format!(
+                "recorded response {res:?} doesn't match the request {}",
+                request.describe()
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl CosmosClient for ReplayClient {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.replay_query(RecordedRequest::Query {
+            path: path.to_string(),
+            msg: msg.encode_to_vec(),
+            height: None,
+        })
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.replay_query(RecordedRequest::Query {
+            path: path.to_string(),
+            msg: msg.encode_to_vec(),
+            height: Some(height),
+        })
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let request = RecordedRequest::SimulateTx { tx: tx.to_bytes()? };
+
+        match self.replay(request.clone())? {
+            RecordedResponse::SimulateTx(res) => Ok(res),
+            res => Err(Self::unexpected(&request, &res)),
+        }
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let request = RecordedRequest::BroadcastTx {
+            tx: tx.to_bytes()?,
+            mode,
+        };
+
+        match self.replay(request.clone())? {
+            RecordedResponse::BroadcastTx(res) => Ok(res),
+            res => Err(Self::unexpected(&request, &res)),
+        }
+    }
+
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        let request = RecordedRequest::WaitForTx {
+            tx_hash: tx_hash.to_string(),
+        };
+
+        match self.replay(request.clone())? {
+            RecordedResponse::WaitForTx(res) => Ok(res),
+            res => Err(Self::unexpected(&request, &res)),
+        }
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let request = RecordedRequest::BroadcastTxBlock { tx: tx.to_bytes()? };
+
+        match self.replay(request.clone())? {
+            RecordedResponse::BroadcastTxBlock(res) => Ok(res),
+            res => Err(Self::unexpected(&request, &res)),
+        }
+    }
+}
+
+/// Hex encoded sha256 of the tx bytes, like the chain's tx hash
+fn tx_hash(tx: &[u8]) -> String {
+    Sha256::digest(tx)
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use crate::chain::coin::Coin;
+    use crate::chain::error::ChainError;
+    use crate::chain::request::TxOptions;
+    use crate::clients::client::CosmTome;
+    use crate::clients::mock_chain::MockChain;
+    use crate::config::cfg::ChainConfig;
+    use crate::modules::bank::{error::BankError, model::SendRequest};
+    use crate::signing_key::key::SigningKey;
+
+    use super::{RecordingClient, ReplayClient};
+
+    #[tokio::test]
+    async fn test_record_replay() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let tx_options = TxOptions::default();
+
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let addr = key.to_addr(&cfg.prefix).await.unwrap();
+        let recipient =
+            SigningKey::random_mnemonic("recipient".to_string(), cfg.derivation_path.clone())
+                .to_addr(&cfg.prefix)
+                .await
+                .unwrap();
+
+        let chain = MockChain::new(&cfg);
+        chain.mint(
+            &addr,
+            vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 1_000_000,
+            }],
+        );
+
+        let req = SendRequest {
+            from: addr.clone(),
+            to: recipient.clone(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 100,
+            }],
+        };
+
+        // record a session against the mock chain
+        let recorder = RecordingClient::new(chain);
+        let cosm_tome = CosmTome::new(cfg.clone(), recorder.clone());
+
+        let sent = cosm_tome
+            .bank_send(req.clone(), &key, &tx_options)
+            .await
+            .unwrap();
+        let balance = cosm_tome
+            .bank_query_balance(recipient.clone(), cfg.denom.parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(balance.balance.amount, 100);
+
+        let path = std::env::temp_dir().join(format!("cosm-tome-replay-{}.json", sent.res.tx_hash));
+        recorder.save(&path).unwrap();
+
+        // replay it offline
+        let replay = ReplayClient::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(replay.unused(), recorder.interactions());
+
+        let cosm_tome = CosmTome::new(cfg.clone(), replay.clone());
+
+        let replayed = cosm_tome.bank_send(req, &key, &tx_options).await.unwrap();
+        assert_eq!(replayed.res.tx_hash, sent.res.tx_hash);
+        assert_eq!(replayed.res.gas_used, sent.res.gas_used);
+
+        let replayed_balance = cosm_tome
+            .bank_query_balance(recipient.clone(), cfg.denom.parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(replayed_balance.balance, balance.balance);
+        assert!(replay.unused().is_empty());
+
+        // every recorded response was used up
+        let err = cosm_tome
+            .bank_query_balance(recipient, cfg.denom.parse().unwrap())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BankError::ChainError(ChainError::UnmatchedRequest { request })
+                if request == "query /cosmos.bank.v1beta1.Query/Balance"
+        ));
+    }
+}
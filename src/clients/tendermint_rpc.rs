@@ -2,46 +2,56 @@ use async_trait::async_trait;
 use cosmrs::proto::cosmos::tx::v1beta1::{SimulateRequest, SimulateResponse};
 use cosmrs::proto::traits::Message;
 use cosmrs::rpc::{Client, HttpClient};
+use cosmrs::tendermint::block::Height;
 
 use crate::chain::error::ChainError;
 use crate::chain::fee::GasInfo;
-use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse};
+use crate::chain::proof::verify_store_proof;
+use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse, VerifiedStoreResponse};
 use crate::modules::tx::model::{BroadcastMode, RawTx};
 
-use super::client::CosmosClient;
+use super::client::{poll_for_tx, CosmosClient, TxPollOptions};
 
 #[derive(Clone, Debug)]
 pub struct TendermintRPC {
     client: HttpClient,
+    tx_poll_options: TxPollOptions,
 }
 
 impl TendermintRPC {
     pub fn new(rpc_endpoint: &str) -> Result<Self, ChainError> {
         Ok(Self {
             client: HttpClient::new(rpc_endpoint)?,
+            tx_poll_options: TxPollOptions::default(),
         })
     }
 
-    fn encode_msg<T: Message>(msg: T) -> Result<Vec<u8>, ChainError> {
-        let mut data = Vec::with_capacity(msg.encoded_len());
-        msg.encode(&mut data)
-            .map_err(ChainError::prost_proto_encoding)?;
-        Ok(data)
+    /// Override how long `wait_for_tx()` and `broadcast_tx_block()` poll for the committed tx
+    pub fn with_tx_poll_options(mut self, options: TxPollOptions) -> Self {
+        self.tx_poll_options = options;
+        self
     }
-}
 
-#[async_trait]
-impl CosmosClient for TendermintRPC {
-    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
-    where
-        I: Message + Default + tonic::IntoRequest<I> + 'static,
-        O: Message + Default + 'static,
-    {
+    // Returns `None` if the tx has not been committed yet
+    async fn get_tx(&self, tx_hash: &str) -> Result<Option<ChainTxResponse>, ChainError> {
+        match self.client.tx(tx_hash.parse()?, false).await {
+            Ok(res) => Ok(Some(res.into())),
+            Err(e) if e.to_string().contains("not found") => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn abci_query<I: Message, O: Message + Default>(
+        &self,
+        msg: I,
+        path: &str,
+        height: Option<Height>,
+    ) -> Result<O, ChainError> {
         let bytes = TendermintRPC::encode_msg(msg)?;
 
         let res = self
             .client
-            .abci_query(Some(path.parse()?), bytes, None, false)
+            .abci_query(Some(path.parse()?), bytes, height, false)
             .await?;
 
         if res.code.is_err() {
@@ -54,6 +64,105 @@ impl CosmosClient for TendermintRPC {
         Ok(proto_res)
     }
 
+    /// Queries the raw value stored under `key` in the `store` module store (eg. "bank", "wasm"),
+    /// and verifies the ICS-23 merkle proof of the value against the app hash of the header that committed it.
+    /// Fails with `ChainError::ProofVerification` if the proof doesn't match.
+    ///
+    /// The proof of the state at `height` is checked against the app hash in the header of block `height + 1`,
+    /// since a block's header commits to the app hash *before* executing its txs.
+    /// So `height` must be below the latest block, otherwise this fails with `ChainError::UnverifiableHeight`.
+    /// Queries the state before the latest block if `height` is `None`.
+    ///
+    /// NOTE: The header is fetched from the same node, so this catches nodes serving state that doesn't match
+    /// their chain, but not nodes serving a forged chain.
+    /// Check `VerifiedStoreResponse::app_hash` against a trusted source, eg. a light client, for the latter.
+    pub async fn query_store_verified(
+        &self,
+        store: &str,
+        key: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<VerifiedStoreResponse, ChainError> {
+        let latest = self
+            .client
+            .latest_commit()
+            .await?
+            .signed_header
+            .header
+            .height
+            .value();
+
+        let height = match height {
+            Some(height) if height >= latest => {
+                return Err(ChainError::UnverifiableHeight { height, latest })
+            }
+            Some(height) => Height::try_from(height)?,
+            None => Height::try_from(latest.saturating_sub(1))?,
+        };
+
+        let res = self
+            .client
+            .abci_query(
+                Some(format!("/store/{store}/key").parse()?),
+                key.clone(),
+                Some(height),
+                true,
+            )
+            .await?;
+
+        if res.code.is_err() {
+            return Err(ChainError::CosmosSdk { res: res.into() });
+        }
+
+        let header = self
+            .client
+            .commit(res.height.increment())
+            .await?
+            .signed_header
+            .header;
+
+        let value = Some(res.value).filter(|v| !v.is_empty());
+
+        verify_store_proof(
+            res.proof.as_ref(),
+            header.app_hash.as_ref(),
+            store,
+            &key,
+            value.as_deref(),
+        )?;
+
+        Ok(VerifiedStoreResponse {
+            value,
+            height: res.height.value(),
+            app_hash: header.app_hash.value(),
+        })
+    }
+
+    fn encode_msg<T: Message>(msg: T) -> Result<Vec<u8>, ChainError> {
+        let mut data = Vec::with_capacity(msg.encoded_len());
+        msg.encode(&mut data)
+            .map_err(ChainError::prost_proto_encoding)?;
+        Ok(data)
+    }
+}
+
+#[async_trait]
+impl CosmosClient for TendermintRPC {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.abci_query(msg, path, None).await
+    }
+
+    async fn query_at<I, O>(&self, msg: I, path: &str, height: u64) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        self.abci_query(msg, path, Some(height.try_into()?)).await
+    }
+
     #[allow(deprecated)]
     async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
         let req = SimulateRequest {
@@ -110,23 +219,97 @@ impl CosmosClient for TendermintRPC {
         Ok(res)
     }
 
+    async fn wait_for_tx(&self, tx_hash: &str) -> Result<ChainTxResponse, ChainError> {
+        poll_for_tx(tx_hash, &self.tx_poll_options, || self.get_tx(tx_hash)).await
+    }
+
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
-        let res = self
-            .client
-            .broadcast_tx_commit(tx.to_bytes()?.into())
-            .await?;
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
 
-        if res.check_tx.code.is_err() {
-            return Err(ChainError::CosmosSdk {
-                res: res.check_tx.into(),
-            });
-        }
-        if res.deliver_tx.code.is_err() {
-            return Err(ChainError::CosmosSdk {
-                res: res.deliver_tx.into(),
-            });
+        self.wait_for_tx(&res.tx_hash).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+    use cosmrs::proto::traits::Message;
+
+    use crate::chain::error::ChainError;
+    use crate::clients::client::CosmosClient;
+    use crate::clients::test_server::{serve, serve_recorded};
+
+    use super::TendermintRPC;
+
+    #[tokio::test]
+    async fn test_query_at() {
+        let value = QueryBalanceResponse {
+            balance: Some(Coin {
+                denom: "utest".to_string(),
+                amount: "1337".to_string(),
+            }),
         }
+        .encode_to_vec();
+
+        let (endpoint, requests) = serve_recorded(vec![(
+            "200 OK",
+            format!(
+                r#"{{"jsonrpc":"2.0","id":"0","result":{{"response":{{"code":0,"log":"","info":"","index":"0","key":"","value":"{}","proofOps":null,"height":"42","codespace":""}}}}}}"#,
+                base64::encode(value)
+            ),
+        )])
+        .await;
+
+        let res: QueryBalanceResponse = TendermintRPC::new(&endpoint)
+            .unwrap()
+            .query_at(
+                QueryBalanceRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                    denom: "utest".to_string(),
+                },
+                "/cosmos.bank.v1beta1.Query/Balance",
+                42,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.balance.unwrap().amount, "1337");
+
+        let requests = requests.lock().unwrap();
+        let (_, body) = requests[0].split_once("\r\n\r\n").unwrap();
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+
+        assert_eq!(body["method"], "abci_query");
+        assert_eq!(body["params"]["path"], "/cosmos.bank.v1beta1.Query/Balance");
+        assert_eq!(body["params"]["height"], "42");
+    }
+
+    #[tokio::test]
+    async fn test_query_store_verified_latest_height() {
+        let header = r#"{"version":{"block":"11","app":"0"},"chain_id":"test-1","height":"100","time":"2022-10-10T00:00:00Z","last_block_id":{"hash":"","parts":{"total":0,"hash":""}},"last_commit_hash":"","data_hash":"","validators_hash":"E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855","next_validators_hash":"E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855","consensus_hash":"E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855","app_hash":"","last_results_hash":"","evidence_hash":"","proposer_address":"0000000000000000000000000000000000000000"}"#;
+        let endpoint = serve(
+            "200 OK",
+            format!(
+                r#"{{"jsonrpc":"2.0","id":"0","result":{{"signed_header":{{"header":{header},"commit":{{"height":"100","round":0,"block_id":{{"hash":"","parts":{{"total":0,"hash":""}}}},"signatures":[]}}}},"canonical":true}}}}"#
+            ),
+        )
+        .await;
+
+        // the app hash of the latest block's state is only known once block 101 is committed
+        let err = TendermintRPC::new(&endpoint)
+            .unwrap()
+            .query_store_verified("bank", vec![2], Some(100))
+            .await
+            .err()
+            .unwrap();
 
-        Ok(res.into())
+        assert!(matches!(
+            err,
+            ChainError::UnverifiableHeight {
+                height: 100,
+                latest: 100
+            }
+        ));
     }
 }
@@ -0,0 +1,68 @@
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+pub(crate) async fn serve(status: &'static str, body: String) -> String {
+    serve_all(vec![(status, body)]).await
+}
+
+pub(crate) async fn serve_all(responses: Vec<(&'static str, String)>) -> String {
+    serve_recorded(responses).await.0
+}
+
+// Minimal http server standing in for a node, serving each canned json response on its own connection.
+// Also returns the raw text of every request it received.
+pub(crate) async fn serve_recorded(
+    responses: Vec<(&'static str, String)>,
+) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let requests = Arc::new(Mutex::new(vec![]));
+    let received = requests.clone();
+
+    tokio::spawn(async move {
+        for (status, body) in responses {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            // read the full request (headers + body) before responding
+            let mut req = vec![];
+            let mut buf = [0u8; 4096];
+            loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                req.extend_from_slice(&buf[..n]);
+
+                let text = String::from_utf8_lossy(&req).to_lowercase();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let content_len = text
+                        .lines()
+                        .find_map(|l| l.strip_prefix("content-length:"))
+                        .map(|l| l.trim().parse::<usize>().unwrap())
+                        .unwrap_or_default();
+
+                    if req.len() >= end + 4 + content_len {
+                        break;
+                    }
+                }
+
+                if n == 0 {
+                    break;
+                }
+            }
+
+            received
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&req).to_string());
+
+            let res = format!(
+                "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(res.as_bytes()).await.unwrap();
+        }
+    });
+
+    (format!("http://{addr}"), requests)
+}
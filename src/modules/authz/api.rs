@@ -0,0 +1,351 @@
+use cosmrs::proto::cosmos::authz::v1beta1::{
+    QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
+    QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::AuthzError,
+    model::{
+        ExecRequest, ExecRequestProto, ExecResponse, GrantAuthorizationsResponse, GrantRequest,
+        GrantRequestProto, GrantResponse, GrantsResponse, RevokeRequest, RevokeRequestProto,
+        RevokeResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Grant `grantee` permission to execute msgs on behalf of `granter`
+    pub async fn authz_grant(
+        &self,
+        req: GrantRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GrantResponse, AuthzError> {
+        self.authz_grant_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn authz_grant_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GrantResponse, AuthzError>
+    where
+        I: IntoIterator<Item = GrantRequest>,
+    {
+        self.authz_grant_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `authz_grant_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn authz_grant_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<GrantResponse, AuthzError>, AuthzError>
+    where
+        I: IntoIterator<Item = GrantRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<GrantRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(GrantResponse { res })
+            })
+            .await?)
+    }
+
+    /// Revoke a previously granted authorization
+    pub async fn authz_revoke(
+        &self,
+        req: RevokeRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RevokeResponse, AuthzError> {
+        self.authz_revoke_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn authz_revoke_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RevokeResponse, AuthzError>
+    where
+        I: IntoIterator<Item = RevokeRequest>,
+    {
+        self.authz_revoke_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `authz_revoke_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn authz_revoke_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<RevokeResponse, AuthzError>, AuthzError>
+    where
+        I: IntoIterator<Item = RevokeRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<RevokeRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(RevokeResponse { res })
+            })
+            .await?)
+    }
+
+    /// Execute msgs on behalf of a granter, signing as the grantee
+    pub async fn authz_exec(
+        &self,
+        req: ExecRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, AuthzError> {
+        self.authz_exec_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn authz_exec_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, AuthzError>
+    where
+        I: IntoIterator<Item = ExecRequest>,
+    {
+        self.authz_exec_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `authz_exec_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn authz_exec_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, AuthzError>, AuthzError>
+    where
+        I: IntoIterator<Item = ExecRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<ExecRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(ExecResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query the grants from `granter` to `grantee`, optionally filtered to a single `msg_type_url`
+    pub async fn authz_query_grants(
+        &self,
+        granter: Address,
+        grantee: Address,
+        msg_type_url: Option<String>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantsResponse, AuthzError> {
+        let req = QueryGrantsRequest {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            msg_type_url: msg_type_url.unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGrantsResponse>(req, "/cosmos.authz.v1beta1.Query/Grants")
+            .await?;
+
+        let grants = res
+            .grants
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GrantsResponse {
+            grants,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all grants given by `granter` (cosmos-sdk 0.46+)
+    pub async fn authz_query_granter_grants(
+        &self,
+        granter: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantAuthorizationsResponse, AuthzError> {
+        let req = QueryGranterGrantsRequest {
+            granter: granter.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGranterGrantsResponse>(
+                req,
+                "/cosmos.authz.v1beta1.Query/GranterGrants",
+            )
+            .await?;
+
+        let grants = res
+            .grants
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GrantAuthorizationsResponse {
+            grants,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all grants given to `grantee` (cosmos-sdk 0.46+)
+    pub async fn authz_query_grantee_grants(
+        &self,
+        grantee: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantAuthorizationsResponse, AuthzError> {
+        let req = QueryGranteeGrantsRequest {
+            grantee: grantee.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGranteeGrantsResponse>(
+                req,
+                "/cosmos.authz.v1beta1.Query/GranteeGrants",
+            )
+            .await?;
+
+        let grants = res
+            .grants
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(GrantAuthorizationsResponse {
+            grants,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use cosmrs::proto::cosmos::authz::v1beta1::{
+        GenericAuthorization, Grant as ProtoGrant, QueryGrantsRequest, QueryGrantsResponse,
+    };
+    use cosmrs::proto::cosmos::base::query::v1beta1::PageResponse;
+    use cosmrs::proto::traits::MessageExt;
+
+    use crate::{
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::authz::model::{Authorization, Grant},
+    };
+
+    #[tokio::test]
+    async fn test_authz_query_grants() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryGrantsRequest, QueryGrantsResponse>()
+            .times(1)
+            .returning(|req, t: &str| {
+                assert_eq!(t, "/cosmos.authz.v1beta1.Query/Grants");
+                assert_eq!(req.granter, "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg");
+                assert_eq!(req.grantee, "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea");
+                assert_eq!(req.msg_type_url, "/cosmos.gov.v1beta1.MsgVote");
+
+                Ok(QueryGrantsResponse {
+                    grants: vec![ProtoGrant {
+                        authorization: Some(cosmrs::proto::Any {
+                            type_url: "/cosmos.authz.v1beta1.GenericAuthorization".to_string(),
+                            value: GenericAuthorization {
+                                msg: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+                            }
+                            .to_bytes()
+                            .unwrap(),
+                        }),
+                        expiration: Some(prost_types::Timestamp {
+                            seconds: 1_700_000_000,
+                            nanos: 0,
+                        }),
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: vec![],
+                        total: 1,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .authz_query_grants(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+                Some("/cosmos.gov.v1beta1.MsgVote".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.grants,
+            vec![Grant {
+                authorization: Authorization::Generic {
+                    msg_type_url: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+                },
+                expiration: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            }]
+        );
+        assert_eq!(res.next.unwrap().total, 1);
+    }
+}
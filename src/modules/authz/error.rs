@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum AuthzError {
+    #[error("MsgExec must contain at least 1 msg")]
+    EmptyMsgs,
+
+    #[error("grant missing authorization")]
+    MissingAuthorization,
+
+    #[error("unsupported StakeAuthorization AuthorizationType: {i:?}")]
+    StakeAuthorizationType { i: i32 },
+
+    #[error("StakeAuthorization missing validators allow or deny list")]
+    MissingStakeValidators,
+
+    #[error("unsupported contract execution limit: {type_url}")]
+    ContractExecutionLimit { type_url: String },
+
+    #[error("unsupported contract execution filter: {type_url}")]
+    ContractExecutionFilter { type_url: String },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,743 @@
+use std::time::SystemTime;
+
+use cosmrs::proto::cosmos::{
+    authz::v1beta1::{
+        GenericAuthorization, Grant as ProtoGrant, GrantAuthorization as ProtoGrantAuthorization,
+    },
+    bank::v1beta1::SendAuthorization,
+    staking::v1beta1::{
+        stake_authorization::{Policy, Validators},
+        StakeAuthorization,
+    },
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::msg::{AnyMsg, Msg};
+use crate::{
+    chain::{
+        coin::Coin, error::ChainError, request::PaginationResponse, response::ChainTxResponse,
+    },
+    modules::auth::model::Address,
+};
+
+use super::error::AuthzError;
+use super::proto::{
+    AcceptedMessageKeysFilter, AcceptedMessagesFilter, AllowAllMessagesFilter, CombinedLimit,
+    ContractExecutionAuthorization, ContractGrant as ProtoContractGrant, MaxCallsLimit,
+    MaxFundsLimit, MsgExec, MsgGrant, MsgRevoke,
+};
+
+/// Permission granted to a grantee to execute msgs on behalf of a granter
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Authorization {
+    /// Unrestricted permission to execute msgs of `msg_type_url` (ie: "/cosmos.gov.v1beta1.MsgVote")
+    Generic { msg_type_url: String },
+
+    /// Permission to send up to `spend_limit` coins from the granter's account
+    Send { spend_limit: Vec<Coin> },
+
+    /// Permission to delegate, undelegate or redelegate the granter's tokens
+    Stake {
+        /// max amount of tokens that can be staked, or `None` for no limit
+        max_tokens: Option<Coin>,
+        validators: StakeValidators,
+        authorization_type: StakeAuthorizationType,
+    },
+
+    /// Permission to execute cosmwasm contracts (wasmd 0.30+)
+    ContractExecution { grants: Vec<ContractGrant> },
+
+    /// Authorization type without a typed representation here
+    Other(AnyMsg),
+}
+
+impl Authorization {
+    const GENERIC: &'static str = "/cosmos.authz.v1beta1.GenericAuthorization";
+    const SEND: &'static str = "/cosmos.bank.v1beta1.SendAuthorization";
+    const STAKE: &'static str = "/cosmos.staking.v1beta1.StakeAuthorization";
+    const CONTRACT_EXECUTION: &'static str = "/cosmwasm.wasm.v1.ContractExecutionAuthorization";
+}
+
+impl TryFrom<AnyMsg> for Authorization {
+    type Error = AuthzError;
+
+    fn try_from(msg: AnyMsg) -> Result<Self, Self::Error> {
+        let authorization = match msg.type_url.as_str() {
+            Self::GENERIC => {
+                let a: GenericAuthorization = msg.to_proto()?;
+                Authorization::Generic {
+                    msg_type_url: a.msg,
+                }
+            }
+            Self::SEND => {
+                let a: SendAuthorization = msg.to_proto()?;
+                Authorization::Send {
+                    spend_limit: a
+                        .spend_limit
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            Self::STAKE => {
+                let a: StakeAuthorization = msg.to_proto()?;
+                Authorization::Stake {
+                    max_tokens: a.max_tokens.map(TryInto::try_into).transpose()?,
+                    validators: a
+                        .validators
+                        .ok_or(AuthzError::MissingStakeValidators)?
+                        .try_into()?,
+                    authorization_type: a.authorization_type.try_into()?,
+                }
+            }
+            Self::CONTRACT_EXECUTION => {
+                let a: ContractExecutionAuthorization = msg.to_proto()?;
+                Authorization::ContractExecution {
+                    grants: a
+                        .grants
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            _ => Authorization::Other(msg),
+        };
+
+        Ok(authorization)
+    }
+}
+
+impl From<Authorization> for AnyMsg {
+    fn from(authorization: Authorization) -> Self {
+        match authorization {
+            Authorization::Generic { msg_type_url } => AnyMsg::from_proto(
+                Authorization::GENERIC,
+                GenericAuthorization { msg: msg_type_url },
+            ),
+            Authorization::Send { spend_limit } => AnyMsg::from_proto(
+                Authorization::SEND,
+                SendAuthorization {
+                    spend_limit: spend_limit.into_iter().map(Into::into).collect(),
+                },
+            ),
+            Authorization::Stake {
+                max_tokens,
+                validators,
+                authorization_type,
+            } => AnyMsg::from_proto(
+                Authorization::STAKE,
+                StakeAuthorization {
+                    max_tokens: max_tokens.map(Into::into),
+                    authorization_type: authorization_type as i32,
+                    validators: Some(validators.into()),
+                },
+            ),
+            Authorization::ContractExecution { grants } => AnyMsg::from_proto(
+                Authorization::CONTRACT_EXECUTION,
+                ContractExecutionAuthorization {
+                    grants: grants.into_iter().map(Into::into).collect(),
+                },
+            ),
+            Authorization::Other(msg) => msg,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StakeValidators {
+    /// grantee can only stake to these validators
+    AllowList(Vec<Address>),
+
+    /// grantee can stake to any validator except these
+    DenyList(Vec<Address>),
+}
+
+impl TryFrom<Policy> for StakeValidators {
+    type Error = AuthzError;
+
+    fn try_from(policy: Policy) -> Result<Self, Self::Error> {
+        let parse = |v: Validators| {
+            v.address
+                .into_iter()
+                .map(|a| a.parse())
+                .collect::<Result<Vec<Address>, _>>()
+        };
+
+        Ok(match policy {
+            Policy::AllowList(v) => StakeValidators::AllowList(parse(v)?),
+            Policy::DenyList(v) => StakeValidators::DenyList(parse(v)?),
+        })
+    }
+}
+
+impl From<StakeValidators> for Policy {
+    fn from(validators: StakeValidators) -> Self {
+        let addrs = |v: Vec<Address>| Validators {
+            address: v.into_iter().map(Into::into).collect(),
+        };
+
+        match validators {
+            StakeValidators::AllowList(v) => Policy::AllowList(addrs(v)),
+            StakeValidators::DenyList(v) => Policy::DenyList(addrs(v)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum StakeAuthorizationType {
+    Unspecified = 0,
+    Delegate = 1,
+    Undelegate = 2,
+    Redelegate = 3,
+}
+
+impl TryFrom<i32> for StakeAuthorizationType {
+    type Error = AuthzError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == StakeAuthorizationType::Unspecified as i32 => {
+                Ok(StakeAuthorizationType::Unspecified)
+            }
+            x if x == StakeAuthorizationType::Delegate as i32 => {
+                Ok(StakeAuthorizationType::Delegate)
+            }
+            x if x == StakeAuthorizationType::Undelegate as i32 => {
+                Ok(StakeAuthorizationType::Undelegate)
+            }
+            x if x == StakeAuthorizationType::Redelegate as i32 => {
+                Ok(StakeAuthorizationType::Redelegate)
+            }
+            _ => Err(AuthzError::StakeAuthorizationType { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractGrant {
+    pub contract: Address,
+    pub limit: ContractExecutionLimit,
+    pub filter: ContractExecutionFilter,
+}
+
+impl TryFrom<ProtoContractGrant> for ContractGrant {
+    type Error = AuthzError;
+
+    fn try_from(g: ProtoContractGrant) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract: g.contract.parse()?,
+            limit: AnyMsg::from(g.limit.unwrap_or_default()).try_into()?,
+            filter: AnyMsg::from(g.filter.unwrap_or_default()).try_into()?,
+        })
+    }
+}
+
+impl From<ContractGrant> for ProtoContractGrant {
+    fn from(g: ContractGrant) -> Self {
+        Self {
+            contract: g.contract.into(),
+            limit: Some(AnyMsg::from(g.limit).into()),
+            filter: Some(AnyMsg::from(g.filter).into()),
+        }
+    }
+}
+
+/// How many times, or with how many funds, a contract can be executed
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractExecutionLimit {
+    MaxCalls {
+        remaining: u64,
+    },
+    MaxFunds {
+        amounts: Vec<Coin>,
+    },
+    Combined {
+        calls_remaining: u64,
+        amounts: Vec<Coin>,
+    },
+}
+
+impl ContractExecutionLimit {
+    const MAX_CALLS: &'static str = "/cosmwasm.wasm.v1.MaxCallsLimit";
+    const MAX_FUNDS: &'static str = "/cosmwasm.wasm.v1.MaxFundsLimit";
+    const COMBINED: &'static str = "/cosmwasm.wasm.v1.CombinedLimit";
+}
+
+impl TryFrom<AnyMsg> for ContractExecutionLimit {
+    type Error = AuthzError;
+
+    fn try_from(msg: AnyMsg) -> Result<Self, Self::Error> {
+        let coins = |amounts: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>| {
+            amounts
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, ChainError>>()
+        };
+
+        let limit = match msg.type_url.as_str() {
+            Self::MAX_CALLS => {
+                let l: MaxCallsLimit = msg.to_proto()?;
+                ContractExecutionLimit::MaxCalls {
+                    remaining: l.remaining,
+                }
+            }
+            Self::MAX_FUNDS => {
+                let l: MaxFundsLimit = msg.to_proto()?;
+                ContractExecutionLimit::MaxFunds {
+                    amounts: coins(l.amounts)?,
+                }
+            }
+            Self::COMBINED => {
+                let l: CombinedLimit = msg.to_proto()?;
+                ContractExecutionLimit::Combined {
+                    calls_remaining: l.calls_remaining,
+                    amounts: coins(l.amounts)?,
+                }
+            }
+            _ => {
+                return Err(AuthzError::ContractExecutionLimit {
+                    type_url: msg.type_url,
+                })
+            }
+        };
+
+        Ok(limit)
+    }
+}
+
+impl From<ContractExecutionLimit> for AnyMsg {
+    fn from(limit: ContractExecutionLimit) -> Self {
+        match limit {
+            ContractExecutionLimit::MaxCalls { remaining } => AnyMsg::from_proto(
+                ContractExecutionLimit::MAX_CALLS,
+                MaxCallsLimit { remaining },
+            ),
+            ContractExecutionLimit::MaxFunds { amounts } => AnyMsg::from_proto(
+                ContractExecutionLimit::MAX_FUNDS,
+                MaxFundsLimit {
+                    amounts: amounts.into_iter().map(Into::into).collect(),
+                },
+            ),
+            ContractExecutionLimit::Combined {
+                calls_remaining,
+                amounts,
+            } => AnyMsg::from_proto(
+                ContractExecutionLimit::COMBINED,
+                CombinedLimit {
+                    calls_remaining,
+                    amounts: amounts.into_iter().map(Into::into).collect(),
+                },
+            ),
+        }
+    }
+}
+
+/// Which execute msgs the grantee is allowed to send to a contract
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractExecutionFilter {
+    AllowAll,
+
+    /// only allow execute msgs whose top level JSON key is one of `keys`
+    AcceptedKeys {
+        keys: Vec<String>,
+    },
+
+    /// only allow these exact JSON execute msgs
+    AcceptedMessages {
+        messages: Vec<Vec<u8>>,
+    },
+}
+
+impl ContractExecutionFilter {
+    const ALLOW_ALL: &'static str = "/cosmwasm.wasm.v1.AllowAllMessagesFilter";
+    const ACCEPTED_KEYS: &'static str = "/cosmwasm.wasm.v1.AcceptedMessageKeysFilter";
+    const ACCEPTED_MESSAGES: &'static str = "/cosmwasm.wasm.v1.AcceptedMessagesFilter";
+}
+
+impl TryFrom<AnyMsg> for ContractExecutionFilter {
+    type Error = AuthzError;
+
+    fn try_from(msg: AnyMsg) -> Result<Self, Self::Error> {
+        let filter = match msg.type_url.as_str() {
+            Self::ALLOW_ALL => ContractExecutionFilter::AllowAll,
+            Self::ACCEPTED_KEYS => {
+                let f: AcceptedMessageKeysFilter = msg.to_proto()?;
+                ContractExecutionFilter::AcceptedKeys { keys: f.keys }
+            }
+            Self::ACCEPTED_MESSAGES => {
+                let f: AcceptedMessagesFilter = msg.to_proto()?;
+                ContractExecutionFilter::AcceptedMessages {
+                    messages: f.messages,
+                }
+            }
+            _ => {
+                return Err(AuthzError::ContractExecutionFilter {
+                    type_url: msg.type_url,
+                })
+            }
+        };
+
+        Ok(filter)
+    }
+}
+
+impl From<ContractExecutionFilter> for AnyMsg {
+    fn from(filter: ContractExecutionFilter) -> Self {
+        match filter {
+            ContractExecutionFilter::AllowAll => AnyMsg::from_proto(
+                ContractExecutionFilter::ALLOW_ALL,
+                AllowAllMessagesFilter {},
+            ),
+            ContractExecutionFilter::AcceptedKeys { keys } => AnyMsg::from_proto(
+                ContractExecutionFilter::ACCEPTED_KEYS,
+                AcceptedMessageKeysFilter { keys },
+            ),
+            ContractExecutionFilter::AcceptedMessages { messages } => AnyMsg::from_proto(
+                ContractExecutionFilter::ACCEPTED_MESSAGES,
+                AcceptedMessagesFilter { messages },
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Grant {
+    pub authorization: Authorization,
+
+    /// time the grant expires at, or `None` if it never expires
+    pub expiration: Option<SystemTime>,
+}
+
+impl TryFrom<ProtoGrant> for Grant {
+    type Error = AuthzError;
+
+    fn try_from(g: ProtoGrant) -> Result<Self, Self::Error> {
+        Ok(Self {
+            authorization: AnyMsg::from(g.authorization.ok_or(AuthzError::MissingAuthorization)?)
+                .try_into()?,
+            expiration: g
+                .expiration
+                .map(SystemTime::try_from)
+                .transpose()
+                .map_err(ChainError::proto_time)?,
+        })
+    }
+}
+
+impl From<Grant> for ProtoGrant {
+    fn from(g: Grant) -> Self {
+        Self {
+            authorization: Some(AnyMsg::from(g.authorization).into()),
+            expiration: g.expiration.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAuthorization {
+    pub granter: Address,
+    pub grantee: Address,
+    pub authorization: Authorization,
+    pub expiration: Option<SystemTime>,
+}
+
+impl TryFrom<ProtoGrantAuthorization> for GrantAuthorization {
+    type Error = AuthzError;
+
+    fn try_from(g: ProtoGrantAuthorization) -> Result<Self, Self::Error> {
+        let grant: Grant = ProtoGrant {
+            authorization: g.authorization,
+            expiration: g.expiration,
+        }
+        .try_into()?;
+
+        Ok(Self {
+            granter: g.granter.parse()?,
+            grantee: g.grantee.parse()?,
+            authorization: grant.authorization,
+            expiration: grant.expiration,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantsResponse {
+    pub grants: Vec<Grant>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAuthorizationsResponse {
+    pub grants: Vec<GrantAuthorization>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Grant `grantee` permission to execute msgs on behalf of `granter`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub authorization: Authorization,
+
+    /// time the grant expires at, or `None` if it never expires (cosmos-sdk 0.46+)
+    pub expiration: Option<SystemTime>,
+}
+
+pub type GrantRequestProto = GrantRequest;
+
+impl Msg for GrantRequestProto {
+    type Proto = MsgGrant;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgGrant> for GrantRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgGrant) -> Result<Self, Self::Error> {
+        let grant: Grant = msg
+            .grant
+            .ok_or(AuthzError::MissingAuthorization)?
+            .try_into()?;
+
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            authorization: grant.authorization,
+            expiration: grant.expiration,
+        })
+    }
+}
+
+impl TryFrom<GrantRequest> for MsgGrant {
+    type Error = AuthzError;
+
+    fn try_from(req: GrantRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            grant: Some(
+                Grant {
+                    authorization: req.authorization,
+                    expiration: req.expiration,
+                }
+                .into(),
+            ),
+        })
+    }
+}
+
+/// Revoke `grantee`'s permission to execute `msg_type_url` msgs on behalf of `granter`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RevokeRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub msg_type_url: String,
+}
+
+pub type RevokeRequestProto = RevokeRequest;
+
+impl Msg for RevokeRequestProto {
+    type Proto = MsgRevoke;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgRevoke> for RevokeRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgRevoke) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            msg_type_url: msg.msg_type_url,
+        })
+    }
+}
+
+impl TryFrom<RevokeRequest> for MsgRevoke {
+    type Error = AuthzError;
+
+    fn try_from(req: RevokeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            msg_type_url: req.msg_type_url,
+        })
+    }
+}
+
+/// Execute `msgs` as `grantee`, on behalf of the granters that signed each msg.
+///
+/// Each wrapped msg's signer must be the granter, not the grantee:
+/// ```rust,no_run
+/// # use cosm_tome::modules::{auth::model::Address, authz::model::ExecRequest, bank::model::SendRequest};
+/// # fn example(hot_wallet: Address, cold_wallet: Address, send: SendRequest) -> Result<(), Box<dyn std::error::Error>> {
+/// // `send.from` is `cold_wallet`
+/// let req = ExecRequest::new(hot_wallet).msg(&send)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ExecRequest {
+    pub grantee: Address,
+    pub msgs: Vec<AnyMsg>,
+}
+
+impl ExecRequest {
+    pub fn new(grantee: Address) -> Self {
+        Self {
+            grantee,
+            msgs: vec![],
+        }
+    }
+
+    /// Wrap a cosm-tome request (ie: `bank::model::SendRequest` or `cosmwasm::model::ExecRequestProto`)
+    pub fn msg<M: Msg>(mut self, msg: &M) -> Result<Self, M::Err> {
+        self.msgs.push(AnyMsg::from_msg(msg)?);
+        Ok(self)
+    }
+}
+
+pub type ExecRequestProto = ExecRequest;
+
+impl Msg for ExecRequestProto {
+    type Proto = MsgExec;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgExec> for ExecRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgExec) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grantee: msg.grantee.parse()?,
+            msgs: msg.msgs.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+impl TryFrom<ExecRequest> for MsgExec {
+    type Error = AuthzError;
+
+    fn try_from(req: ExecRequest) -> Result<Self, Self::Error> {
+        if req.msgs.is_empty() {
+            return Err(AuthzError::EmptyMsgs);
+        }
+
+        Ok(Self {
+            grantee: req.grantee.into(),
+            msgs: req.msgs.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct GrantResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RevokeResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ExecResponse {
+    pub res: ChainTxResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use crate::chain::{coin::Coin, msg::AnyMsg};
+    use crate::modules::auth::model::Address;
+    use crate::modules::bank::model::SendRequest;
+
+    use super::{
+        Authorization, ExecRequest, GrantRequest, StakeAuthorizationType, StakeValidators,
+    };
+
+    #[test]
+    fn test_grant_round_trip() {
+        let granter: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+        let grantee: Address = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+            .parse()
+            .unwrap();
+
+        for authorization in [
+            Authorization::Generic {
+                msg_type_url: "/cosmos.gov.v1beta1.MsgVote".to_string(),
+            },
+            Authorization::Send {
+                spend_limit: vec![Coin {
+                    denom: "utest".parse().unwrap(),
+                    amount: 10,
+                }],
+            },
+            Authorization::Stake {
+                max_tokens: None,
+                validators: StakeValidators::AllowList(vec![
+                    Address::new("junovaloper", &[7; 20]).unwrap()
+                ]),
+                authorization_type: StakeAuthorizationType::Delegate,
+            },
+        ] {
+            let req = GrantRequest {
+                granter: granter.clone(),
+                grantee: grantee.clone(),
+                authorization,
+                expiration: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)),
+            };
+
+            let any = AnyMsg::from_msg(&req).unwrap();
+            assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgGrant");
+            assert_eq!(any.to_msg::<GrantRequest>().unwrap(), req);
+        }
+    }
+
+    #[test]
+    fn test_exec_round_trip() {
+        let send = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let req = ExecRequest::new(
+            "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+        )
+        .msg(&send)
+        .unwrap();
+
+        let any = AnyMsg::from_msg(&req).unwrap();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgExec");
+
+        let decoded = any.to_msg::<ExecRequest>().unwrap();
+        assert_eq!(decoded, req);
+        assert_eq!(decoded.msgs[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(decoded.msgs[0].to_msg::<SendRequest>().unwrap(), send);
+
+        // exec without msgs is rejected before encoding
+        let empty = ExecRequest::new(req.grantee);
+        assert!(AnyMsg::from_msg(&empty).is_err());
+    }
+}
@@ -0,0 +1,109 @@
+//! Authz protos missing from `cosmos-sdk-proto`.
+//!
+//! `cosmos-sdk-proto` ships the authz msgs without a `TypeUrl` impl, and the wasmd contract authorizations (wasmd 0.30+) are not included at all,
+//! so these mirror the upstream `.proto` definitions field for field.
+
+use cosmrs::proto::{
+    cosmos::{authz::v1beta1::Grant, base::v1beta1::Coin},
+    traits::TypeUrl,
+};
+use cosmrs::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgGrant {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub grant: Option<Grant>,
+}
+
+impl TypeUrl for MsgGrant {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgGrant";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
+
+impl TypeUrl for MsgExec {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgExec";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRevoke {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+}
+
+impl TypeUrl for MsgRevoke {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgRevoke";
+}
+
+/// `cosmwasm.wasm.v1.ContractExecutionAuthorization`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ContractExecutionAuthorization {
+    #[prost(message, repeated, tag = "1")]
+    pub grants: Vec<ContractGrant>,
+}
+
+/// `cosmwasm.wasm.v1.ContractGrant`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ContractGrant {
+    #[prost(string, tag = "1")]
+    pub contract: String,
+    #[prost(message, optional, tag = "2")]
+    pub limit: Option<Any>,
+    #[prost(message, optional, tag = "3")]
+    pub filter: Option<Any>,
+}
+
+/// `cosmwasm.wasm.v1.MaxCallsLimit`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MaxCallsLimit {
+    #[prost(uint64, tag = "1")]
+    pub remaining: u64,
+}
+
+/// `cosmwasm.wasm.v1.MaxFundsLimit`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MaxFundsLimit {
+    #[prost(message, repeated, tag = "1")]
+    pub amounts: Vec<Coin>,
+}
+
+/// `cosmwasm.wasm.v1.CombinedLimit`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CombinedLimit {
+    #[prost(uint64, tag = "1")]
+    pub calls_remaining: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub amounts: Vec<Coin>,
+}
+
+/// `cosmwasm.wasm.v1.AllowAllMessagesFilter`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AllowAllMessagesFilter {}
+
+/// `cosmwasm.wasm.v1.AcceptedMessageKeysFilter`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AcceptedMessageKeysFilter {
+    #[prost(string, repeated, tag = "1")]
+    pub keys: Vec<String>,
+}
+
+/// `cosmwasm.wasm.v1.AcceptedMessagesFilter`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AcceptedMessagesFilter {
+    #[prost(bytes = "vec", repeated, tag = "1")]
+    pub messages: Vec<Vec<u8>>,
+}
@@ -6,12 +6,20 @@ use cosmrs::proto::cosmos::bank::v1beta1::{
     QuerySupplyOfResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
 
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::traits::Message;
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
 use crate::{
     chain::{
-        coin::Denom,
+        coin::{parse_amount, Coin, Denom},
+        error::ChainError,
         request::{PaginationRequest, TxOptions},
     },
-    clients::client::{CosmTome, CosmosClient},
+    clients::{
+        client::{CosmTome, CosmosClient},
+        tendermint_rpc::TendermintRPC,
+    },
     modules::{auth::model::Address, bank::model::SendResponse},
     signing_key::key::SigningKey,
 };
@@ -41,6 +49,23 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<SendResponse, BankError>
+    where
+        I: IntoIterator<Item = SendRequest>,
+    {
+        self.bank_send_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `bank_send_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn bank_send_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<SendResponse, BankError>, BankError>
     where
         I: IntoIterator<Item = SendRequest>,
     {
@@ -51,13 +76,11 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(Into::into)
             .collect::<Vec<SendRequestProto>>();
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
-
-        Ok(SendResponse { res })
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(SendResponse { res })
+            })
+            .await?)
     }
 
     /// Query the amount of `denom` currently held by an `address`
@@ -65,6 +88,27 @@ impl<T: CosmosClient> CosmTome<T> {
         &self,
         address: Address,
         denom: Denom,
+    ) -> Result<BalanceResponse, BankError> {
+        self.bank_query_balance_at_height(address, denom, None)
+            .await
+    }
+
+    /// Same as `bank_query_balance()`, but reads the state as of the block at `height`
+    pub async fn bank_query_balance_at(
+        &self,
+        address: Address,
+        denom: Denom,
+        height: u64,
+    ) -> Result<BalanceResponse, BankError> {
+        self.bank_query_balance_at_height(address, denom, Some(height))
+            .await
+    }
+
+    async fn bank_query_balance_at_height(
+        &self,
+        address: Address,
+        denom: Denom,
+        height: Option<u64>,
     ) -> Result<BalanceResponse, BankError> {
         let req = QueryBalanceRequest {
             address: address.into(),
@@ -72,8 +116,11 @@ impl<T: CosmosClient> CosmTome<T> {
         };
 
         let res = self
-            .client
-            .query::<_, QueryBalanceResponse>(req, "/cosmos.bank.v1beta1.Query/Balance")
+            .query_at_height::<_, QueryBalanceResponse>(
+                req,
+                "/cosmos.bank.v1beta1.Query/Balance",
+                height,
+            )
             .await?;
 
         // NOTE: we are unwrapping here, because unknown denoms still have a 0 balance returned here
@@ -87,6 +134,27 @@ impl<T: CosmosClient> CosmTome<T> {
         &self,
         address: Address,
         pagination: Option<PaginationRequest>,
+    ) -> Result<BalancesResponse, BankError> {
+        self.bank_query_balances_at_height(address, pagination, None)
+            .await
+    }
+
+    /// Same as `bank_query_balances()`, but reads the state as of the block at `height`
+    pub async fn bank_query_balances_at(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+        height: u64,
+    ) -> Result<BalancesResponse, BankError> {
+        self.bank_query_balances_at_height(address, pagination, Some(height))
+            .await
+    }
+
+    async fn bank_query_balances_at_height(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+        height: Option<u64>,
     ) -> Result<BalancesResponse, BankError> {
         let req = QueryAllBalancesRequest {
             address: address.into(),
@@ -94,8 +162,11 @@ impl<T: CosmosClient> CosmTome<T> {
         };
 
         let res = self
-            .client
-            .query::<_, QueryAllBalancesResponse>(req, "/cosmos.bank.v1beta1.Query/AllBalances")
+            .query_at_height::<_, QueryAllBalancesResponse>(
+                req,
+                "/cosmos.bank.v1beta1.Query/AllBalances",
+                height,
+            )
             .await?;
 
         let balances = res
@@ -143,13 +214,33 @@ impl<T: CosmosClient> CosmTome<T> {
 
     /// Query global supply of `denom` for all accounts
     pub async fn bank_query_supply(&self, denom: Denom) -> Result<BalanceResponse, BankError> {
+        self.bank_query_supply_at_height(denom, None).await
+    }
+
+    /// Same as `bank_query_supply()`, but reads the state as of the block at `height`
+    pub async fn bank_query_supply_at(
+        &self,
+        denom: Denom,
+        height: u64,
+    ) -> Result<BalanceResponse, BankError> {
+        self.bank_query_supply_at_height(denom, Some(height)).await
+    }
+
+    async fn bank_query_supply_at_height(
+        &self,
+        denom: Denom,
+        height: Option<u64>,
+    ) -> Result<BalanceResponse, BankError> {
         let req = QuerySupplyOfRequest {
             denom: denom.into(),
         };
 
         let res = self
-            .client
-            .query::<_, QuerySupplyOfResponse>(req, "/cosmos.bank.v1beta1.Query/SupplyOf")
+            .query_at_height::<_, QuerySupplyOfResponse>(
+                req,
+                "/cosmos.bank.v1beta1.Query/SupplyOf",
+                height,
+            )
             .await?;
 
         // NOTE: we are unwrapping here, because unknown denoms still have a 0 balance returned here
@@ -245,21 +336,73 @@ impl<T: CosmosClient> CosmTome<T> {
     }
 }
 
+impl CosmTome<TendermintRPC> {
+    /// Query the amount of `denom` held by an `address` straight from the bank store,
+    /// verifying the merkle proof of the balance against the chain's app hash.
+    /// See `TendermintRPC::query_store_verified()`.
+    pub async fn bank_query_balance_verified(
+        &self,
+        address: Address,
+        denom: Denom,
+        height: Option<u64>,
+    ) -> Result<BalanceResponse, BankError> {
+        let address = address.to_bytes();
+        let denom: String = denom.into();
+
+        // x/bank `BalancesPrefix | len(address) | address | denom`
+        let mut key = vec![0x02, address.len() as u8];
+        key.extend(address);
+        key.extend(denom.as_bytes());
+
+        let res = self
+            .client
+            .query_store_verified("bank", key, height)
+            .await?;
+
+        let amount = match res.value {
+            // zero balances are not stored
+            None => 0,
+            // cosmos-sdk < v0.46 stores the proto encoded `Coin`, newer versions only the amount string
+            Some(value) if value.first() == Some(&0x0a) => {
+                let coin = ProtoCoin::decode(value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+                parse_amount(&coin.amount)?
+            }
+            Some(value) => parse_amount(&String::from_utf8_lossy(&value))?,
+        };
+
+        Ok(BalanceResponse {
+            balance: Coin {
+                denom: denom.parse()?,
+                amount,
+            },
+        })
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "mocks")]
 mod tests {
     use crate::{
         chain::{
             error::ChainError,
-            response::{ChainResponse, ChainTxResponse, Code},
+            response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code},
         },
         clients::client::MockCosmosClient,
-        modules::{bank::model::SendResponse, tx::error::TxError},
+        modules::{
+            auth::model::Address,
+            bank::model::SendResponse,
+            tx::{error::TxError, model::BroadcastMode, sequence::SequenceOptions},
+        },
     };
     use cosmrs::proto::{
         cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
-        traits::MessageExt,
+        cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse},
+        cosmos::base::v1beta1::Coin as ProtoCoin,
+        cosmos::tx::v1beta1::{AuthInfo, TxRaw},
+        traits::{Message, MessageExt},
     };
+    use std::sync::{Arc, Mutex};
 
     use crate::{
         chain::{coin::Coin, fee::GasInfo, request::TxOptions},
@@ -278,6 +421,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: Some("localhost".to_string()),
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -285,31 +429,10 @@ mod tests {
         let tx_options = TxOptions::default();
         let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
 
-        let mut mock_client = MockCosmosClient::new();
-
-        mock_client
-            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
-            .times(2)
-            .returning(move |_, t: &str| {
-                Ok(QueryAccountResponse {
-                    account: Some(cosmrs::proto::Any {
-                        type_url: t.to_owned(),
-                        value: BaseAccount {
-                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
-                            pub_key: None,
-                            account_number: 1337,
-                            sequence: 1,
-                        }
-                        .to_bytes()
-                        .unwrap(),
-                    }),
-                })
-            });
+        // msgs are encoded before anything is queried from chain
+        let mock_client = MockCosmosClient::new();
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         // empty amount vec errors:
         let req = SendRequest {
@@ -374,6 +497,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: None,
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -409,14 +533,29 @@ mod tests {
         });
 
         mock_client
-            .expect_broadcast_tx_block()
+            .expect_broadcast_tx()
             .times(1)
-            .returning(|_| {
+            .returning(|_, mode| {
+                assert_eq!(mode, BroadcastMode::Sync);
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(1)
+            .returning(|tx_hash| {
+                assert_eq!(tx_hash, "TX_HASH_0");
+
                 Ok(ChainTxResponse {
                     res: ChainResponse {
                         code: Code::Ok,
                         data: None,
                         log: "log log log".to_string(),
+                        codespace: String::new(),
                     },
                     events: vec![],
                     gas_wanted: 200,
@@ -426,10 +565,7 @@ mod tests {
                 })
             });
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         let req = SendRequest {
             from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
@@ -453,7 +589,8 @@ mod tests {
                     res: ChainResponse {
                         code: Code::Ok,
                         data: None,
-                        log: "log log log".to_string()
+                        log: "log log log".to_string(),
+                        codespace: String::new(),
                     },
                     events: vec![],
                     gas_wanted: 200,
@@ -474,6 +611,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: None,
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -492,14 +630,12 @@ mod tests {
                         code: Code::Err(1),
                         data: None,
                         log: "error".to_string(),
+                        codespace: String::new(),
                     },
                 })
             });
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         let req = SendRequest {
             from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
@@ -523,5 +659,194 @@ mod tests {
         assert!(matches!(res, BankError::TxError(TxError::AccountError(..))));
     }
 
+    #[tokio::test]
+    async fn test_bank_send_sequence_manager() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // the earlier txs are still in the mempool, so the chain keeps reporting the same sequence
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 5,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let sequences = Arc::new(Mutex::new(vec![]));
+        let signed = sequences.clone();
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(4)
+            .returning(move |tx, _| {
+                let tx: TxRaw = tx.clone().into();
+                let sequence = AuthInfo::decode(tx.auth_info_bytes.as_slice())
+                    .unwrap()
+                    .signer_infos[0]
+                    .sequence;
+
+                signed.lock().unwrap().push(sequence);
+
+                // another process signed 2 txs with the same key in the meantime
+                if sequence == 7 {
+                    return Err(ChainError::CosmosSdk {
+                        res: ChainResponse {
+                            code: Code::Err(32),
+                            data: None,
+                            log: "account sequence mismatch, expected 9, got 7: incorrect account sequence".to_string(),
+                            codespace: "sdk".to_string(),
+                        },
+                    });
+                }
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: format!("TX_HASH_{sequence}"),
+                })
+            });
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(3)
+            .returning(|tx_hash| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse::default(),
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: tx_hash.to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client)
+            .with_sequence_manager(SequenceOptions { max_retries: 1 });
+
+        let req = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let res = cosm_tome
+            .bank_send(req.clone(), &key, &tx_options)
+            .await
+            .unwrap();
+        assert_eq!(res.res.tx_hash, "TX_HASH_5");
+
+        let res = cosm_tome
+            .bank_send(req.clone(), &key, &tx_options)
+            .await
+            .unwrap();
+        assert_eq!(res.res.tx_hash, "TX_HASH_6");
+
+        // the mismatch resyncs the cached sequence to the one the chain expects
+        let res = cosm_tome.bank_send(req, &key, &tx_options).await.unwrap();
+        assert_eq!(res.res.tx_hash, "TX_HASH_9");
+
+        assert_eq!(*sequences.lock().unwrap(), vec![5, 6, 7, 9]);
+    }
+
+    #[tokio::test]
+    async fn test_bank_query_balance_at() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query_at::<QueryBalanceRequest, QueryBalanceResponse>()
+            .times(1)
+            .returning(|req, path, height| {
+                assert_eq!(path, "/cosmos.bank.v1beta1.Query/Balance");
+                assert_eq!(height, 42);
+
+                Ok(QueryBalanceResponse {
+                    balance: Some(ProtoCoin {
+                        denom: req.denom,
+                        amount: "10".to_string(),
+                    }),
+                })
+            });
+
+        // no height queries the latest state
+        mock_client
+            .expect_query::<QueryBalanceRequest, QueryBalanceResponse>()
+            .times(1)
+            .returning(|req, _| {
+                Ok(QueryBalanceResponse {
+                    balance: Some(ProtoCoin {
+                        denom: req.denom,
+                        amount: "20".to_string(),
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let address: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+
+        let res = cosm_tome
+            .bank_query_balance_at(address.clone(), cfg.denom.parse().unwrap(), 42)
+            .await
+            .unwrap();
+        assert_eq!(res.balance.amount, 10);
+
+        let res = cosm_tome
+            .bank_query_balance(address, cfg.denom.parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(res.balance.amount, 20);
+    }
+
     // TODO: Add more happy path tests for other functions
 }
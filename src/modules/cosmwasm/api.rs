@@ -1,18 +1,31 @@
 use serde::Serialize;
 
-use crate::chain::request::TxOptions;
+use crate::chain::request::{PaginationRequest, TxOptions};
+use crate::chain::response::ChainTxResponse;
 use crate::clients::client::CosmTome;
+use crate::clients::tendermint_rpc::TendermintRPC;
 use cosmrs::proto::cosmwasm::wasm::v1::{
+    QueryAllContractStateRequest, QueryAllContractStateResponse, QueryCodeRequest,
+    QueryCodeResponse, QueryCodesRequest, QueryCodesResponse, QueryContractHistoryRequest,
+    QueryContractHistoryResponse, QueryContractInfoRequest, QueryContractInfoResponse,
+    QueryContractsByCodeRequest, QueryContractsByCodeResponse, QueryPinnedCodesRequest,
+    QueryPinnedCodesResponse, QueryRawContractStateRequest, QueryRawContractStateResponse,
     QuerySmartContractStateRequest, QuerySmartContractStateResponse,
 };
 
 use crate::modules::auth::model::Address;
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
 use crate::{clients::client::CosmosClient, signing_key::key::SigningKey};
 
 use super::model::{
-    ExecRequest, ExecResponse, InstantiateBatchResponse, InstantiateRequest, MigrateRequest,
-    MigrateResponse, QueryResponse, StoreCodeBatchResponse, StoreCodeRequest,
+    AllStateResponse, ClearAdminRequest, ClearAdminResponse, CodeResponse, CodesResponse,
+    ContractHistoryResponse, ContractInfoResponse, ContractsByCodeResponse, ExecRequest,
+    ExecResponse, Instantiate2Request, InstantiateBatchResponse, InstantiateRequest,
+    MigrateRequest, MigrateResponse, ParamsResponse, PinnedCodesResponse, QueryResponse,
+    RawQueryResponse, StoreCodeBatchResponse, StoreCodeRequest, UpdateAdminRequest,
+    UpdateAdminResponse,
 };
+use super::proto::{QueryParamsRequest, QueryParamsResponse};
 use super::{
     error::CosmwasmError,
     model::{InstantiateResponse, StoreCodeResponse},
@@ -39,6 +52,23 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<StoreCodeBatchResponse, CosmwasmError>
+    where
+        I: IntoIterator<Item = StoreCodeRequest>,
+    {
+        self.wasm_store_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_store_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_store_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<StoreCodeBatchResponse, CosmwasmError>, CosmwasmError>
     where
         I: IntoIterator<Item = StoreCodeRequest>,
     {
@@ -49,20 +79,18 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                let code_ids = res
+                    .find_event_tags("store_code".to_string(), "code_id".to_string())
+                    .into_iter()
+                    .map(|x| x.value.parse::<u64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| CosmwasmError::MissingEvent)?;
 
-        let code_ids = res
-            .find_event_tags("store_code".to_string(), "code_id".to_string())
-            .into_iter()
-            .map(|x| x.value.parse::<u64>())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|_| CosmwasmError::MissingEvent)?;
-
-        Ok(StoreCodeBatchResponse { code_ids, res })
+                Ok(StoreCodeBatchResponse { code_ids, res })
+            })
+            .await?)
     }
 
     pub async fn wasm_instantiate<S>(
@@ -90,6 +118,24 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<InstantiateBatchResponse, CosmwasmError>
+    where
+        S: Serialize,
+        I: IntoIterator<Item = InstantiateRequest<S>>,
+    {
+        self.wasm_instantiate_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_instantiate_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_instantiate_batch_async<S, I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<InstantiateBatchResponse, CosmwasmError>, CosmwasmError>
     where
         S: Serialize,
         I: IntoIterator<Item = InstantiateRequest<S>>,
@@ -101,28 +147,79 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(InstantiateBatchResponse {
+                    addresses: instantiated_addresses(&res)?,
+                    res,
+                })
+            })
+            .await?)
+    }
+
+    /// Instantiate a contract at a deterministic address, see `instantiate2_address()`
+    pub async fn wasm_instantiate2<S>(
+        &self,
+        req: Instantiate2Request<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<InstantiateResponse, CosmwasmError>
+    where
+        S: Serialize,
+    {
+        let mut res = self
+            .wasm_instantiate2_batch(vec![req], key, tx_options)
             .await?;
 
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        Ok(InstantiateResponse {
+            address: res.addresses.remove(0),
+            res: res.res,
+        })
+    }
 
-        let events =
-            res.find_event_tags("instantiate".to_string(), "_contract_address".to_string());
+    pub async fn wasm_instantiate2_batch<S, I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<InstantiateBatchResponse, CosmwasmError>
+    where
+        S: Serialize,
+        I: IntoIterator<Item = Instantiate2Request<S>>,
+    {
+        self.wasm_instantiate2_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
 
-        if events.is_empty() {
-            return Err(CosmwasmError::MissingEvent);
-        }
+    /// Non-blocking `wasm_instantiate2_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_instantiate2_batch_async<S, I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<InstantiateBatchResponse, CosmwasmError>, CosmwasmError>
+    where
+        S: Serialize,
+        I: IntoIterator<Item = Instantiate2Request<S>>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
 
-        let addrs = events
+        let msgs = reqs
             .into_iter()
-            .map(|e| e.value.parse())
+            .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(InstantiateBatchResponse {
-            addresses: addrs,
-            res,
-        })
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(InstantiateBatchResponse {
+                    addresses: instantiated_addresses(&res)?,
+                    res,
+                })
+            })
+            .await?)
     }
 
     pub async fn wasm_execute<S>(
@@ -143,6 +240,24 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<ExecResponse, CosmwasmError>
+    where
+        S: Serialize,
+        I: IntoIterator<Item = ExecRequest<S>>,
+    {
+        self.wasm_execute_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_execute_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_execute_batch_async<S, I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError>
     where
         S: Serialize,
         I: IntoIterator<Item = ExecRequest<S>>,
@@ -154,19 +269,36 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(ExecResponse { res })
+            })
+            .await?)
+    }
 
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+    pub async fn wasm_query<S: Serialize>(
+        &self,
+        address: Address,
+        msg: &S,
+    ) -> Result<QueryResponse, CosmwasmError> {
+        self.wasm_query_at_height(address, msg, None).await
+    }
 
-        Ok(ExecResponse { res })
+    /// Same as `wasm_query()`, but reads the state as of the block at `height`
+    pub async fn wasm_query_at<S: Serialize>(
+        &self,
+        address: Address,
+        msg: &S,
+        height: u64,
+    ) -> Result<QueryResponse, CosmwasmError> {
+        self.wasm_query_at_height(address, msg, Some(height)).await
     }
 
-    pub async fn wasm_query<S: Serialize>(
+    async fn wasm_query_at_height<S: Serialize>(
         &self,
         address: Address,
         msg: &S,
+        height: Option<u64>,
     ) -> Result<QueryResponse, CosmwasmError> {
         let payload = serde_json::to_vec(msg).map_err(CosmwasmError::json)?;
 
@@ -176,10 +308,10 @@ impl<T: CosmosClient> CosmTome<T> {
         };
 
         let res = self
-            .client
-            .query::<_, QuerySmartContractStateResponse>(
+            .query_at_height::<_, QuerySmartContractStateResponse>(
                 req,
                 "/cosmwasm.wasm.v1.Query/SmartContractState",
+                height,
             )
             .await?;
 
@@ -204,6 +336,24 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<MigrateResponse, CosmwasmError>
+    where
+        S: Serialize,
+        I: IntoIterator<Item = MigrateRequest<S>>,
+    {
+        self.wasm_migrate_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_migrate_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_migrate_batch_async<S, I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<MigrateResponse, CosmwasmError>, CosmwasmError>
     where
         S: Serialize,
         I: IntoIterator<Item = MigrateRequest<S>>,
@@ -215,14 +365,928 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(MigrateResponse { res })
+            })
+            .await?)
+    }
+
+    /// Transfer the admin rights of a contract to `new_admin`. Must be signed by the current admin.
+    pub async fn wasm_update_admin(
+        &self,
+        req: UpdateAdminRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<UpdateAdminResponse, CosmwasmError> {
+        self.wasm_update_admin_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn wasm_update_admin_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<UpdateAdminResponse, CosmwasmError>
+    where
+        I: IntoIterator<Item = UpdateAdminRequest>,
+    {
+        self.wasm_update_admin_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_update_admin_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_update_admin_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<UpdateAdminResponse, CosmwasmError>, CosmwasmError>
+    where
+        I: IntoIterator<Item = UpdateAdminRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .map(|r| r.to_proto(sender_addr.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(UpdateAdminResponse { res })
+            })
+            .await?)
+    }
+
+    /// Remove the admin of a contract, so it can never be migrated again. Must be signed by the current admin.
+    pub async fn wasm_clear_admin(
+        &self,
+        req: ClearAdminRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ClearAdminResponse, CosmwasmError> {
+        self.wasm_clear_admin_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn wasm_clear_admin_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ClearAdminResponse, CosmwasmError>
+    where
+        I: IntoIterator<Item = ClearAdminRequest>,
+    {
+        self.wasm_clear_admin_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `wasm_clear_admin_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn wasm_clear_admin_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ClearAdminResponse, CosmwasmError>, CosmwasmError>
+    where
+        I: IntoIterator<Item = ClearAdminRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .map(|r| r.to_proto(sender_addr.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(ClearAdminResponse { res })
+            })
+            .await?)
+    }
+
+    pub async fn wasm_query_contract_info(
+        &self,
+        address: Address,
+    ) -> Result<ContractInfoResponse, CosmwasmError> {
+        self.wasm_query_contract_info_at_height(address, None).await
+    }
+
+    /// Same as `wasm_query_contract_info()`, but reads the state as of the block at `height`
+    pub async fn wasm_query_contract_info_at(
+        &self,
+        address: Address,
+        height: u64,
+    ) -> Result<ContractInfoResponse, CosmwasmError> {
+        self.wasm_query_contract_info_at_height(address, Some(height))
+            .await
+    }
+
+    async fn wasm_query_contract_info_at_height(
+        &self,
+        address: Address,
+        height: Option<u64>,
+    ) -> Result<ContractInfoResponse, CosmwasmError> {
+        let req = QueryContractInfoRequest {
+            address: address.into(),
+        };
+
+        let res = self
+            .query_at_height::<_, QueryContractInfoResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/ContractInfo",
+                height,
+            )
+            .await?;
+
+        Ok(ContractInfoResponse {
+            address: res.address.parse()?,
+            contract_info: res
+                .contract_info
+                .ok_or(CosmwasmError::MissingContractInfo)?
+                .try_into()?,
+        })
+    }
+
+    /// Query the instantiate and migrate history of a contract
+    pub async fn wasm_query_contract_history(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ContractHistoryResponse, CosmwasmError> {
+        let req = QueryContractHistoryRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryContractHistoryResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/ContractHistory",
+            )
+            .await?;
+
+        let entries = res
+            .entries
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ContractHistoryResponse {
+            entries,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the raw value stored under `key` in a contract's storage, bypassing the contract's query entrypoint
+    pub async fn wasm_query_raw(
+        &self,
+        address: Address,
+        key: Vec<u8>,
+    ) -> Result<RawQueryResponse, CosmwasmError> {
+        self.wasm_query_raw_at_height(address, key, None).await
+    }
+
+    /// Same as `wasm_query_raw()`, but reads the state as of the block at `height`
+    pub async fn wasm_query_raw_at(
+        &self,
+        address: Address,
+        key: Vec<u8>,
+        height: u64,
+    ) -> Result<RawQueryResponse, CosmwasmError> {
+        self.wasm_query_raw_at_height(address, key, Some(height))
+            .await
+    }
+
+    async fn wasm_query_raw_at_height(
+        &self,
+        address: Address,
+        key: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<RawQueryResponse, CosmwasmError> {
+        let req = QueryRawContractStateRequest {
+            address: address.into(),
+            query_data: key,
+        };
+
+        let res = self
+            .query_at_height::<_, QueryRawContractStateResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/RawContractState",
+                height,
+            )
+            .await?;
+
+        Ok(RawQueryResponse {
+            data: Some(res.data).filter(|d| !d.is_empty()),
+        })
+    }
+
+    /// Query every raw key / value pair in a contract's storage
+    pub async fn wasm_query_all_state(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllStateResponse, CosmwasmError> {
+        let req = QueryAllContractStateRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllContractStateResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/AllContractState",
+            )
+            .await?;
+
+        Ok(AllStateResponse {
+            models: res.models.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query a code's info along with its wasm bytecode
+    pub async fn wasm_query_code(&self, code_id: u64) -> Result<CodeResponse, CosmwasmError> {
+        let req = QueryCodeRequest { code_id };
+
+        let res = self
+            .client
+            .query::<_, QueryCodeResponse>(req, "/cosmwasm.wasm.v1.Query/Code")
+            .await?;
+
+        Ok(CodeResponse {
+            code_info: res
+                .code_info
+                .ok_or(CosmwasmError::MissingCodeInfo)?
+                .try_into()?,
+            data: res.data,
+        })
+    }
+
+    /// Query the info of all stored codes, without their bytecode
+    pub async fn wasm_query_codes(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<CodesResponse, CosmwasmError> {
+        let req = QueryCodesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryCodesResponse>(req, "/cosmwasm.wasm.v1.Query/Codes")
+            .await?;
+
+        let code_infos = res
+            .code_infos
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CodesResponse {
+            code_infos,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the addresses of all contracts instantiated from `code_id`
+    pub async fn wasm_query_contracts_by_code(
+        &self,
+        code_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ContractsByCodeResponse, CosmwasmError> {
+        let req = QueryContractsByCodeRequest {
+            code_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryContractsByCodeResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/ContractsByCode",
+            )
+            .await?;
+
+        let contracts = res
+            .contracts
+            .into_iter()
+            .map(|c| c.parse())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ContractsByCodeResponse {
+            contracts,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the ids of the codes pinned in the wasm VM cache
+    pub async fn wasm_query_pinned_codes(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PinnedCodesResponse, CosmwasmError> {
+        let req = QueryPinnedCodesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPinnedCodesResponse>(req, "/cosmwasm.wasm.v1.Query/PinnedCodes")
             .await?;
 
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        Ok(PinnedCodesResponse {
+            code_ids: res.code_ids,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the wasm module params (wasmd v0.26+)
+    pub async fn wasm_query_params(&self) -> Result<ParamsResponse, CosmwasmError> {
+        let req = QueryParamsRequest {};
 
-        Ok(MigrateResponse { res })
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmwasm.wasm.v1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.ok_or(CosmwasmError::MissingParams)?.try_into()?,
+        })
     }
+}
 
-    // TODO: Finish
+fn instantiated_addresses(res: &ChainTxResponse) -> Result<Vec<Address>, CosmwasmError> {
+    let events = res.find_event_tags("instantiate".to_string(), "_contract_address".to_string());
+
+    if events.is_empty() {
+        return Err(CosmwasmError::MissingEvent);
+    }
+
+    let addrs = events
+        .into_iter()
+        .map(|e| e.value.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(addrs)
+}
+
+impl CosmTome<TendermintRPC> {
+    /// Query the raw value stored under `key` in a contract's storage straight from the wasm store,
+    /// verifying its merkle proof against the chain's app hash.
+    /// See `TendermintRPC::query_store_verified()`.
+    pub async fn wasm_query_raw_verified(
+        &self,
+        address: Address,
+        key: Vec<u8>,
+        height: Option<u64>,
+    ) -> Result<RawQueryResponse, CosmwasmError> {
+        // wasmd `ContractStorePrefix | address | key`
+        let mut store_key = vec![0x03];
+        store_key.extend(address.to_bytes());
+        store_key.extend(key);
+
+        let res = self
+            .client
+            .query_store_verified("wasm", store_key, height)
+            .await?;
+
+        Ok(RawQueryResponse { data: res.value })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmwasm::wasm::v1::{
+        AbsoluteTxPosition, ContractInfo, QueryContractInfoRequest, QueryContractInfoResponse,
+    };
+
+    use std::time::Duration;
+
+    use cosmrs::proto::{
+        cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        cosmos::tx::v1beta1::{TxBody, TxRaw},
+        cosmwasm::wasm::v1::{MsgClearAdmin, MsgInstantiateContract, MsgUpdateAdmin},
+        traits::{Message, MessageExt},
+    };
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            error::ChainError,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse, Event, Tag},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::{
+            auth::model::Address,
+            cosmwasm::{
+                error::CosmwasmError,
+                model::{
+                    ClearAdminRequest, Instantiate2Request, StoreCodeRequest, UpdateAdminRequest,
+                },
+            },
+            tx::{error::TxError, model::BroadcastMode},
+        },
+        signing_key::key::SigningKey,
+    };
+
+    fn mock_store_broadcast(mock_client: &mut MockCosmosClient) {
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(|_, mode| {
+                assert_eq!(mode, BroadcastMode::Async);
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+    }
+
+    // Mocks a blocking tx whose signed msgs are passed to `check_msgs`, and that emits `events` once committed
+    fn mock_blocking_tx<F>(mock_client: &mut MockCosmosClient, events: Vec<Event>, check_msgs: F)
+    where
+        F: Fn(Vec<cosmrs::proto::Any>) + Send + 'static,
+    {
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(move |tx, _| {
+                let tx: TxRaw = tx.clone().into();
+                check_msgs(TxBody::decode(tx.body_bytes.as_slice()).unwrap().messages);
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(1)
+            .returning(move |tx_hash| {
+                Ok(ChainTxResponse {
+                    events: events.clone(),
+                    tx_hash: tx_hash.to_string(),
+                    height: 1337,
+                    ..Default::default()
+                })
+            });
+    }
+
+    fn store_requests() -> Vec<StoreCodeRequest> {
+        vec![
+            StoreCodeRequest {
+                wasm_data: vec![0, 97, 115, 109],
+                instantiate_perms: None,
+            },
+            StoreCodeRequest {
+                wasm_data: vec![0, 97, 115, 109, 1],
+                instantiate_perms: None,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_wasm_update_admin() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let sender = key.to_addr(&cfg.prefix).await.unwrap().to_string();
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_blocking_tx(&mut mock_client, vec![], move |msgs| {
+            assert_eq!(msgs.len(), 1);
+            assert_eq!(msgs[0].type_url, "/cosmwasm.wasm.v1.MsgUpdateAdmin");
+            assert_eq!(
+                MsgUpdateAdmin::decode(msgs[0].value.as_slice()).unwrap(),
+                MsgUpdateAdmin {
+                    sender: sender.clone(),
+                    new_admin: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+                    contract: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                }
+            );
+        });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .wasm_update_admin(
+                UpdateAdminRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                        .parse()
+                        .unwrap(),
+                    new_admin: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                        .parse()
+                        .unwrap(),
+                },
+                &key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+        assert_eq!(res.res.height, 1337);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_clear_admin() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let sender = key.to_addr(&cfg.prefix).await.unwrap().to_string();
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_blocking_tx(&mut mock_client, vec![], move |msgs| {
+            assert_eq!(msgs.len(), 1);
+            assert_eq!(msgs[0].type_url, "/cosmwasm.wasm.v1.MsgClearAdmin");
+            assert_eq!(
+                MsgClearAdmin::decode(msgs[0].value.as_slice()).unwrap(),
+                MsgClearAdmin {
+                    sender: sender.clone(),
+                    contract: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                }
+            );
+        });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .wasm_clear_admin(
+                ClearAdminRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                        .parse()
+                        .unwrap(),
+                },
+                &key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+        assert_eq!(res.res.height, 1337);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_instantiate2() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let sender = key.to_addr(&cfg.prefix).await.unwrap().to_string();
+
+        let contract = Address::new("juno", &[9; 32]).unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_blocking_tx(
+            &mut mock_client,
+            vec![Event {
+                type_str: "instantiate".to_string(),
+                attributes: vec![
+                    Tag {
+                        key: "_contract_address".to_string(),
+                        value: contract.to_string(),
+                    },
+                    Tag {
+                        key: "code_id".to_string(),
+                        value: "7".to_string(),
+                    },
+                ],
+            }],
+            move |msgs| {
+                assert_eq!(msgs.len(), 1);
+                assert_eq!(
+                    msgs[0].type_url,
+                    "/cosmwasm.wasm.v1.MsgInstantiateContract2"
+                );
+
+                // fields 1-6 are shared with `MsgInstantiateContract`, which prost decodes while skipping the rest
+                assert_eq!(
+                    MsgInstantiateContract::decode(msgs[0].value.as_slice()).unwrap(),
+                    MsgInstantiateContract {
+                        sender: sender.clone(),
+                        admin: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+                        code_id: 7,
+                        label: "label".to_string(),
+                        msg: br#"{"count":1}"#.to_vec(),
+                        funds: vec![cosmrs::proto::cosmos::base::v1beta1::Coin {
+                            denom: "utest".to_string(),
+                            amount: "10".to_string(),
+                        }],
+                    }
+                );
+
+                // salt is field 7 (bytes) and fix_msg is field 8 (bool)
+                assert!(msgs[0]
+                    .value
+                    .ends_with(&[0x3a, 3, b's', b'a', b'l', 0x40, 1]));
+            },
+        );
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .wasm_instantiate2(
+                Instantiate2Request {
+                    code_id: 7,
+                    msg: serde_json::json!({ "count": 1 }),
+                    label: "label".to_string(),
+                    admin: Some(
+                        "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                            .parse()
+                            .unwrap(),
+                    ),
+                    funds: vec![Coin {
+                        denom: "utest".parse().unwrap(),
+                        amount: 10,
+                    }],
+                    salt: b"sal".to_vec(),
+                    fix_msg: true,
+                },
+                &key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.address, contract);
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+
+    #[tokio::test]
+    async fn test_wasm_store_batch_async() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_store_broadcast(&mut mock_client);
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(2)
+            .returning(|tx_hash| {
+                assert_eq!(tx_hash, "TX_HASH_0");
+
+                Ok(ChainTxResponse {
+                    events: ["7", "8"]
+                        .into_iter()
+                        .map(|code_id| Event {
+                            type_str: "store_code".to_string(),
+                            attributes: vec![Tag {
+                                key: "code_id".to_string(),
+                                value: code_id.to_string(),
+                            }],
+                        })
+                        .collect(),
+                    tx_hash: tx_hash.to_string(),
+                    height: 1337,
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let pending = cosm_tome
+            .wasm_store_batch_async(
+                store_requests(),
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Async,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(pending.tx_hash(), "TX_HASH_0");
+
+        let res = pending.wait(&cosm_tome).await.unwrap();
+        assert_eq!(res.code_ids, vec![7, 8]);
+        assert_eq!(res.res.height, 1337);
+
+        let res = pending.wait_with_retries(&cosm_tome, &key).await.unwrap();
+        assert_eq!(res.code_ids, vec![7, 8]);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_store_batch_async_timeout() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_store_broadcast(&mut mock_client);
+
+        // the tx never shows up on chain, a timeout is not retried by re-broadcasting
+        mock_client
+            .expect_wait_for_tx()
+            .times(2)
+            .returning(|tx_hash| {
+                Err(ChainError::TxPollTimeout {
+                    tx_hash: tx_hash.to_string(),
+                    timeout: Duration::from_secs(60),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let pending = cosm_tome
+            .wasm_store_batch_async(
+                store_requests(),
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Async,
+            )
+            .await
+            .unwrap();
+
+        let err = pending.wait(&cosm_tome).await.err().unwrap();
+        assert!(matches!(
+            err,
+            CosmwasmError::TxError(TxError::ChainError(ChainError::TxPollTimeout { ref tx_hash, .. }))
+                if tx_hash == "TX_HASH_0"
+        ));
+
+        let err = pending
+            .wait_with_retries(&cosm_tome, &key)
+            .await
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            CosmwasmError::TxError(TxError::ChainError(ChainError::TxPollTimeout { ref tx_hash, .. }))
+                if tx_hash == "TX_HASH_0"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wasm_query_contract_info() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryContractInfoRequest, QueryContractInfoResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmwasm.wasm.v1.Query/ContractInfo");
+
+                Ok(QueryContractInfoResponse {
+                    address: req.address,
+                    contract_info: Some(ContractInfo {
+                        code_id: 7,
+                        creator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                        admin: "".to_string(),
+                        label: "test contract".to_string(),
+                        created: Some(AbsoluteTxPosition {
+                            block_height: 1337,
+                            tx_index: 2,
+                        }),
+                        ibc_port_id: "".to_string(),
+                        extension: None,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .wasm_query_contract_info(
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let info = res.contract_info;
+        assert_eq!(info.code_id, 7);
+        assert_eq!(info.admin, None);
+        assert_eq!(info.label, "test contract");
+        assert_eq!(info.created.unwrap().block_height, 1337);
+        assert_eq!(info.ibc_port_id, None);
+    }
 }
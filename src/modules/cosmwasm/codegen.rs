@@ -0,0 +1,569 @@
+//! Generates Rust msg types from the JSON schema a CosmWasm contract exports with `cosmwasm-schema`'s `write_api!`.
+//!
+//! Meant to be called from a `build.rs`, so the contract's msgs are typed at compile time:
+//!
+//! ```no_run
+//! // build.rs
+//! let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("cw20_base.rs");
+//! cosm_tome::modules::cosmwasm::codegen::generate_file("schema/cw20-base.json", out).unwrap();
+//! println!("cargo:rerun-if-changed=schema/cw20-base.json");
+//! ```
+//!
+//! ```ignore
+//! // src/lib.rs
+//! pub mod cw20_base {
+//!     include!(concat!(env!("OUT_DIR"), "/cw20_base.rs"));
+//! }
+//!
+//! let token = cw20_base::Cw20BaseContract::new(&cosm_tome, address);
+//! let res: cw20_base::BalanceResponse = token
+//!     .query(&cw20_base::QueryMsg::Balance { address: "juno1...".to_string() })
+//!     .await?;
+//! ```
+//!
+//! The generated code derives `serde::Serialize` and `serde::Deserialize`, so the crate including it needs `serde`.
+//! Only the subset of JSON schema that `schemars` emits for CosmWasm msgs is supported.
+
+use std::{collections::BTreeMap, fmt::Write, fs, path::Path};
+
+use serde_json::{Map, Value};
+
+use super::error::CodegenError;
+
+/// Generates the msg, response and helper types for the contract schema in `schema_json`.
+///
+/// Also emits a `{ContractName}Contract` alias of `Contract` bound to the contract's execute, query and migrate msgs.
+pub fn generate(schema_json: &str) -> Result<String, CodegenError> {
+    let schema: Value = serde_json::from_str(schema_json)?;
+    let schema = schema
+        .as_object()
+        .ok_or_else(|| CodegenError::Unsupported {
+            message: "contract schema is not a json object".to_string(),
+        })?;
+
+    let contract_name = schema
+        .get("contract_name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| CodegenError::Unsupported {
+            message: "contract schema missing `contract_name`".to_string(),
+        })?;
+
+    let mut gen = Generator::default();
+
+    let mut roots = BTreeMap::new();
+    for msg in ["instantiate", "execute", "query", "migrate", "sudo"] {
+        if let Some(root) = schema.get(msg).filter(|r| !r.is_null()) {
+            roots.insert(msg, gen.root(root)?);
+        }
+    }
+
+    if let Some(responses) = schema.get("responses").and_then(Value::as_object) {
+        for root in responses.values() {
+            gen.root(root)?;
+        }
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// @generated by cosm-tome from the `{contract_name}` contract schema. Do not edit."
+    )?;
+
+    for item in gen.items.values() {
+        writeln!(out)?;
+        out.push_str(item);
+    }
+
+    if let (Some(execute), Some(query)) = (roots.get("execute"), roots.get("query")) {
+        let migrate = roots
+            .get("migrate")
+            .map(String::as_str)
+            .unwrap_or("cosm_tome::modules::cosmwasm::model::Empty");
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "pub type {}Contract<'a, T> = cosm_tome::modules::cosmwasm::contract::Contract<'a, T, {execute}, {query}, {migrate}>;",
+            pascal_case(contract_name)
+        )?;
+    }
+
+    Ok(out)
+}
+
+/// Reads the contract schema at `schema_path` and writes the generated code to `out_path`
+pub fn generate_file(
+    schema_path: impl AsRef<Path>,
+    out_path: impl AsRef<Path>,
+) -> Result<(), CodegenError> {
+    let schema = fs::read_to_string(schema_path)?;
+
+    fs::write(out_path, generate(&schema)?)?;
+
+    Ok(())
+}
+
+#[derive(Default)]
+struct Generator {
+    /// generated items by type name, deduped since every root schema repeats its shared definitions
+    items: BTreeMap<String, String>,
+}
+
+impl Generator {
+    /// Generates a root schema and its definitions, returning the root's type name
+    fn root(&mut self, root: &Value) -> Result<String, CodegenError> {
+        let name = root
+            .get("title")
+            .and_then(Value::as_str)
+            .map(pascal_case)
+            .ok_or_else(|| CodegenError::Unsupported {
+                message: "root schema missing `title`".to_string(),
+            })?;
+
+        if let Some(defs) = root.get("definitions").and_then(Value::as_object) {
+            for (def_name, def) in defs {
+                self.named(&pascal_case(def_name), def)?;
+            }
+        }
+
+        self.named(&name, root)?;
+
+        Ok(name)
+    }
+
+    fn named(&mut self, name: &str, schema: &Value) -> Result<(), CodegenError> {
+        if self.items.contains_key(name) {
+            return Ok(());
+        }
+
+        let mut out = String::new();
+        doc(&mut out, schema, "")?;
+
+        if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+            self.enum_item(&mut out, name, variants)?;
+        } else if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            unit_enum_item(&mut out, name, values)?;
+        } else if let Some(props) = object_properties(schema) {
+            writeln!(
+                out,
+                "#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]"
+            )?;
+            writeln!(out, "pub struct {name} {{")?;
+            fields(&mut out, schema, props, "    ")?;
+            writeln!(out, "}}")?;
+        } else {
+            writeln!(out, "pub type {name} = {};", type_of(schema)?)?;
+        }
+
+        self.items.insert(name.to_string(), out);
+
+        Ok(())
+    }
+
+    fn enum_item(
+        &mut self,
+        out: &mut String,
+        name: &str,
+        variants: &[Value],
+    ) -> Result<(), CodegenError> {
+        writeln!(
+            out,
+            "#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]"
+        )?;
+        writeln!(out, "#[serde(rename_all = \"snake_case\")]")?;
+        writeln!(out, "pub enum {name} {{")?;
+
+        for variant in variants {
+            // unit variants serialize as plain strings: `"increment"`
+            if let Some(values) = variant.get("enum").and_then(Value::as_array) {
+                for value in values {
+                    doc(out, variant, "    ")?;
+                    variant_name(out, enum_value(value)?)?;
+                    writeln!(out, ",")?;
+                }
+                continue;
+            }
+
+            // other variants are externally tagged objects with a single property: `{"transfer": {...}}`
+            let (tag, inner) = object_properties(variant)
+                .filter(|props| props.len() == 1)
+                .and_then(|props| props.into_iter().next())
+                .ok_or_else(|| CodegenError::Unsupported {
+                    message: format!("`{name}` variant is not an externally tagged object"),
+                })?;
+
+            doc(out, variant, "    ")?;
+            variant_name(out, tag)?;
+
+            if object_properties(inner).is_some_and(|props| props.is_empty()) {
+                writeln!(out, " {{}},")?;
+            } else if let Some(props) = object_properties(inner) {
+                writeln!(out, " {{")?;
+                fields(out, inner, props, "        ")?;
+                writeln!(out, "    }},")?;
+            } else {
+                writeln!(out, "({}),", type_of(inner)?)?;
+            }
+        }
+
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+}
+
+fn unit_enum_item(out: &mut String, name: &str, values: &[Value]) -> Result<(), CodegenError> {
+    writeln!(
+        out,
+        "#[derive(Copy, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]"
+    )?;
+    writeln!(out, "#[serde(rename_all = \"snake_case\")]")?;
+    writeln!(out, "pub enum {name} {{")?;
+
+    for value in values {
+        variant_name(out, enum_value(value)?)?;
+        writeln!(out, ",")?;
+    }
+
+    writeln!(out, "}}")?;
+
+    Ok(())
+}
+
+fn enum_value(value: &Value) -> Result<&str, CodegenError> {
+    value.as_str().ok_or_else(|| CodegenError::Unsupported {
+        message: format!("non string enum value: {value}"),
+    })
+}
+
+/// Writes the variant name, renaming it when `rename_all = "snake_case"` would not round trip
+fn variant_name(out: &mut String, tag: &str) -> Result<(), CodegenError> {
+    let variant = pascal_case(tag);
+
+    if snake_case(&variant) != tag {
+        writeln!(out, "    #[serde(rename = \"{tag}\")]")?;
+    }
+
+    write!(out, "    {variant}")?;
+
+    Ok(())
+}
+
+fn fields(
+    out: &mut String,
+    schema: &Value,
+    props: Vec<(&String, &Value)>,
+    indent: &str,
+) -> Result<(), CodegenError> {
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .map(|r| r.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let public = if indent.len() > 4 { "" } else { "pub " };
+
+    for (field, field_schema) in props {
+        doc(out, field_schema, indent)?;
+
+        let ident = field_ident(field);
+        if ident.trim_start_matches("r#") != field {
+            writeln!(out, "{indent}#[serde(rename = \"{field}\")]")?;
+        }
+
+        let mut ty = type_of(field_schema)?;
+        if !required.contains(&field.as_str()) {
+            writeln!(
+                out,
+                "{indent}#[serde(default, skip_serializing_if = \"Option::is_none\")]"
+            )?;
+
+            if !ty.starts_with("Option<") {
+                ty = format!("Option<{ty}>");
+            }
+        }
+
+        writeln!(out, "{indent}{public}{ident}: {ty},")?;
+    }
+
+    Ok(())
+}
+
+/// Rust type of an inline schema
+fn type_of(schema: &Value) -> Result<String, CodegenError> {
+    let schema = match schema {
+        Value::Bool(true) => return Ok("serde_json::Value".to_string()),
+        Value::Object(schema) => schema,
+        _ => {
+            return Err(CodegenError::Unsupported {
+                message: format!("unsupported schema: {schema}"),
+            })
+        }
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return Ok(pascal_case(
+            reference.rsplit('/').next().unwrap_or(reference),
+        ));
+    }
+
+    if let Some([inner]) = schema
+        .get("allOf")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+    {
+        return type_of(inner);
+    }
+
+    if let Some(any_of) = schema.get("anyOf").and_then(Value::as_array) {
+        let not_null = any_of
+            .iter()
+            .filter(|s| s.get("type").and_then(Value::as_str) != Some("null"))
+            .collect::<Vec<_>>();
+
+        return match not_null.as_slice() {
+            [inner] if not_null.len() < any_of.len() => Ok(format!("Option<{}>", type_of(inner)?)),
+            _ => Ok("serde_json::Value".to_string()),
+        };
+    }
+
+    match schema.get("type") {
+        Some(Value::String(ty)) => primitive(schema, ty),
+        Some(Value::Array(types)) => {
+            let not_null = types
+                .iter()
+                .filter_map(Value::as_str)
+                .filter(|t| *t != "null")
+                .collect::<Vec<_>>();
+
+            match not_null.as_slice() {
+                [ty] if not_null.len() < types.len() => {
+                    Ok(format!("Option<{}>", primitive(schema, ty)?))
+                }
+                [ty] => primitive(schema, ty),
+                _ => Ok("serde_json::Value".to_string()),
+            }
+        }
+        _ => Ok("serde_json::Value".to_string()),
+    }
+}
+
+fn primitive(schema: &Map<String, Value>, ty: &str) -> Result<String, CodegenError> {
+    let format = schema.get("format").and_then(Value::as_str);
+
+    let ty = match ty {
+        "string" => "String".to_string(),
+        "boolean" => "bool".to_string(),
+        "null" => "()".to_string(),
+        "number" => "f64".to_string(),
+        "integer" => match format {
+            Some("uint8") => "u8",
+            Some("uint16") => "u16",
+            Some("uint32") => "u32",
+            Some("uint64") | Some("uint") => "u64",
+            Some("uint128") => "u128",
+            Some("int8") => "i8",
+            Some("int16") => "i16",
+            Some("int32") => "i32",
+            Some("int128") => "i128",
+            _ => "i64",
+        }
+        .to_string(),
+        "array" => match schema.get("items") {
+            Some(Value::Array(items)) => format!(
+                "({})",
+                items
+                    .iter()
+                    .map(type_of)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .join(", ")
+            ),
+            Some(items) => format!("Vec<{}>", type_of(items)?),
+            None => "Vec<serde_json::Value>".to_string(),
+        },
+        "object" => match schema.get("additionalProperties") {
+            Some(Value::Object(values)) => format!(
+                "std::collections::BTreeMap<String, {}>",
+                type_of(&Value::Object(values.clone()))?
+            ),
+            _ => "serde_json::Value".to_string(),
+        },
+        _ => {
+            return Err(CodegenError::Unsupported {
+                message: format!("unsupported schema type: {ty}"),
+            })
+        }
+    };
+
+    Ok(ty)
+}
+
+/// Properties of an object schema that should become a struct, including empty `{}` objects
+fn object_properties(schema: &Value) -> Option<Vec<(&String, &Value)>> {
+    if schema.get("type").and_then(Value::as_str) != Some("object") {
+        return None;
+    }
+
+    match schema.get("properties") {
+        Some(Value::Object(props)) => Some(props.iter().collect()),
+        None if !matches!(schema.get("additionalProperties"), Some(Value::Object(_))) => {
+            Some(vec![])
+        }
+        _ => None,
+    }
+}
+
+fn doc(out: &mut String, schema: &Value, indent: &str) -> Result<(), CodegenError> {
+    if let Some(description) = schema.get("description").and_then(Value::as_str) {
+        for line in description.lines() {
+            writeln!(out, "{indent}/// {}", line.trim_end())?;
+        }
+    }
+
+    Ok(())
+}
+
+fn field_ident(field: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "break", "const", "continue", "crate", "dyn", "else",
+        "enum", "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+        "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait",
+        "true", "type", "unsafe", "use", "where", "while", "yield",
+    ];
+
+    let ident = snake_case(field);
+
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else {
+        ident
+    }
+}
+
+fn pascal_case(s: &str) -> String {
+    s.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn snake_case(s: &str) -> String {
+    let mut out = String::new();
+
+    for (i, c) in s.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else if !out.ends_with('_') {
+            out.push('_');
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r##"{
+        "contract_name": "cw-counter",
+        "contract_version": "0.1.0",
+        "instantiate": {
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "InstantiateMsg",
+            "type": "object",
+            "required": ["count"],
+            "properties": { "count": { "type": "integer", "format": "int32" } },
+            "additionalProperties": false
+        },
+        "execute": {
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "ExecuteMsg",
+            "oneOf": [
+                {
+                    "type": "object",
+                    "required": ["increment"],
+                    "properties": { "increment": { "type": "object", "additionalProperties": false } },
+                    "additionalProperties": false
+                },
+                {
+                    "description": "Resets the counter",
+                    "type": "object",
+                    "required": ["reset"],
+                    "properties": {
+                        "reset": {
+                            "type": "object",
+                            "required": ["count"],
+                            "properties": {
+                                "count": { "type": "integer", "format": "int32" },
+                                "type": { "anyOf": [{ "$ref": "#/definitions/Kind" }, { "type": "null" }] }
+                            },
+                            "additionalProperties": false
+                        }
+                    },
+                    "additionalProperties": false
+                }
+            ],
+            "definitions": {
+                "Kind": { "type": "string", "enum": ["soft", "hard"] }
+            }
+        },
+        "query": {
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "title": "QueryMsg",
+            "oneOf": [{ "type": "string", "enum": ["get_count"] }]
+        },
+        "migrate": null,
+        "sudo": null,
+        "responses": {
+            "get_count": {
+                "$schema": "http://json-schema.org/draft-07/schema#",
+                "title": "GetCountResponse",
+                "type": "object",
+                "required": ["count", "owners"],
+                "properties": {
+                    "count": { "type": "integer", "format": "int32" },
+                    "owners": { "type": "array", "items": { "$ref": "#/definitions/Addr" } }
+                },
+                "definitions": { "Addr": { "type": "string" } }
+            }
+        }
+    }"##;
+
+    #[test]
+    fn test_generate() {
+        let code = generate(SCHEMA).unwrap();
+
+        assert!(code.contains("pub struct InstantiateMsg {\n    pub count: i32,\n}"));
+        assert!(code.contains("pub enum ExecuteMsg {\n    Increment {},\n"));
+        assert!(code.contains("    /// Resets the counter\n    Reset {\n        count: i32,"));
+        assert!(code.contains(
+            "        #[serde(default, skip_serializing_if = \"Option::is_none\")]\n        r#type: Option<Kind>,"
+        ));
+        assert!(code.contains("pub enum Kind {\n    Soft,\n    Hard,\n}"));
+        assert!(code.contains("pub enum QueryMsg {\n    GetCount,\n}"));
+        assert!(code.contains("pub type Addr = String;"));
+        assert!(code.contains("    pub owners: Vec<Addr>,"));
+        assert!(code.contains(
+            "pub type CwCounterContract<'a, T> = cosm_tome::modules::cosmwasm::contract::Contract<'a, T, ExecuteMsg, QueryMsg, cosm_tome::modules::cosmwasm::model::Empty>;"
+        ));
+    }
+
+    #[test]
+    fn test_generate_missing_contract_name() {
+        let err = generate("{}").unwrap_err();
+
+        assert!(matches!(err, CodegenError::Unsupported { .. }));
+    }
+}
@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    chain::{coin::Coin, request::TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::{
+        auth::model::Address,
+        tx::model::{BroadcastMode, PendingTx},
+    },
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::CosmwasmError,
+    model::{
+        Empty, ExecRequest, ExecResponse, InstantiateRequest, InstantiateResponse, MigrateRequest,
+        MigrateResponse,
+    },
+};
+
+/// Handle to a deployed contract that only accepts its own `E`xecute, `Q`uery and `M`igrate msg types.
+///
+/// The msg types can be written by hand or generated from the contract's schema with `codegen::generate()`.
+///
+/// ```no_run
+/// # use cosm_tome::clients::client::{CosmTome, CosmosClient};
+/// # use cosm_tome::modules::cosmwasm::contract::Contract;
+/// # use cosm_tome::modules::auth::model::Address;
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize)]
+/// #[serde(rename_all = "snake_case")]
+/// enum ExecuteMsg {
+///     Increment {},
+/// }
+///
+/// #[derive(Serialize)]
+/// #[serde(rename_all = "snake_case")]
+/// enum QueryMsg {
+///     GetCount {},
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct GetCountResponse {
+///     count: i32,
+/// }
+///
+/// # async fn run<T: CosmosClient>(cosm_tome: CosmTome<T>, address: Address) {
+/// let counter = Contract::<_, ExecuteMsg, QueryMsg>::new(&cosm_tome, address);
+///
+/// let res: GetCountResponse = counter.query(&QueryMsg::GetCount {}).await.unwrap();
+/// # }
+/// ```
+pub struct Contract<'a, T: CosmosClient, E, Q, M = Empty> {
+    client: &'a CosmTome<T>,
+    address: Address,
+    msgs: PhantomData<(E, Q, M)>,
+}
+
+impl<'a, T, E, Q, M> Contract<'a, T, E, Q, M>
+where
+    T: CosmosClient,
+    E: Serialize,
+    Q: Serialize,
+    M: Serialize,
+{
+    pub fn new(client: &'a CosmTome<T>, address: Address) -> Self {
+        Self {
+            client,
+            address,
+            msgs: PhantomData,
+        }
+    }
+
+    /// Instantiates a new contract and returns a handle to it
+    pub async fn instantiate<I: Serialize>(
+        client: &'a CosmTome<T>,
+        req: InstantiateRequest<I>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<(Self, InstantiateResponse), CosmwasmError> {
+        let res = client.wasm_instantiate(req, key, tx_options).await?;
+
+        Ok((Self::new(client, res.address.clone()), res))
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    pub async fn execute(
+        &self,
+        msg: E,
+        funds: Vec<Coin>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        let req = ExecRequest {
+            address: self.address.clone(),
+            msg,
+            funds,
+        };
+
+        self.client.wasm_execute(req, key, tx_options).await
+    }
+
+    /// Non-blocking `execute()` that returns once the tx is broadcast with `mode`
+    pub async fn execute_async(
+        &self,
+        msg: E,
+        funds: Vec<Coin>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        let req = ExecRequest {
+            address: self.address.clone(),
+            msg,
+            funds,
+        };
+
+        self.client
+            .wasm_execute_batch_async(vec![req], key, tx_options, mode)
+            .await
+    }
+
+    /// Queries the contract, deserializing its json response into `R`
+    pub async fn query<R: DeserializeOwned>(&self, msg: &Q) -> Result<R, CosmwasmError> {
+        let res = self.client.wasm_query(self.address.clone(), msg).await?;
+
+        Ok(res.data()?)
+    }
+
+    pub async fn migrate(
+        &self,
+        new_code_id: u64,
+        msg: M,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<MigrateResponse, CosmwasmError> {
+        let req = MigrateRequest {
+            address: self.address.clone(),
+            new_code_id,
+            msg,
+        };
+
+        self.client.wasm_migrate(req, key, tx_options).await
+    }
+
+    /// Non-blocking `migrate()` that returns once the tx is broadcast with `mode`
+    pub async fn migrate_async(
+        &self,
+        new_code_id: u64,
+        msg: M,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<MigrateResponse, CosmwasmError>, CosmwasmError> {
+        let req = MigrateRequest {
+            address: self.address.clone(),
+            new_code_id,
+            msg,
+        };
+
+        self.client
+            .wasm_migrate_batch_async(vec![req], key, tx_options, mode)
+            .await
+    }
+}
+
+impl<'a, T: CosmosClient, E, Q, M> Clone for Contract<'a, T, E, Q, M> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            address: self.address.clone(),
+            msgs: PhantomData,
+        }
+    }
+}
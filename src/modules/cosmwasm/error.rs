@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 use crate::{
-    chain::error::ChainError,
+    chain::error::{ChainError, DeserializeError},
     modules::{auth::error::AccountError, tx::error::TxError},
 };
 
@@ -15,9 +15,24 @@ pub enum CosmwasmError {
     #[error("unsupported instantiate permission AccessType: {i:?}")]
     AccessType { i: i32 },
 
+    #[error("unsupported ContractCodeHistoryOperationType: {i:?}")]
+    HistoryOperation { i: i32 },
+
     #[error("missing event from chain response")]
     MissingEvent,
 
+    #[error("contract info missing from chain response")]
+    MissingContractInfo,
+
+    #[error("code info missing from chain response")]
+    MissingCodeInfo,
+
+    #[error("params missing from chain response")]
+    MissingParams,
+
+    #[error(transparent)]
+    DeserializeError(#[from] DeserializeError),
+
     #[error(transparent)]
     TxError(#[from] TxError),
 
@@ -33,3 +48,18 @@ impl CosmwasmError {
         CosmwasmError::JsonSerialize { source: e }
     }
 }
+
+#[derive(Error, Debug)]
+pub enum CodegenError {
+    #[error("invalid contract schema json")]
+    Json(#[from] SerdeJsonError),
+
+    #[error("unsupported contract schema: {message}")]
+    Unsupported { message: String },
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Fmt(#[from] std::fmt::Error),
+}
@@ -3,3 +3,9 @@ pub mod api;
 pub mod model;
 
 pub mod error;
+
+pub mod proto;
+
+pub mod contract;
+
+pub mod codegen;
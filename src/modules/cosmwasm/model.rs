@@ -1,22 +1,30 @@
 use cosmrs::proto::cosmwasm::wasm::v1::MsgStoreCode;
 use cosmrs::proto::cosmwasm::wasm::v1::{
-    AccessConfig as ProtoAccessConfig, AccessType as ProtoAccessType, MsgExecuteContract,
-    MsgInstantiateContract, MsgMigrateContract, QuerySmartContractStateResponse,
+    AbsoluteTxPosition as ProtoAbsoluteTxPosition, AccessConfig as ProtoAccessConfig,
+    AccessType as ProtoAccessType, CodeInfoResponse,
+    ContractCodeHistoryEntry as ProtoContractCodeHistoryEntry, ContractInfo as ProtoContractInfo,
+    Model, MsgClearAdmin, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
+    MsgUpdateAdmin, QuerySmartContractStateResponse,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::chain::error::DeserializeError;
 use crate::chain::msg::Msg;
 use crate::{
     chain::{
         coin::Coin,
+        request::PaginationResponse,
         response::{ChainResponse, ChainTxResponse, Code},
     },
     modules::auth::model::Address,
 };
 
-use super::error::CosmwasmError;
+use super::{
+    error::CosmwasmError,
+    proto::{MsgInstantiateContract2, Params as ProtoParams},
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct StoreCodeRequest {
@@ -188,6 +196,130 @@ impl TryFrom<InstantiateRequestProto> for MsgInstantiateContract {
     }
 }
 
+/// Instantiates a contract at a deterministic address, computable ahead of time with `instantiate2_address()` (wasmd v0.29+)
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Instantiate2Request<S: Serialize> {
+    pub code_id: u64,
+    pub msg: S,
+    pub label: String,
+    pub admin: Option<Address>,
+    pub funds: Vec<Coin>,
+
+    /// arbitrary bytes (max 64) making the address unique per creator and code checksum
+    pub salt: Vec<u8>,
+
+    /// also derive the address from the instantiate `msg`. Not supported by `instantiate2_address()`.
+    pub fix_msg: bool,
+}
+
+impl<S: Serialize> Instantiate2Request<S> {
+    pub fn to_proto(self, signer_addr: Address) -> Result<Instantiate2RequestProto, CosmwasmError> {
+        let payload = serde_json::to_vec(&self.msg).map_err(CosmwasmError::json)?;
+
+        Ok(Instantiate2RequestProto {
+            signer_addr,
+            code_id: self.code_id,
+            msg: payload,
+            label: self.label,
+            admin: self.admin,
+            funds: self.funds,
+            salt: self.salt,
+            fix_msg: self.fix_msg,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Instantiate2RequestProto {
+    pub signer_addr: Address,
+    pub code_id: u64,
+    pub msg: Vec<u8>,
+    pub label: String,
+    pub admin: Option<Address>,
+    pub funds: Vec<Coin>,
+    pub salt: Vec<u8>,
+    pub fix_msg: bool,
+}
+
+impl Msg for Instantiate2RequestProto {
+    type Proto = MsgInstantiateContract2;
+    type Err = CosmwasmError;
+}
+
+impl TryFrom<MsgInstantiateContract2> for Instantiate2RequestProto {
+    type Error = CosmwasmError;
+
+    fn try_from(msg: MsgInstantiateContract2) -> Result<Self, Self::Error> {
+        let admin = if msg.admin.is_empty() {
+            None
+        } else {
+            Some(msg.admin.parse()?)
+        };
+
+        Ok(Self {
+            signer_addr: msg.sender.parse()?,
+            code_id: msg.code_id,
+            msg: msg.msg,
+            label: msg.label,
+            admin,
+            funds: msg
+                .funds
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            salt: msg.salt,
+            fix_msg: msg.fix_msg,
+        })
+    }
+}
+
+impl TryFrom<Instantiate2RequestProto> for MsgInstantiateContract2 {
+    type Error = CosmwasmError;
+
+    fn try_from(req: Instantiate2RequestProto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sender: req.signer_addr.into(),
+            admin: req.admin.map(Into::into).unwrap_or_default(),
+            code_id: req.code_id,
+            label: req.label,
+            msg: req.msg,
+            funds: req.funds.into_iter().map(Into::into).collect(),
+            salt: req.salt,
+            fix_msg: req.fix_msg,
+        })
+    }
+}
+
+/// Computes the address `creator` will get when instantiating the code with `checksum` through
+/// `Instantiate2Request` with `salt` and `fix_msg: false`, without talking to the chain.
+///
+/// `checksum` is the sha256 hash of the wasm bytecode, as returned by `wasm_query_code()`.
+/// This follows wasmd's `BuildContractAddressPredictable`.
+pub fn instantiate2_address(
+    checksum: &[u8],
+    creator: &Address,
+    salt: &[u8],
+    prefix: &str,
+) -> Result<Address, CosmwasmError> {
+    let creator = creator.to_bytes();
+
+    // length prefixed checksum | creator | salt | msg, with an empty msg since `fix_msg` is false
+    let mut key = Vec::new();
+    for part in [checksum, &creator, salt, &[]] {
+        key.extend_from_slice(&(part.len() as u64).to_be_bytes());
+        key.extend_from_slice(part);
+    }
+
+    // ADR-028 module address for the `wasm` module
+    let addr = Sha256::new()
+        .chain_update(Sha256::digest(b"module"))
+        .chain_update(b"wasm\0")
+        .chain_update(&key)
+        .finalize();
+
+    Ok(Address::new(prefix, &addr)?)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct InstantiateResponse {
     pub address: Address,
@@ -321,6 +453,10 @@ impl AsRef<ChainResponse> for QueryResponse {
     }
 }
 
+/// Empty msg that serializes to `{}`, for contracts whose migrate entrypoint takes no arguments
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Empty {}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct MigrateRequest<S: Serialize> {
     pub address: Address,
@@ -397,6 +533,128 @@ impl AsRef<ChainTxResponse> for MigrateResponse {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UpdateAdminRequest {
+    pub address: Address,
+    pub new_admin: Address,
+}
+
+impl UpdateAdminRequest {
+    pub fn to_proto(self, signer_addr: Address) -> Result<UpdateAdminRequestProto, CosmwasmError> {
+        Ok(UpdateAdminRequestProto {
+            signer_addr,
+            contract_addr: self.address,
+            new_admin: self.new_admin,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UpdateAdminRequestProto {
+    pub signer_addr: Address,
+    pub contract_addr: Address,
+    pub new_admin: Address,
+}
+
+impl Msg for UpdateAdminRequestProto {
+    type Proto = MsgUpdateAdmin;
+    type Err = CosmwasmError;
+}
+
+impl TryFrom<MsgUpdateAdmin> for UpdateAdminRequestProto {
+    type Error = CosmwasmError;
+
+    fn try_from(msg: MsgUpdateAdmin) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signer_addr: msg.sender.parse()?,
+            contract_addr: msg.contract.parse()?,
+            new_admin: msg.new_admin.parse()?,
+        })
+    }
+}
+
+impl TryFrom<UpdateAdminRequestProto> for MsgUpdateAdmin {
+    type Error = CosmwasmError;
+
+    fn try_from(req: UpdateAdminRequestProto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sender: req.signer_addr.into(),
+            new_admin: req.new_admin.into(),
+            contract: req.contract_addr.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UpdateAdminResponse {
+    pub res: ChainTxResponse,
+}
+
+impl AsRef<ChainTxResponse> for UpdateAdminResponse {
+    fn as_ref(&self) -> &ChainTxResponse {
+        &self.res
+    }
+}
+
+/// Removes the admin of a contract, making it immutable since nobody can migrate it anymore
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ClearAdminRequest {
+    pub address: Address,
+}
+
+impl ClearAdminRequest {
+    pub fn to_proto(self, signer_addr: Address) -> Result<ClearAdminRequestProto, CosmwasmError> {
+        Ok(ClearAdminRequestProto {
+            signer_addr,
+            contract_addr: self.address,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ClearAdminRequestProto {
+    pub signer_addr: Address,
+    pub contract_addr: Address,
+}
+
+impl Msg for ClearAdminRequestProto {
+    type Proto = MsgClearAdmin;
+    type Err = CosmwasmError;
+}
+
+impl TryFrom<MsgClearAdmin> for ClearAdminRequestProto {
+    type Error = CosmwasmError;
+
+    fn try_from(msg: MsgClearAdmin) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signer_addr: msg.sender.parse()?,
+            contract_addr: msg.contract.parse()?,
+        })
+    }
+}
+
+impl TryFrom<ClearAdminRequestProto> for MsgClearAdmin {
+    type Error = CosmwasmError;
+
+    fn try_from(req: ClearAdminRequestProto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sender: req.signer_addr.into(),
+            contract: req.contract_addr.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ClearAdminResponse {
+    pub res: ChainTxResponse,
+}
+
+impl AsRef<ChainTxResponse> for ClearAdminResponse {
+    fn as_ref(&self) -> &ChainTxResponse {
+        &self.res
+    }
+}
+
 impl From<QuerySmartContractStateResponse> for ChainResponse {
     fn from(res: QuerySmartContractStateResponse) -> ChainResponse {
         ChainResponse {
@@ -512,3 +770,325 @@ impl From<ProtoAccessType> for AccessType {
         }
     }
 }
+
+/// Block height and tx index a contract was created or migrated at
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct AbsoluteTxPosition {
+    pub block_height: u64,
+    pub tx_index: u64,
+}
+
+impl From<ProtoAbsoluteTxPosition> for AbsoluteTxPosition {
+    fn from(pos: ProtoAbsoluteTxPosition) -> Self {
+        Self {
+            block_height: pos.block_height,
+            tx_index: pos.tx_index,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub code_id: u64,
+    pub creator: Address,
+    pub admin: Option<Address>,
+    pub label: String,
+    pub created: Option<AbsoluteTxPosition>,
+
+    /// set if the contract is IBC enabled
+    pub ibc_port_id: Option<String>,
+}
+
+impl TryFrom<ProtoContractInfo> for ContractInfo {
+    type Error = CosmwasmError;
+
+    fn try_from(info: ProtoContractInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code_id: info.code_id,
+            creator: info.creator.parse()?,
+            admin: if info.admin.is_empty() {
+                None
+            } else {
+                Some(info.admin.parse()?)
+            },
+            label: info.label,
+            created: info.created.map(Into::into),
+            ibc_port_id: Some(info.ibc_port_id).filter(|p| !p.is_empty()),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractInfoResponse {
+    pub address: Address,
+    pub contract_info: ContractInfo,
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash, PartialOrd, Ord,
+)]
+#[repr(i32)]
+pub enum ContractCodeHistoryOperationType {
+    /// CONTRACT_CODE_HISTORY_OPERATION_TYPE_UNSPECIFIED placeholder for empty value
+    Unspecified = 0,
+    /// CONTRACT_CODE_HISTORY_OPERATION_TYPE_INIT contract was instantiated
+    Init = 1,
+    /// CONTRACT_CODE_HISTORY_OPERATION_TYPE_MIGRATE contract was migrated to a new code id
+    Migrate = 2,
+    /// CONTRACT_CODE_HISTORY_OPERATION_TYPE_GENESIS contract was imported from genesis
+    Genesis = 3,
+}
+
+impl TryFrom<i32> for ContractCodeHistoryOperationType {
+    type Error = CosmwasmError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ContractCodeHistoryOperationType::Unspecified as i32 => {
+                Ok(ContractCodeHistoryOperationType::Unspecified)
+            }
+            x if x == ContractCodeHistoryOperationType::Init as i32 => {
+                Ok(ContractCodeHistoryOperationType::Init)
+            }
+            x if x == ContractCodeHistoryOperationType::Migrate as i32 => {
+                Ok(ContractCodeHistoryOperationType::Migrate)
+            }
+            x if x == ContractCodeHistoryOperationType::Genesis as i32 => {
+                Ok(ContractCodeHistoryOperationType::Genesis)
+            }
+            _ => Err(CosmwasmError::HistoryOperation { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ContractCodeHistoryEntry {
+    pub operation: ContractCodeHistoryOperationType,
+    pub code_id: u64,
+    pub updated: Option<AbsoluteTxPosition>,
+
+    /// json encoded instantiate or migrate msg
+    pub msg: Vec<u8>,
+}
+
+impl ContractCodeHistoryEntry {
+    pub fn msg<'a, T: Deserialize<'a>>(&'a self) -> Result<T, DeserializeError> {
+        Ok(serde_json::from_slice(&self.msg)?)
+    }
+}
+
+impl TryFrom<ProtoContractCodeHistoryEntry> for ContractCodeHistoryEntry {
+    type Error = CosmwasmError;
+
+    fn try_from(entry: ProtoContractCodeHistoryEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operation: entry.operation.try_into()?,
+            code_id: entry.code_id,
+            updated: entry.updated.map(Into::into),
+            msg: entry.msg,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ContractHistoryResponse {
+    pub entries: Vec<ContractCodeHistoryEntry>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct RawQueryResponse {
+    /// raw value stored under the key, or `None` if the key is not set
+    pub data: Option<Vec<u8>>,
+}
+
+/// Raw key / value pair in a contract's storage
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ContractStateEntry {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl From<Model> for ContractStateEntry {
+    fn from(model: Model) -> Self {
+        Self {
+            key: model.key,
+            value: model.value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AllStateResponse {
+    pub models: Vec<ContractStateEntry>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodeInfo {
+    pub code_id: u64,
+    pub creator: Address,
+
+    /// sha256 hash of the wasm bytecode
+    pub checksum: Vec<u8>,
+}
+
+impl TryFrom<CodeInfoResponse> for CodeInfo {
+    type Error = CosmwasmError;
+
+    fn try_from(info: CodeInfoResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code_id: info.code_id,
+            creator: info.creator.parse()?,
+            checksum: info.data_hash,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodeResponse {
+    pub code_info: CodeInfo,
+
+    /// the wasm bytecode
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodesResponse {
+    pub code_infos: Vec<CodeInfo>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractsByCodeResponse {
+    pub contracts: Vec<Address>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PinnedCodesResponse {
+    pub code_ids: Vec<u64>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Params {
+    pub code_upload_access: AccessType,
+
+    /// addresses allowed to upload code when `code_upload_access` is `OnlyAddress` (or `AnyOfAddresses` on wasmd v0.31+)
+    pub code_upload_addresses: Vec<Address>,
+
+    pub instantiate_default_permission: AccessType,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = CosmwasmError;
+
+    fn try_from(params: ProtoParams) -> Result<Self, Self::Error> {
+        let access = params.code_upload_access.unwrap_or_default();
+
+        Ok(Self {
+            code_upload_access: access.permission.try_into()?,
+            code_upload_addresses: Some(access.address)
+                .filter(|a| !a.is_empty())
+                .into_iter()
+                .chain(access.addresses)
+                .map(|a| a.parse())
+                .collect::<Result<Vec<_>, _>>()?,
+            instantiate_default_permission: params.instantiate_default_permission.try_into()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Params,
+}
+
+/// cw-utils `Expiration`, used by the cw20 allowance and cw721 approval msgs
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    AtHeight(u64),
+    /// Nanoseconds since the unix epoch
+    AtTime(
+        #[serde(with = "uint_str")]
+        #[schemars(with = "String")]
+        u64,
+    ),
+    Never {},
+}
+
+impl Default for Expiration {
+    fn default() -> Self {
+        Expiration::Never {}
+    }
+}
+
+/// (De)serializes integers as json strings, the way cosmwasm's `Uint64`/`Uint128` do
+pub(crate) mod uint_str {
+    use std::{fmt::Display, str::FromStr};
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: Display, S: Serializer>(v: &T, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, T, D>(d: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(d)?.parse().map_err(de::Error::custom)
+    }
+}
+
+/// (De)serializes bytes as a base64 json string, the way cosmwasm's `Binary` does
+pub(crate) mod binary {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&base64::encode(v))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        base64::decode(String::deserialize(d)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::auth::model::Address;
+
+    use super::instantiate2_address;
+
+    fn hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_instantiate2_address() {
+        // test vector from cosmwasm-std's `instantiate2_address_works`
+        let checksum = hex("13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5");
+        let creator =
+            Address::new("juno", &hex("9999999999aaaaaaaaaabbbbbbbbbbcccccccccc")).unwrap();
+
+        let addr = instantiate2_address(&checksum, &creator, &hex("61"), "juno").unwrap();
+
+        assert_eq!(
+            addr.to_bytes(),
+            hex("5e865d3e45ad3e961f77fd77d46543417ced44d924dc3e079b5415ff6775f847")
+        );
+        assert_eq!(addr.prefix(), "juno");
+    }
+}
@@ -0,0 +1,59 @@
+//! CosmWasm protos missing from `cosmos-sdk-proto`.
+//!
+//! `cosmos-sdk-proto` is generated from wasmd v0.23, which predates the `Params` query,
+//! the `AccessConfig.addresses` field and `MsgInstantiateContract2`,
+//! so these mirror the upstream `.proto` definitions field for field.
+
+use cosmrs::proto::{cosmos::base::v1beta1::Coin, traits::TypeUrl};
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccessConfig {
+    #[prost(int32, tag = "1")]
+    pub permission: i32,
+    /// deprecated in wasmd v0.31 in favour of `addresses`
+    #[prost(string, tag = "2")]
+    pub address: String,
+    #[prost(string, repeated, tag = "3")]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Params {
+    #[prost(message, optional, tag = "1")]
+    pub code_upload_access: Option<AccessConfig>,
+    #[prost(int32, tag = "2")]
+    pub instantiate_default_permission: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgInstantiateContract2 {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub admin: String,
+    #[prost(uint64, tag = "3")]
+    pub code_id: u64,
+    #[prost(string, tag = "4")]
+    pub label: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub msg: Vec<u8>,
+    #[prost(message, repeated, tag = "6")]
+    pub funds: Vec<Coin>,
+    #[prost(bytes = "vec", tag = "7")]
+    pub salt: Vec<u8>,
+    #[prost(bool, tag = "8")]
+    pub fix_msg: bool,
+}
+
+impl TypeUrl for MsgInstantiateContract2 {
+    const TYPE_URL: &'static str = "/cosmwasm.wasm.v1.MsgInstantiateContract2";
+}
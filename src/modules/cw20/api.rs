@@ -0,0 +1,375 @@
+use serde::Serialize;
+
+use crate::chain::request::TxOptions;
+use crate::clients::client::{CosmTome, CosmosClient};
+use crate::modules::auth::model::Address;
+use crate::modules::cosmwasm::{error::CosmwasmError, model::ExecResponse};
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::signing_key::key::SigningKey;
+
+use super::model::{
+    AllAccountsResponse, AllowanceRequest, AllowanceResponse, BalanceResponse, Cw20QueryMsg,
+    SendFromRequest, SendRequest, TokenInfoResponse, TransferFromRequest, TransferRequest,
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn cw20_transfer(
+        &self,
+        req: TransferRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.into(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_transfer()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_transfer_async(
+        &self,
+        req: TransferRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.into()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_send<S: Serialize>(
+        &self,
+        req: SendRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.try_into()?, key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_send()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_send_async<S: Serialize>(
+        &self,
+        req: SendRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.try_into()?], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_increase_allowance(
+        &self,
+        req: AllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.increase(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_increase_allowance()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_increase_allowance_async(
+        &self,
+        req: AllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.increase()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_decrease_allowance(
+        &self,
+        req: AllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.decrease(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_decrease_allowance()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_decrease_allowance_async(
+        &self,
+        req: AllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.decrease()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_transfer_from(
+        &self,
+        req: TransferFromRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.into(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_transfer_from()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_transfer_from_async(
+        &self,
+        req: TransferFromRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.into()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_send_from<S: Serialize>(
+        &self,
+        req: SendFromRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.try_into()?, key, tx_options).await
+    }
+
+    /// Non-blocking `cw20_send_from()` that returns once the tx is broadcast with `mode`
+    pub async fn cw20_send_from_async<S: Serialize>(
+        &self,
+        req: SendFromRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.try_into()?], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw20_query_balance(
+        &self,
+        contract: Address,
+        address: Address,
+    ) -> Result<BalanceResponse, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw20QueryMsg::Balance { address })
+            .await?;
+
+        Ok(res.data()?)
+    }
+
+    pub async fn cw20_query_token_info(
+        &self,
+        contract: Address,
+    ) -> Result<TokenInfoResponse, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw20QueryMsg::TokenInfo {})
+            .await?;
+
+        Ok(res.data()?)
+    }
+
+    pub async fn cw20_query_allowance(
+        &self,
+        contract: Address,
+        owner: Address,
+        spender: Address,
+    ) -> Result<AllowanceResponse, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw20QueryMsg::Allowance { owner, spender })
+            .await?;
+
+        Ok(res.data()?)
+    }
+
+    /// Lists the token holders, `limit` at a time, starting after the `start_after` account.
+    /// Pass the last account of the previous page as `start_after` to get the next one.
+    pub async fn cw20_query_all_accounts(
+        &self,
+        contract: Address,
+        start_after: Option<Address>,
+        limit: Option<u32>,
+    ) -> Result<AllAccountsResponse, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw20QueryMsg::AllAccounts { start_after, limit })
+            .await?;
+
+        Ok(res.data()?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::{
+        cosmos::{
+            auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+            tx::v1beta1::{TxBody, TxRaw},
+        },
+        cosmwasm::wasm::v1::{
+            MsgExecuteContract, QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+        },
+        traits::{Message, MessageExt},
+    };
+
+    use crate::{
+        chain::{
+            fee::GasInfo,
+            request::TxOptions,
+            response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::{
+            cosmwasm::model::Expiration, cw20::model::TransferRequest, tx::model::BroadcastMode,
+        },
+        signing_key::key::SigningKey,
+    };
+
+    #[tokio::test]
+    async fn test_cw20_transfer_async() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(|tx, mode| {
+                assert_eq!(mode, BroadcastMode::Async);
+
+                let tx: TxRaw = tx.clone().into();
+                let msgs = TxBody::decode(tx.body_bytes.as_slice()).unwrap().messages;
+                let msg = MsgExecuteContract::decode(msgs[0].value.as_slice()).unwrap();
+
+                assert_eq!(msg.contract, "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea");
+                assert_eq!(
+                    String::from_utf8(msg.msg).unwrap(),
+                    r#"{"transfer":{"recipient":"juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730","amount":"10"}}"#
+                );
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(1)
+            .returning(|tx_hash| {
+                Ok(ChainTxResponse {
+                    tx_hash: tx_hash.to_string(),
+                    height: 1337,
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let pending = cosm_tome
+            .cw20_transfer_async(
+                TransferRequest {
+                    contract: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                        .parse()
+                        .unwrap(),
+                    recipient: "juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730"
+                        .parse()
+                        .unwrap(),
+                    amount: 10,
+                },
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Async,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(pending.tx_hash(), "TX_HASH_0");
+
+        let res = pending.wait(&cosm_tome).await.unwrap();
+        assert_eq!(res.res.height, 1337);
+    }
+
+    #[tokio::test]
+    async fn test_cw20_query_allowance() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QuerySmartContractStateRequest, QuerySmartContractStateResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmwasm.wasm.v1.Query/SmartContractState");
+                assert_eq!(
+                    String::from_utf8(req.query_data).unwrap(),
+                    r#"{"allowance":{"owner":"juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg","spender":"juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730"}}"#
+                );
+
+                Ok(QuerySmartContractStateResponse {
+                    data: br#"{"allowance":"340282366920938463463374607431768211455","expires":{"at_time":"1700000000000000000"}}"#.to_vec(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .cw20_query_allowance(
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.allowance, u128::MAX);
+        assert_eq!(res.expires, Expiration::AtTime(1_700_000_000_000_000_000));
+    }
+}
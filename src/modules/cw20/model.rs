@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    auth::model::Address,
+    cosmwasm::{
+        error::CosmwasmError,
+        model::{binary, uint_str, ExecRequest, Expiration},
+    },
+};
+
+/// The cw20 execute msgs that have helpers in `cw20::api`.
+///
+/// Public so several cw20 msgs can be batched into one tx with `wasm_execute_batch()`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20ExecuteMsg {
+    Transfer {
+        recipient: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+    },
+    Send {
+        contract: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+        #[serde(with = "binary")]
+        msg: Vec<u8>,
+    },
+    IncreaseAllowance {
+        spender: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+        expires: Option<Expiration>,
+    },
+    TransferFrom {
+        owner: Address,
+        recipient: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+    },
+    SendFrom {
+        owner: Address,
+        contract: Address,
+        #[serde(with = "uint_str")]
+        amount: u128,
+        #[serde(with = "binary")]
+        msg: Vec<u8>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw20QueryMsg {
+    Balance {
+        address: Address,
+    },
+    TokenInfo {},
+    Allowance {
+        owner: Address,
+        spender: Address,
+    },
+    AllAccounts {
+        start_after: Option<Address>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferRequest {
+    /// cw20 token contract
+    pub contract: Address,
+    pub recipient: Address,
+    pub amount: u128,
+}
+
+impl From<TransferRequest> for ExecRequest<Cw20ExecuteMsg> {
+    fn from(req: TransferRequest) -> Self {
+        ExecRequest {
+            address: req.contract,
+            msg: Cw20ExecuteMsg::Transfer {
+                recipient: req.recipient,
+                amount: req.amount,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+/// Sends tokens to `recipient_contract`, triggering its `receive` entrypoint with `msg`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SendRequest<S: Serialize> {
+    /// cw20 token contract
+    pub contract: Address,
+    pub recipient_contract: Address,
+    pub amount: u128,
+    pub msg: S,
+}
+
+impl<S: Serialize> TryFrom<SendRequest<S>> for ExecRequest<Cw20ExecuteMsg> {
+    type Error = CosmwasmError;
+
+    fn try_from(req: SendRequest<S>) -> Result<Self, Self::Error> {
+        Ok(ExecRequest {
+            address: req.contract,
+            msg: Cw20ExecuteMsg::Send {
+                contract: req.recipient_contract,
+                amount: req.amount,
+                msg: serde_json::to_vec(&req.msg).map_err(CosmwasmError::json)?,
+            },
+            funds: vec![],
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AllowanceRequest {
+    /// cw20 token contract
+    pub contract: Address,
+    pub spender: Address,
+    pub amount: u128,
+    /// Replaces the allowance's current expiration when set
+    pub expires: Option<Expiration>,
+}
+
+impl AllowanceRequest {
+    pub(crate) fn increase(self) -> ExecRequest<Cw20ExecuteMsg> {
+        ExecRequest {
+            address: self.contract,
+            msg: Cw20ExecuteMsg::IncreaseAllowance {
+                spender: self.spender,
+                amount: self.amount,
+                expires: self.expires,
+            },
+            funds: vec![],
+        }
+    }
+
+    pub(crate) fn decrease(self) -> ExecRequest<Cw20ExecuteMsg> {
+        ExecRequest {
+            address: self.contract,
+            msg: Cw20ExecuteMsg::DecreaseAllowance {
+                spender: self.spender,
+                amount: self.amount,
+                expires: self.expires,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+/// Transfers tokens out of `owner`'s balance using the signer's allowance
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferFromRequest {
+    /// cw20 token contract
+    pub contract: Address,
+    pub owner: Address,
+    pub recipient: Address,
+    pub amount: u128,
+}
+
+impl From<TransferFromRequest> for ExecRequest<Cw20ExecuteMsg> {
+    fn from(req: TransferFromRequest) -> Self {
+        ExecRequest {
+            address: req.contract,
+            msg: Cw20ExecuteMsg::TransferFrom {
+                owner: req.owner,
+                recipient: req.recipient,
+                amount: req.amount,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+/// Sends tokens out of `owner`'s balance to `recipient_contract` using the signer's allowance
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SendFromRequest<S: Serialize> {
+    /// cw20 token contract
+    pub contract: Address,
+    pub owner: Address,
+    pub recipient_contract: Address,
+    pub amount: u128,
+    pub msg: S,
+}
+
+impl<S: Serialize> TryFrom<SendFromRequest<S>> for ExecRequest<Cw20ExecuteMsg> {
+    type Error = CosmwasmError;
+
+    fn try_from(req: SendFromRequest<S>) -> Result<Self, Self::Error> {
+        Ok(ExecRequest {
+            address: req.contract,
+            msg: Cw20ExecuteMsg::SendFrom {
+                owner: req.owner,
+                contract: req.recipient_contract,
+                amount: req.amount,
+                msg: serde_json::to_vec(&req.msg).map_err(CosmwasmError::json)?,
+            },
+            funds: vec![],
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct BalanceResponse {
+    #[serde(with = "uint_str")]
+    pub balance: u128,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TokenInfoResponse {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u8,
+    #[serde(with = "uint_str")]
+    pub total_supply: u128,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AllowanceResponse {
+    #[serde(with = "uint_str")]
+    pub allowance: u128,
+    pub expires: Expiration,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AllAccountsResponse {
+    pub accounts: Vec<Address>,
+}
@@ -0,0 +1,233 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::chain::request::TxOptions;
+use crate::clients::client::{CosmTome, CosmosClient};
+use crate::modules::auth::model::Address;
+use crate::modules::cosmwasm::{error::CosmwasmError, model::ExecResponse};
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::signing_key::key::SigningKey;
+
+use super::model::{
+    ApproveRequest, Cw721QueryMsg, NftInfoResponse, OwnerOfResponse, RevokeRequest, SendNftRequest,
+    TokensResponse, TransferNftRequest,
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn cw721_transfer_nft(
+        &self,
+        req: TransferNftRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.into(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw721_transfer_nft()` that returns once the tx is broadcast with `mode`
+    pub async fn cw721_transfer_nft_async(
+        &self,
+        req: TransferNftRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.into()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw721_send_nft<S: Serialize>(
+        &self,
+        req: SendNftRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.try_into()?, key, tx_options).await
+    }
+
+    /// Non-blocking `cw721_send_nft()` that returns once the tx is broadcast with `mode`
+    pub async fn cw721_send_nft_async<S: Serialize>(
+        &self,
+        req: SendNftRequest<S>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.try_into()?], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw721_approve(
+        &self,
+        req: ApproveRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.into(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw721_approve()` that returns once the tx is broadcast with `mode`
+    pub async fn cw721_approve_async(
+        &self,
+        req: ApproveRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.into()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw721_revoke(
+        &self,
+        req: RevokeRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, CosmwasmError> {
+        self.wasm_execute(req.into(), key, tx_options).await
+    }
+
+    /// Non-blocking `cw721_revoke()` that returns once the tx is broadcast with `mode`
+    pub async fn cw721_revoke_async(
+        &self,
+        req: RevokeRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<ExecResponse, CosmwasmError>, CosmwasmError> {
+        self.wasm_execute_batch_async(vec![req.into()], key, tx_options, mode)
+            .await
+    }
+
+    pub async fn cw721_query_owner_of(
+        &self,
+        contract: Address,
+        token_id: String,
+        include_expired: bool,
+    ) -> Result<OwnerOfResponse, CosmwasmError> {
+        let msg = Cw721QueryMsg::OwnerOf {
+            token_id,
+            include_expired: Some(include_expired),
+        };
+
+        let res = self.wasm_query(contract, &msg).await?;
+
+        Ok(res.data()?)
+    }
+
+    /// Queries the nft's metadata, deserializing the collection specific `extension` into `E`.
+    /// Use `Option<serde_json::Value>` when the extension's shape is unknown.
+    pub async fn cw721_query_nft_info<E: DeserializeOwned>(
+        &self,
+        contract: Address,
+        token_id: String,
+    ) -> Result<NftInfoResponse<E>, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw721QueryMsg::NftInfo { token_id })
+            .await?;
+
+        Ok(res.data()?)
+    }
+
+    /// Lists the token ids owned by `owner`, `limit` at a time, starting after the `start_after` token id
+    pub async fn cw721_query_tokens(
+        &self,
+        contract: Address,
+        owner: Address,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<TokensResponse, CosmwasmError> {
+        let msg = Cw721QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        };
+
+        let res = self.wasm_query(contract, &msg).await?;
+
+        Ok(res.data()?)
+    }
+
+    /// Lists every token id in the collection, `limit` at a time, starting after the `start_after` token id
+    pub async fn cw721_query_all_tokens(
+        &self,
+        contract: Address,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Result<TokensResponse, CosmwasmError> {
+        let res = self
+            .wasm_query(contract, &Cw721QueryMsg::AllTokens { start_after, limit })
+            .await?;
+
+        Ok(res.data()?)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmwasm::wasm::v1::{
+        QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+    };
+    use serde::Deserialize;
+
+    use crate::{
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+    };
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Metadata {
+        name: String,
+    }
+
+    #[tokio::test]
+    async fn test_cw721_query_nft_info() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QuerySmartContractStateRequest, QuerySmartContractStateResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmwasm.wasm.v1.Query/SmartContractState");
+                assert_eq!(
+                    String::from_utf8(req.query_data).unwrap(),
+                    r#"{"nft_info":{"token_id":"42"}}"#
+                );
+
+                Ok(QuerySmartContractStateResponse {
+                    data: br#"{"token_uri":null,"extension":{"name":"punk #42"}}"#.to_vec(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .cw721_query_nft_info::<Metadata>(
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+                "42".to_string(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.token_uri, None);
+        assert_eq!(
+            res.extension,
+            Metadata {
+                name: "punk #42".to_string()
+            }
+        );
+    }
+}
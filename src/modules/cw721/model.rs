@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::modules::{
+    auth::model::Address,
+    cosmwasm::{
+        error::CosmwasmError,
+        model::{binary, ExecRequest, Expiration},
+    },
+};
+
+/// The cw721 execute msgs that have helpers in `cw721::api`.
+///
+/// Public so several cw721 msgs can be batched into one tx with `wasm_execute_batch()`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw721ExecuteMsg {
+    TransferNft {
+        recipient: Address,
+        token_id: String,
+    },
+    SendNft {
+        contract: Address,
+        token_id: String,
+        #[serde(with = "binary")]
+        msg: Vec<u8>,
+    },
+    Approve {
+        spender: Address,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: Address,
+        token_id: String,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Cw721QueryMsg {
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    NftInfo {
+        token_id: String,
+    },
+    Tokens {
+        owner: Address,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferNftRequest {
+    /// cw721 collection contract
+    pub contract: Address,
+    pub recipient: Address,
+    pub token_id: String,
+}
+
+impl From<TransferNftRequest> for ExecRequest<Cw721ExecuteMsg> {
+    fn from(req: TransferNftRequest) -> Self {
+        ExecRequest {
+            address: req.contract,
+            msg: Cw721ExecuteMsg::TransferNft {
+                recipient: req.recipient,
+                token_id: req.token_id,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+/// Sends the nft to `recipient_contract`, triggering its `receive_nft` entrypoint with `msg`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SendNftRequest<S: Serialize> {
+    /// cw721 collection contract
+    pub contract: Address,
+    pub recipient_contract: Address,
+    pub token_id: String,
+    pub msg: S,
+}
+
+impl<S: Serialize> TryFrom<SendNftRequest<S>> for ExecRequest<Cw721ExecuteMsg> {
+    type Error = CosmwasmError;
+
+    fn try_from(req: SendNftRequest<S>) -> Result<Self, Self::Error> {
+        Ok(ExecRequest {
+            address: req.contract,
+            msg: Cw721ExecuteMsg::SendNft {
+                contract: req.recipient_contract,
+                token_id: req.token_id,
+                msg: serde_json::to_vec(&req.msg).map_err(CosmwasmError::json)?,
+            },
+            funds: vec![],
+        })
+    }
+}
+
+/// Allows `spender` to transfer or send the nft on the owner's behalf
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ApproveRequest {
+    /// cw721 collection contract
+    pub contract: Address,
+    pub spender: Address,
+    pub token_id: String,
+    pub expires: Option<Expiration>,
+}
+
+impl From<ApproveRequest> for ExecRequest<Cw721ExecuteMsg> {
+    fn from(req: ApproveRequest) -> Self {
+        ExecRequest {
+            address: req.contract,
+            msg: Cw721ExecuteMsg::Approve {
+                spender: req.spender,
+                token_id: req.token_id,
+                expires: req.expires,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RevokeRequest {
+    /// cw721 collection contract
+    pub contract: Address,
+    pub spender: Address,
+    pub token_id: String,
+}
+
+impl From<RevokeRequest> for ExecRequest<Cw721ExecuteMsg> {
+    fn from(req: RevokeRequest) -> Self {
+        ExecRequest {
+            address: req.contract,
+            msg: Cw721ExecuteMsg::Revoke {
+                spender: req.spender,
+                token_id: req.token_id,
+            },
+            funds: vec![],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Approval {
+    pub spender: Address,
+    pub expires: Expiration,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct OwnerOfResponse {
+    pub owner: Address,
+    pub approvals: Vec<Approval>,
+}
+
+/// `extension` holds the collection specific metadata, eg. cw721-metadata-onchain's `Metadata`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct NftInfoResponse<E> {
+    pub token_uri: Option<String>,
+    pub extension: E,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TokensResponse {
+    pub tokens: Vec<String>,
+}
@@ -0,0 +1,507 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::request::TxOptions,
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::DistributionError,
+    model::{
+        CommunityPoolResponse, DelegationRewardsResponse, DelegationTotalRewardsResponse,
+        FundCommunityPoolRequest, FundCommunityPoolRequestProto, FundCommunityPoolResponse,
+        ParamsResponse, SetWithdrawAddressRequest, SetWithdrawAddressRequestProto,
+        SetWithdrawAddressResponse, ValidatorCommissionResponse,
+        ValidatorOutstandingRewardsResponse, WithdrawAddressResponse, WithdrawCommissionRequest,
+        WithdrawCommissionRequestProto, WithdrawCommissionResponse, WithdrawRewardsRequest,
+        WithdrawRewardsRequestProto, WithdrawRewardsResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Withdraw the staking rewards a delegator has accrued from a validator
+    pub async fn distribution_withdraw_rewards(
+        &self,
+        req: WithdrawRewardsRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<WithdrawRewardsResponse, DistributionError> {
+        self.distribution_withdraw_rewards_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn distribution_withdraw_rewards_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<WithdrawRewardsResponse, DistributionError>
+    where
+        I: IntoIterator<Item = WithdrawRewardsRequest>,
+    {
+        self.distribution_withdraw_rewards_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `distribution_withdraw_rewards_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn distribution_withdraw_rewards_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<WithdrawRewardsResponse, DistributionError>, DistributionError>
+    where
+        I: IntoIterator<Item = WithdrawRewardsRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<WithdrawRewardsRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(WithdrawRewardsResponse { res })
+            })
+            .await?)
+    }
+
+    /// Withdraw the full commission accrued by a validator
+    pub async fn distribution_withdraw_commission(
+        &self,
+        req: WithdrawCommissionRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<WithdrawCommissionResponse, DistributionError> {
+        self.distribution_withdraw_commission_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn distribution_withdraw_commission_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<WithdrawCommissionResponse, DistributionError>
+    where
+        I: IntoIterator<Item = WithdrawCommissionRequest>,
+    {
+        self.distribution_withdraw_commission_batch_async(
+            reqs,
+            key,
+            tx_options,
+            BroadcastMode::Sync,
+        )
+        .await?
+        .wait_with_retries(self, key)
+        .await
+    }
+
+    /// Non-blocking `distribution_withdraw_commission_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn distribution_withdraw_commission_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<WithdrawCommissionResponse, DistributionError>, DistributionError>
+    where
+        I: IntoIterator<Item = WithdrawCommissionRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<WithdrawCommissionRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(WithdrawCommissionResponse { res })
+            })
+            .await?)
+    }
+
+    /// Set the address that a delegator's rewards are withdrawn to
+    pub async fn distribution_set_withdraw_address(
+        &self,
+        req: SetWithdrawAddressRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SetWithdrawAddressResponse, DistributionError> {
+        self.distribution_set_withdraw_address_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn distribution_set_withdraw_address_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SetWithdrawAddressResponse, DistributionError>
+    where
+        I: IntoIterator<Item = SetWithdrawAddressRequest>,
+    {
+        self.distribution_set_withdraw_address_batch_async(
+            reqs,
+            key,
+            tx_options,
+            BroadcastMode::Sync,
+        )
+        .await?
+        .wait_with_retries(self, key)
+        .await
+    }
+
+    /// Non-blocking `distribution_set_withdraw_address_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn distribution_set_withdraw_address_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<SetWithdrawAddressResponse, DistributionError>, DistributionError>
+    where
+        I: IntoIterator<Item = SetWithdrawAddressRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<SetWithdrawAddressRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(SetWithdrawAddressResponse { res })
+            })
+            .await?)
+    }
+
+    /// Send funds from `depositor` into the community pool
+    pub async fn distribution_fund_community_pool(
+        &self,
+        req: FundCommunityPoolRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<FundCommunityPoolResponse, DistributionError> {
+        self.distribution_fund_community_pool_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn distribution_fund_community_pool_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<FundCommunityPoolResponse, DistributionError>
+    where
+        I: IntoIterator<Item = FundCommunityPoolRequest>,
+    {
+        self.distribution_fund_community_pool_batch_async(
+            reqs,
+            key,
+            tx_options,
+            BroadcastMode::Sync,
+        )
+        .await?
+        .wait_with_retries(self, key)
+        .await
+    }
+
+    /// Non-blocking `distribution_fund_community_pool_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn distribution_fund_community_pool_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<FundCommunityPoolResponse, DistributionError>, DistributionError>
+    where
+        I: IntoIterator<Item = FundCommunityPoolRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<FundCommunityPoolRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(FundCommunityPoolResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query the rewards `delegator_address` has accrued from delegating to `validator_address`
+    pub async fn distribution_query_delegation_rewards(
+        &self,
+        delegator_address: Address,
+        validator_address: Address,
+    ) -> Result<DelegationRewardsResponse, DistributionError> {
+        let req = QueryDelegationRewardsRequest {
+            delegator_address: delegator_address.into(),
+            validator_address: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+            )
+            .await?;
+
+        let rewards = res
+            .rewards
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DelegationRewardsResponse { rewards })
+    }
+
+    /// Query the rewards `delegator_address` has accrued across all of its validators
+    pub async fn distribution_query_delegation_total_rewards(
+        &self,
+        delegator_address: Address,
+    ) -> Result<DelegationTotalRewardsResponse, DistributionError> {
+        let req = QueryDelegationTotalRewardsRequest {
+            delegator_address: delegator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationTotalRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards",
+            )
+            .await?;
+
+        let rewards = res
+            .rewards
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let total = res
+            .total
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DelegationTotalRewardsResponse { rewards, total })
+    }
+
+    /// Query the rewards a validator has not yet distributed to its delegators
+    pub async fn distribution_query_validator_outstanding_rewards(
+        &self,
+        validator_address: Address,
+    ) -> Result<ValidatorOutstandingRewardsResponse, DistributionError> {
+        let req = QueryValidatorOutstandingRewardsRequest {
+            validator_address: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorOutstandingRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards",
+            )
+            .await?;
+
+        let rewards = res
+            .rewards
+            .map(|r| r.rewards)
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ValidatorOutstandingRewardsResponse { rewards })
+    }
+
+    /// Query the commission a validator has accrued
+    pub async fn distribution_query_validator_commission(
+        &self,
+        validator_address: Address,
+    ) -> Result<ValidatorCommissionResponse, DistributionError> {
+        let req = QueryValidatorCommissionRequest {
+            validator_address: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorCommissionResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/ValidatorCommission",
+            )
+            .await?;
+
+        let commission = res
+            .commission
+            .map(|c| c.commission)
+            .unwrap_or_default()
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ValidatorCommissionResponse { commission })
+    }
+
+    /// Query the address that `delegator_address`'s rewards are withdrawn to
+    pub async fn distribution_query_withdraw_address(
+        &self,
+        delegator_address: Address,
+    ) -> Result<WithdrawAddressResponse, DistributionError> {
+        let req = QueryDelegatorWithdrawAddressRequest {
+            delegator_address: delegator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorWithdrawAddressResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress",
+            )
+            .await?;
+
+        Ok(WithdrawAddressResponse {
+            withdraw_address: res.withdraw_address.parse()?,
+        })
+    }
+
+    /// Query the funds held by the community pool
+    pub async fn distribution_query_community_pool(
+        &self,
+    ) -> Result<CommunityPoolResponse, DistributionError> {
+        let req = QueryCommunityPoolRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryCommunityPoolResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/CommunityPool",
+            )
+            .await?;
+
+        let pool = res
+            .pool
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(CommunityPoolResponse { pool })
+    }
+
+    /// Query distribution module's params
+    pub async fn distribution_query_params(&self) -> Result<ParamsResponse, DistributionError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.distribution.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::base::v1beta1::DecCoin as ProtoDecCoin;
+    use cosmrs::proto::cosmos::distribution::v1beta1::{
+        QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    };
+
+    use crate::{
+        chain::coin::DecCoin,
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::auth::model::Address,
+    };
+
+    #[tokio::test]
+    async fn test_distribution_query_delegation_rewards() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let delegator: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+        let validator = Address::new("junovaloper", &[7; 20]).unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let expected_validator = validator.to_string();
+        mock_client
+            .expect_query::<QueryDelegationRewardsRequest, QueryDelegationRewardsResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmos.distribution.v1beta1.Query/DelegationRewards");
+                assert_eq!(
+                    req.delegator_address,
+                    "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                );
+                assert_eq!(req.validator_address, expected_validator);
+
+                // `DecCoin` amounts are proto encoded as integers with 18 decimal places
+                Ok(QueryDelegationRewardsResponse {
+                    rewards: vec![
+                        ProtoDecCoin {
+                            denom: "utest".to_string(),
+                            amount: "1500000000000000000".to_string(),
+                        },
+                        ProtoDecCoin {
+                            denom: "uother".to_string(),
+                            amount: "123".to_string(),
+                        },
+                    ],
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .distribution_query_delegation_rewards(delegator, validator)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.rewards,
+            vec![
+                DecCoin {
+                    denom: "utest".parse().unwrap(),
+                    amount: "1.5".parse().unwrap(),
+                },
+                DecCoin {
+                    denom: "uother".parse().unwrap(),
+                    amount: "0.000000000000000123".parse().unwrap(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,321 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    DelegationDelegatorReward, MsgFundCommunityPool, MsgSetWithdrawAddress,
+    MsgWithdrawDelegatorReward, MsgWithdrawValidatorCommission, Params as ProtoParams,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::DecCoin;
+use crate::chain::decimal::Decimal;
+use crate::chain::msg::Msg;
+use crate::{
+    chain::{coin::Coin, error::ChainError, response::ChainTxResponse},
+    modules::auth::model::Address,
+};
+
+use super::error::DistributionError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DelegationRewardsResponse {
+    pub rewards: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegationTotalRewardsResponse {
+    /// rewards accrued by the delegator, per validator
+    pub rewards: Vec<DelegatorReward>,
+
+    /// sum of rewards accrued across all validators
+    pub total: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegatorReward {
+    pub validator_address: Address,
+    pub reward: Vec<DecCoin>,
+}
+
+impl TryFrom<DelegationDelegatorReward> for DelegatorReward {
+    type Error = DistributionError;
+
+    fn try_from(r: DelegationDelegatorReward) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_address: r.validator_address.parse()?,
+            reward: r
+                .reward
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorOutstandingRewardsResponse {
+    pub rewards: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorCommissionResponse {
+    pub commission: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CommunityPoolResponse {
+    pub pool: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WithdrawAddressResponse {
+    pub withdraw_address: Address,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    pub community_tax: Decimal,
+    pub base_proposer_reward: Decimal,
+    pub bonus_proposer_reward: Decimal,
+    pub withdraw_addr_enabled: bool,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            community_tax: Decimal::from_proto(&p.community_tax)?,
+            base_proposer_reward: Decimal::from_proto(&p.base_proposer_reward)?,
+            bonus_proposer_reward: Decimal::from_proto(&p.bonus_proposer_reward)?,
+            withdraw_addr_enabled: p.withdraw_addr_enabled,
+        })
+    }
+}
+
+/// Withdraw the rewards `delegator_address` has accrued from delegating to `validator_address`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WithdrawRewardsRequest {
+    pub delegator_address: Address,
+    pub validator_address: Address,
+}
+
+pub type WithdrawRewardsRequestProto = WithdrawRewardsRequest;
+
+impl Msg for WithdrawRewardsRequestProto {
+    type Proto = MsgWithdrawDelegatorReward;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgWithdrawDelegatorReward> for WithdrawRewardsRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgWithdrawDelegatorReward) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: msg.delegator_address.parse()?,
+            validator_address: msg.validator_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<WithdrawRewardsRequest> for MsgWithdrawDelegatorReward {
+    type Error = DistributionError;
+
+    fn try_from(req: WithdrawRewardsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator_address.into(),
+            validator_address: req.validator_address.into(),
+        })
+    }
+}
+
+/// Withdraw the full commission accrued by `validator_address`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WithdrawCommissionRequest {
+    pub validator_address: Address,
+}
+
+pub type WithdrawCommissionRequestProto = WithdrawCommissionRequest;
+
+impl Msg for WithdrawCommissionRequestProto {
+    type Proto = MsgWithdrawValidatorCommission;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgWithdrawValidatorCommission> for WithdrawCommissionRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgWithdrawValidatorCommission) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_address: msg.validator_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<WithdrawCommissionRequest> for MsgWithdrawValidatorCommission {
+    type Error = DistributionError;
+
+    fn try_from(req: WithdrawCommissionRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_address: req.validator_address.into(),
+        })
+    }
+}
+
+/// Set the address that `delegator_address`'s rewards will be withdrawn to
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SetWithdrawAddressRequest {
+    pub delegator_address: Address,
+    pub withdraw_address: Address,
+}
+
+pub type SetWithdrawAddressRequestProto = SetWithdrawAddressRequest;
+
+impl Msg for SetWithdrawAddressRequestProto {
+    type Proto = MsgSetWithdrawAddress;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgSetWithdrawAddress> for SetWithdrawAddressRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgSetWithdrawAddress) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: msg.delegator_address.parse()?,
+            withdraw_address: msg.withdraw_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<SetWithdrawAddressRequest> for MsgSetWithdrawAddress {
+    type Error = DistributionError;
+
+    fn try_from(req: SetWithdrawAddressRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator_address.into(),
+            withdraw_address: req.withdraw_address.into(),
+        })
+    }
+}
+
+/// Send `amounts` of funds from `depositor` to the community pool
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct FundCommunityPoolRequest {
+    pub depositor: Address,
+    pub amounts: Vec<Coin>,
+}
+
+pub type FundCommunityPoolRequestProto = FundCommunityPoolRequest;
+
+impl Msg for FundCommunityPoolRequestProto {
+    type Proto = MsgFundCommunityPool;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgFundCommunityPool> for FundCommunityPoolRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgFundCommunityPool) -> Result<Self, Self::Error> {
+        Ok(Self {
+            depositor: msg.depositor.parse()?,
+            amounts: msg
+                .amount
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<FundCommunityPoolRequest> for MsgFundCommunityPool {
+    type Error = DistributionError;
+
+    fn try_from(req: FundCommunityPoolRequest) -> Result<Self, Self::Error> {
+        if req.amounts.is_empty() {
+            return Err(DistributionError::EmptyAmount);
+        }
+
+        for amount in &req.amounts {
+            if amount.amount == 0 {
+                return Err(DistributionError::EmptyAmount);
+            }
+        }
+
+        Ok(Self {
+            depositor: req.depositor.into(),
+            amount: req.amounts.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct WithdrawRewardsResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct WithdrawCommissionResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SetWithdrawAddressResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct FundCommunityPoolResponse {
+    pub res: ChainTxResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chain::{coin::Coin, msg::Msg};
+    use crate::modules::auth::model::Address;
+
+    use super::{FundCommunityPoolRequest, WithdrawRewardsRequest};
+
+    #[test]
+    fn test_msg_round_trip() {
+        let req = WithdrawRewardsRequest {
+            delegator_address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            validator_address: Address::new("junovaloper", &[7; 20]).unwrap(),
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(
+            any.type_url,
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"
+        );
+        assert_eq!(WithdrawRewardsRequest::from_any(&any).unwrap(), req);
+
+        let req = FundCommunityPoolRequest {
+            depositor: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(
+            any.type_url,
+            "/cosmos.distribution.v1beta1.MsgFundCommunityPool"
+        );
+        assert_eq!(FundCommunityPoolRequest::from_any(&any).unwrap(), req);
+
+        // empty amounts are rejected before encoding
+        let req = FundCommunityPoolRequest {
+            amounts: vec![],
+            ..req
+        };
+        assert!(req.to_any().is_err());
+    }
+}
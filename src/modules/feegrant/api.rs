@@ -0,0 +1,295 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::FeegrantError,
+    model::{
+        AllowanceResponse, AllowancesResponse, GrantAllowanceRequest, GrantAllowanceRequestProto,
+        GrantAllowanceResponse, RevokeAllowanceRequest, RevokeAllowanceRequestProto,
+        RevokeAllowanceResponse,
+    },
+    proto::{QueryAllowancesByGranterRequest, QueryAllowancesByGranterResponse},
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Grant `grantee` an allowance to pay tx fees out of `granter`'s account.
+    /// The grantee can then use it by setting `TxOptions::fee_granter`.
+    pub async fn feegrant_grant_allowance(
+        &self,
+        req: GrantAllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GrantAllowanceResponse, FeegrantError> {
+        self.feegrant_grant_allowance_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn feegrant_grant_allowance_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GrantAllowanceResponse, FeegrantError>
+    where
+        I: IntoIterator<Item = GrantAllowanceRequest>,
+    {
+        self.feegrant_grant_allowance_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `feegrant_grant_allowance_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn feegrant_grant_allowance_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<GrantAllowanceResponse, FeegrantError>, FeegrantError>
+    where
+        I: IntoIterator<Item = GrantAllowanceRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<GrantAllowanceRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(GrantAllowanceResponse { res })
+            })
+            .await?)
+    }
+
+    /// Revoke a previously granted fee allowance
+    pub async fn feegrant_revoke_allowance(
+        &self,
+        req: RevokeAllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RevokeAllowanceResponse, FeegrantError> {
+        self.feegrant_revoke_allowance_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn feegrant_revoke_allowance_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RevokeAllowanceResponse, FeegrantError>
+    where
+        I: IntoIterator<Item = RevokeAllowanceRequest>,
+    {
+        self.feegrant_revoke_allowance_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `feegrant_revoke_allowance_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn feegrant_revoke_allowance_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<RevokeAllowanceResponse, FeegrantError>, FeegrantError>
+    where
+        I: IntoIterator<Item = RevokeAllowanceRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<RevokeAllowanceRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(RevokeAllowanceResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query the fee allowance `granter` gave to `grantee`
+    pub async fn feegrant_query_allowance(
+        &self,
+        granter: Address,
+        grantee: Address,
+    ) -> Result<AllowanceResponse, FeegrantError> {
+        let req = QueryAllowanceRequest {
+            granter: granter.into(),
+            grantee: grantee.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowanceResponse>(req, "/cosmos.feegrant.v1beta1.Query/Allowance")
+            .await?;
+
+        Ok(AllowanceResponse {
+            allowance: res
+                .allowance
+                .ok_or(FeegrantError::MissingAllowance)?
+                .try_into()?,
+        })
+    }
+
+    /// Query all fee allowances given to `grantee`
+    pub async fn feegrant_query_allowances(
+        &self,
+        grantee: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllowancesResponse, FeegrantError> {
+        let req = QueryAllowancesRequest {
+            grantee: grantee.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowancesResponse>(req, "/cosmos.feegrant.v1beta1.Query/Allowances")
+            .await?;
+
+        let allowances = res
+            .allowances
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AllowancesResponse {
+            allowances,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all fee allowances given by `granter` (cosmos-sdk 0.46+)
+    pub async fn feegrant_query_allowances_by_granter(
+        &self,
+        granter: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllowancesResponse, FeegrantError> {
+        let req = QueryAllowancesByGranterRequest {
+            granter: granter.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowancesByGranterResponse>(
+                req,
+                "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter",
+            )
+            .await?;
+
+        let allowances = res
+            .allowances
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(AllowancesResponse {
+            allowances,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::feegrant::v1beta1::{
+        Grant, QueryAllowanceRequest, QueryAllowanceResponse,
+    };
+
+    use crate::{
+        chain::{coin::Coin, msg::AnyMsg, Any},
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::feegrant::model::{Allowance, BasicAllowance, PeriodicAllowance},
+    };
+
+    #[tokio::test]
+    async fn test_feegrant_query_allowance_nested() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let allowance = Allowance::AllowedMsg {
+            allowance: Box::new(Allowance::Periodic(PeriodicAllowance {
+                basic: BasicAllowance {
+                    spend_limit: vec![Coin {
+                        denom: "utest".parse().unwrap(),
+                        amount: 1_000_000,
+                    }],
+                    expiration: None,
+                },
+                period: Duration::from_secs(86400),
+                period_spend_limit: vec![Coin {
+                    denom: "utest".parse().unwrap(),
+                    amount: 10_000,
+                }],
+                period_can_spend: vec![],
+                period_reset: None,
+            })),
+            allowed_messages: vec!["/cosmos.gov.v1beta1.MsgVote".to_string()],
+        };
+
+        let any: Any = AnyMsg::from(allowance.clone()).into();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAllowanceRequest, QueryAllowanceResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmos.feegrant.v1beta1.Query/Allowance");
+                assert_eq!(req.grantee, "juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730");
+
+                Ok(QueryAllowanceResponse {
+                    allowance: Some(Grant {
+                        granter: req.granter,
+                        grantee: req.grantee,
+                        allowance: Some(any.clone()),
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .feegrant_query_allowance(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.allowance.allowance, allowance);
+    }
+}
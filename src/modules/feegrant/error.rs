@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum FeegrantError {
+    #[error("fee allowance missing from chain response")]
+    MissingAllowance,
+
+    #[error("PeriodicAllowance missing period")]
+    MissingPeriod,
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,302 @@
+use std::time::{Duration, SystemTime};
+
+use cosmrs::proto::cosmos::{
+    base::v1beta1::Coin as ProtoCoin,
+    feegrant::v1beta1::{
+        AllowedMsgAllowance, BasicAllowance as ProtoBasicAllowance, Grant as ProtoGrant,
+        MsgGrantAllowance, MsgRevokeAllowance, PeriodicAllowance as ProtoPeriodicAllowance,
+    },
+};
+use cosmrs::proto::traits::TypeUrl;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::msg::{AnyMsg, Msg};
+use crate::{
+    chain::{
+        coin::Coin, error::ChainError, request::PaginationResponse, response::ChainTxResponse,
+    },
+    modules::auth::model::Address,
+};
+
+use super::error::FeegrantError;
+
+/// Fee allowance a granter gives to a grantee, to pay for the grantee's tx fees
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Allowance {
+    Basic(BasicAllowance),
+    Periodic(PeriodicAllowance),
+
+    /// Restricts `allowance` to only pay fees for txs containing `allowed_messages` (ie: "/cosmos.gov.v1beta1.MsgVote")
+    AllowedMsg {
+        allowance: Box<Allowance>,
+        allowed_messages: Vec<String>,
+    },
+
+    /// Allowance type without a typed representation here
+    Other(AnyMsg),
+}
+
+impl TryFrom<AnyMsg> for Allowance {
+    type Error = FeegrantError;
+
+    fn try_from(msg: AnyMsg) -> Result<Self, Self::Error> {
+        let allowance = match msg.type_url.as_str() {
+            ProtoBasicAllowance::TYPE_URL => {
+                Allowance::Basic(msg.to_proto::<ProtoBasicAllowance>()?.try_into()?)
+            }
+            ProtoPeriodicAllowance::TYPE_URL => {
+                Allowance::Periodic(msg.to_proto::<ProtoPeriodicAllowance>()?.try_into()?)
+            }
+            AllowedMsgAllowance::TYPE_URL => {
+                let a: AllowedMsgAllowance = msg.to_proto()?;
+                Allowance::AllowedMsg {
+                    allowance: Box::new(
+                        AnyMsg::from(a.allowance.ok_or(FeegrantError::MissingAllowance)?)
+                            .try_into()?,
+                    ),
+                    allowed_messages: a.allowed_messages,
+                }
+            }
+            _ => Allowance::Other(msg),
+        };
+
+        Ok(allowance)
+    }
+}
+
+impl From<Allowance> for AnyMsg {
+    fn from(allowance: Allowance) -> Self {
+        match allowance {
+            Allowance::Basic(a) => {
+                AnyMsg::from_proto(ProtoBasicAllowance::TYPE_URL, ProtoBasicAllowance::from(a))
+            }
+            Allowance::Periodic(a) => AnyMsg::from_proto(
+                ProtoPeriodicAllowance::TYPE_URL,
+                ProtoPeriodicAllowance::from(a),
+            ),
+            Allowance::AllowedMsg {
+                allowance,
+                allowed_messages,
+            } => AnyMsg::from_proto(
+                AllowedMsgAllowance::TYPE_URL,
+                AllowedMsgAllowance {
+                    allowance: Some(AnyMsg::from(*allowance).into()),
+                    allowed_messages,
+                },
+            ),
+            Allowance::Other(msg) => msg,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct BasicAllowance {
+    /// max amount of fees the grantee can spend, or empty for no limit
+    pub spend_limit: Vec<Coin>,
+
+    /// time the allowance expires at, or `None` if it never expires
+    pub expiration: Option<SystemTime>,
+}
+
+fn coins(coins: Vec<ProtoCoin>) -> Result<Vec<Coin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+fn time(ts: Option<prost_types::Timestamp>) -> Result<Option<SystemTime>, ChainError> {
+    ts.map(SystemTime::try_from)
+        .transpose()
+        .map_err(ChainError::proto_time)
+}
+
+impl TryFrom<ProtoBasicAllowance> for BasicAllowance {
+    type Error = ChainError;
+
+    fn try_from(a: ProtoBasicAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            spend_limit: coins(a.spend_limit)?,
+            expiration: time(a.expiration)?,
+        })
+    }
+}
+
+impl From<BasicAllowance> for ProtoBasicAllowance {
+    fn from(a: BasicAllowance) -> Self {
+        Self {
+            spend_limit: a.spend_limit.into_iter().map(Into::into).collect(),
+            expiration: a.expiration.map(Into::into),
+        }
+    }
+}
+
+/// Allowance that resets the grantee's spendable amount every `period`
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PeriodicAllowance {
+    /// overall limit and expiration, applied on top of the periodic limit
+    pub basic: BasicAllowance,
+
+    pub period: Duration,
+
+    /// max amount of fees the grantee can spend each period
+    pub period_spend_limit: Vec<Coin>,
+
+    /// amount left to spend in the current period. Set by the chain, ignored when granting
+    pub period_can_spend: Vec<Coin>,
+
+    /// time the current period ends at. Set by the chain, ignored when granting
+    pub period_reset: Option<SystemTime>,
+}
+
+impl TryFrom<ProtoPeriodicAllowance> for PeriodicAllowance {
+    type Error = FeegrantError;
+
+    fn try_from(a: ProtoPeriodicAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            basic: a
+                .basic
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            period: a
+                .period
+                .ok_or(FeegrantError::MissingPeriod)?
+                .try_into()
+                .map_err(ChainError::proto_time)?,
+            period_spend_limit: coins(a.period_spend_limit)?,
+            period_can_spend: coins(a.period_can_spend)?,
+            period_reset: time(a.period_reset)?,
+        })
+    }
+}
+
+impl From<PeriodicAllowance> for ProtoPeriodicAllowance {
+    fn from(a: PeriodicAllowance) -> Self {
+        Self {
+            basic: Some(a.basic.into()),
+            // std Durations only fail to convert when they overflow an i64 of seconds
+            period: prost_types::Duration::try_from(a.period).ok(),
+            period_spend_limit: a.period_spend_limit.into_iter().map(Into::into).collect(),
+            period_can_spend: a.period_can_spend.into_iter().map(Into::into).collect(),
+            period_reset: a.period_reset.map(Into::into),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct FeeGrant {
+    pub granter: Address,
+    pub grantee: Address,
+    pub allowance: Allowance,
+}
+
+impl TryFrom<ProtoGrant> for FeeGrant {
+    type Error = FeegrantError;
+
+    fn try_from(g: ProtoGrant) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: g.granter.parse()?,
+            grantee: g.grantee.parse()?,
+            allowance: AnyMsg::from(g.allowance.ok_or(FeegrantError::MissingAllowance)?)
+                .try_into()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AllowanceResponse {
+    pub allowance: FeeGrant,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AllowancesResponse {
+    pub allowances: Vec<FeeGrant>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Grant `grantee` an `allowance` to pay tx fees out of `granter`'s account
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAllowanceRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub allowance: Allowance,
+}
+
+pub type GrantAllowanceRequestProto = GrantAllowanceRequest;
+
+impl Msg for GrantAllowanceRequestProto {
+    type Proto = MsgGrantAllowance;
+    type Err = FeegrantError;
+}
+
+impl TryFrom<MsgGrantAllowance> for GrantAllowanceRequest {
+    type Error = FeegrantError;
+
+    fn try_from(msg: MsgGrantAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            allowance: AnyMsg::from(msg.allowance.ok_or(FeegrantError::MissingAllowance)?)
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<GrantAllowanceRequest> for MsgGrantAllowance {
+    type Error = FeegrantError;
+
+    fn try_from(req: GrantAllowanceRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            allowance: Some(AnyMsg::from(req.allowance).into()),
+        })
+    }
+}
+
+/// Revoke the fee allowance `granter` gave to `grantee`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RevokeAllowanceRequest {
+    pub granter: Address,
+    pub grantee: Address,
+}
+
+pub type RevokeAllowanceRequestProto = RevokeAllowanceRequest;
+
+impl Msg for RevokeAllowanceRequestProto {
+    type Proto = MsgRevokeAllowance;
+    type Err = FeegrantError;
+}
+
+impl TryFrom<MsgRevokeAllowance> for RevokeAllowanceRequest {
+    type Error = FeegrantError;
+
+    fn try_from(msg: MsgRevokeAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+        })
+    }
+}
+
+impl TryFrom<RevokeAllowanceRequest> for MsgRevokeAllowance {
+    type Error = FeegrantError;
+
+    fn try_from(req: RevokeAllowanceRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct GrantAllowanceResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RevokeAllowanceResponse {
+    pub res: ChainTxResponse,
+}
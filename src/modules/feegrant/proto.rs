@@ -0,0 +1,24 @@
+//! Feegrant protos missing from `cosmos-sdk-proto`.
+//!
+//! The `AllowancesByGranter` query was added in cosmos-sdk 0.46, so these mirror the upstream `.proto` definitions field for field.
+
+use cosmrs::proto::cosmos::{
+    base::query::v1beta1::{PageRequest, PageResponse},
+    feegrant::v1beta1::Grant,
+};
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryAllowancesByGranterRequest {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryAllowancesByGranterResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub allowances: Vec<Grant>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
@@ -0,0 +1,852 @@
+use cosmrs::proto::cosmos::gov::v1beta1::{
+    QueryDepositRequest, QueryDepositResponse, QueryDepositsRequest, QueryDepositsResponse,
+    QueryParamsRequest, QueryParamsResponse, QueryProposalRequest, QueryProposalResponse,
+    QueryProposalsRequest, QueryProposalsResponse, QueryTallyResultRequest,
+    QueryTallyResultResponse, QueryVoteRequest, QueryVoteResponse, QueryVotesRequest,
+    QueryVotesResponse,
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::{
+        request::{PaginationRequest, TxOptions},
+        response::ChainTxResponse,
+    },
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::GovError,
+    model::{
+        DepositRequest, DepositRequestProto, DepositResponse, GovVersion, Params, ParamsResponse,
+        ProposalDepositResponse, ProposalDepositsResponse, ProposalResponse, ProposalStatus,
+        ProposalVoteResponse, ProposalVotesResponse, ProposalsResponse,
+        SubmitLegacyProposalRequest, SubmitLegacyProposalRequestProto, SubmitProposalBatchResponse,
+        SubmitProposalRequest, SubmitProposalRequestProto, SubmitProposalResponse,
+        TallyResultResponse, VoteRequest, VoteRequestProto, VoteResponse, VoteWeightedRequest,
+        VoteWeightedRequestProto, VoteWeightedResponse,
+    },
+    proto::v1,
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Submit a gov v1 proposal containing arbitrary msgs (cosmos-sdk 0.46+)
+    pub async fn gov_submit_proposal(
+        &self,
+        req: SubmitProposalRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalResponse, GovError> {
+        let mut res = self
+            .gov_submit_proposal_batch(vec![req], key, tx_options)
+            .await?;
+
+        Ok(SubmitProposalResponse {
+            proposal_id: res.proposal_ids.remove(0),
+            res: res.res,
+        })
+    }
+
+    pub async fn gov_submit_proposal_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalBatchResponse, GovError>
+    where
+        I: IntoIterator<Item = SubmitProposalRequest>,
+    {
+        self.gov_submit_proposal_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `gov_submit_proposal_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn gov_submit_proposal_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<SubmitProposalBatchResponse, GovError>, GovError>
+    where
+        I: IntoIterator<Item = SubmitProposalRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<SubmitProposalRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                let proposal_ids = parse_proposal_ids(&res)?;
+
+                Ok(SubmitProposalBatchResponse { proposal_ids, res })
+            })
+            .await?)
+    }
+
+    /// Submit a gov v1beta1 proposal with legacy content
+    pub async fn gov_submit_legacy_proposal(
+        &self,
+        req: SubmitLegacyProposalRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalResponse, GovError> {
+        let mut res = self
+            .gov_submit_legacy_proposal_batch(vec![req], key, tx_options)
+            .await?;
+
+        Ok(SubmitProposalResponse {
+            proposal_id: res.proposal_ids.remove(0),
+            res: res.res,
+        })
+    }
+
+    pub async fn gov_submit_legacy_proposal_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalBatchResponse, GovError>
+    where
+        I: IntoIterator<Item = SubmitLegacyProposalRequest>,
+    {
+        self.gov_submit_legacy_proposal_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `gov_submit_legacy_proposal_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn gov_submit_legacy_proposal_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<SubmitProposalBatchResponse, GovError>, GovError>
+    where
+        I: IntoIterator<Item = SubmitLegacyProposalRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<SubmitLegacyProposalRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                let proposal_ids = parse_proposal_ids(&res)?;
+
+                Ok(SubmitProposalBatchResponse { proposal_ids, res })
+            })
+            .await?)
+    }
+
+    /// Vote on a proposal that is in its voting period
+    pub async fn gov_vote(
+        &self,
+        req: VoteRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VoteResponse, GovError> {
+        self.gov_vote_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn gov_vote_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VoteResponse, GovError>
+    where
+        I: IntoIterator<Item = VoteRequest>,
+    {
+        self.gov_vote_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `gov_vote_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn gov_vote_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<VoteResponse, GovError>, GovError>
+    where
+        I: IntoIterator<Item = VoteRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<VoteRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(VoteResponse { res })
+            })
+            .await?)
+    }
+
+    /// Split a vote on a proposal across multiple options
+    pub async fn gov_vote_weighted(
+        &self,
+        req: VoteWeightedRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VoteWeightedResponse, GovError> {
+        self.gov_vote_weighted_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn gov_vote_weighted_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VoteWeightedResponse, GovError>
+    where
+        I: IntoIterator<Item = VoteWeightedRequest>,
+    {
+        self.gov_vote_weighted_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `gov_vote_weighted_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn gov_vote_weighted_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<VoteWeightedResponse, GovError>, GovError>
+    where
+        I: IntoIterator<Item = VoteWeightedRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<VoteWeightedRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(VoteWeightedResponse { res })
+            })
+            .await?)
+    }
+
+    /// Deposit funds towards a proposal's minimum deposit
+    pub async fn gov_deposit(
+        &self,
+        req: DepositRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DepositResponse, GovError> {
+        self.gov_deposit_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn gov_deposit_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DepositResponse, GovError>
+    where
+        I: IntoIterator<Item = DepositRequest>,
+    {
+        self.gov_deposit_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `gov_deposit_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn gov_deposit_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<DepositResponse, GovError>, GovError>
+    where
+        I: IntoIterator<Item = DepositRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<DepositRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(DepositResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query all proposals, optionally filtered by `status`, `voter` and/or `depositor`
+    pub async fn gov_query_proposals(
+        &self,
+        version: GovVersion,
+        status: Option<ProposalStatus>,
+        voter: Option<Address>,
+        depositor: Option<Address>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ProposalsResponse, GovError> {
+        let proposal_status = status.map(|s| s as i32).unwrap_or_default();
+        let voter = voter.map(Into::into).unwrap_or_default();
+        let depositor = depositor.map(Into::into).unwrap_or_default();
+        let pagination = pagination.map(Into::into);
+
+        let (proposals, next) = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryProposalsRequest {
+                    proposal_status,
+                    voter,
+                    depositor,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, QueryProposalsResponse>(req, "/cosmos.gov.v1beta1.Query/Proposals")
+                    .await?;
+
+                (
+                    res.proposals
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryProposalsRequest {
+                    proposal_status,
+                    voter,
+                    depositor,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryProposalsResponse>(req, "/cosmos.gov.v1.Query/Proposals")
+                    .await?;
+
+                (
+                    res.proposals
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(ProposalsResponse {
+            proposals,
+            next: next.map(Into::into),
+        })
+    }
+
+    /// Query a single proposal by its id
+    pub async fn gov_query_proposal(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+    ) -> Result<ProposalResponse, GovError> {
+        let proposal = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryProposalRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, QueryProposalResponse>(req, "/cosmos.gov.v1beta1.Query/Proposal")
+                    .await?;
+
+                res.proposal.ok_or(GovError::MissingProposal)?.try_into()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryProposalRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryProposalResponse>(req, "/cosmos.gov.v1.Query/Proposal")
+                    .await?;
+
+                res.proposal.ok_or(GovError::MissingProposal)?.try_into()?
+            }
+        };
+
+        Ok(ProposalResponse { proposal })
+    }
+
+    /// Query `voter`'s vote on a proposal
+    pub async fn gov_query_vote(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Result<ProposalVoteResponse, GovError> {
+        let vote = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryVoteRequest {
+                    proposal_id,
+                    voter: voter.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, QueryVoteResponse>(req, "/cosmos.gov.v1beta1.Query/Vote")
+                    .await?;
+
+                res.vote.ok_or(GovError::MissingVote)?.try_into()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryVoteRequest {
+                    proposal_id,
+                    voter: voter.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryVoteResponse>(req, "/cosmos.gov.v1.Query/Vote")
+                    .await?;
+
+                res.vote.ok_or(GovError::MissingVote)?.try_into()?
+            }
+        };
+
+        Ok(ProposalVoteResponse { vote })
+    }
+
+    /// Query all votes on a proposal
+    pub async fn gov_query_votes(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ProposalVotesResponse, GovError> {
+        let pagination = pagination.map(Into::into);
+
+        let (votes, next) = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryVotesRequest {
+                    proposal_id,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, QueryVotesResponse>(req, "/cosmos.gov.v1beta1.Query/Votes")
+                    .await?;
+
+                (
+                    res.votes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryVotesRequest {
+                    proposal_id,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryVotesResponse>(req, "/cosmos.gov.v1.Query/Votes")
+                    .await?;
+
+                (
+                    res.votes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(ProposalVotesResponse {
+            votes,
+            next: next.map(Into::into),
+        })
+    }
+
+    /// Query `depositor`'s deposit on a proposal
+    pub async fn gov_query_deposit(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+        depositor: Address,
+    ) -> Result<ProposalDepositResponse, GovError> {
+        let deposit = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryDepositRequest {
+                    proposal_id,
+                    depositor: depositor.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, QueryDepositResponse>(req, "/cosmos.gov.v1beta1.Query/Deposit")
+                    .await?;
+
+                res.deposit.ok_or(GovError::MissingDeposit)?.try_into()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryDepositRequest {
+                    proposal_id,
+                    depositor: depositor.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryDepositResponse>(req, "/cosmos.gov.v1.Query/Deposit")
+                    .await?;
+
+                res.deposit.ok_or(GovError::MissingDeposit)?.try_into()?
+            }
+        };
+
+        Ok(ProposalDepositResponse { deposit })
+    }
+
+    /// Query all deposits on a proposal
+    pub async fn gov_query_deposits(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ProposalDepositsResponse, GovError> {
+        let pagination = pagination.map(Into::into);
+
+        let (deposits, next) = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryDepositsRequest {
+                    proposal_id,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, QueryDepositsResponse>(req, "/cosmos.gov.v1beta1.Query/Deposits")
+                    .await?;
+
+                (
+                    res.deposits
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryDepositsRequest {
+                    proposal_id,
+                    pagination,
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryDepositsResponse>(req, "/cosmos.gov.v1.Query/Deposits")
+                    .await?;
+
+                (
+                    res.deposits
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(ProposalDepositsResponse {
+            deposits,
+            next: next.map(Into::into),
+        })
+    }
+
+    /// Query the current tally of votes on a proposal
+    pub async fn gov_query_tally(
+        &self,
+        version: GovVersion,
+        proposal_id: u64,
+    ) -> Result<TallyResultResponse, GovError> {
+        let tally = match version {
+            GovVersion::V1Beta1 => {
+                let req = QueryTallyResultRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, QueryTallyResultResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/TallyResult",
+                    )
+                    .await?;
+
+                res.tally.ok_or(GovError::MissingTally)?.try_into()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryTallyResultRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryTallyResultResponse>(
+                        req,
+                        "/cosmos.gov.v1.Query/TallyResult",
+                    )
+                    .await?;
+
+                res.tally.ok_or(GovError::MissingTally)?.try_into()?
+            }
+        };
+
+        Ok(TallyResultResponse { tally })
+    }
+
+    /// Query gov module's deposit, voting and tally params
+    pub async fn gov_query_params(&self, version: GovVersion) -> Result<ParamsResponse, GovError> {
+        let params = match version {
+            // the params query only returns the params matching `params_type`, so we need one query for each
+            GovVersion::V1Beta1 => {
+                let mut res = vec![];
+                for params_type in ["deposit", "voting", "tallying"] {
+                    let req = QueryParamsRequest {
+                        params_type: params_type.to_string(),
+                    };
+
+                    res.push(
+                        self.client
+                            .query::<_, QueryParamsResponse>(
+                                req,
+                                "/cosmos.gov.v1beta1.Query/Params",
+                            )
+                            .await?,
+                    );
+                }
+
+                Params::from_v1beta1(
+                    res[0].deposit_params.take(),
+                    res[1].voting_params.take(),
+                    res[2].tally_params.take(),
+                )?
+            }
+            GovVersion::V1 => {
+                let query_v1 = |params_type: &str| {
+                    let req = v1::QueryParamsRequest {
+                        params_type: params_type.to_string(),
+                    };
+
+                    self.client
+                        .query::<_, v1::QueryParamsResponse>(req, "/cosmos.gov.v1.Query/Params")
+                };
+
+                // cosmos-sdk 0.47+ returns all params at once, no matter the `params_type`
+                let mut res = query_v1("deposit").await?;
+
+                match res.params.take() {
+                    Some(params) => Params::from_v1_params(params)?,
+                    // cosmos-sdk 0.46 only has the per type params
+                    None => Params::from_v1(
+                        res.deposit_params.take(),
+                        query_v1("voting").await?.voting_params,
+                        query_v1("tallying").await?.tally_params,
+                    )?,
+                }
+            }
+        };
+
+        Ok(ParamsResponse { params })
+    }
+}
+
+fn parse_proposal_ids(res: &ChainTxResponse) -> Result<Vec<u64>, GovError> {
+    let events = res.find_event_tags("submit_proposal".to_string(), "proposal_id".to_string());
+
+    if events.is_empty() {
+        return Err(GovError::MissingEvent);
+    }
+
+    events
+        .into_iter()
+        .map(|e| e.value.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| GovError::MissingEvent)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmrs::proto::cosmos::gov::v1beta1::TextProposal;
+    use cosmrs::proto::traits::Message;
+    use cosmrs::Any;
+
+    use crate::{
+        chain::coin::Coin,
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::gov::{
+            model::{GovVersion, ProposalContent, ProposalStatus},
+            proto::v1,
+        },
+    };
+
+    #[tokio::test]
+    async fn test_gov_query_params_v1() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<v1::QueryParamsRequest, v1::QueryParamsResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmos.gov.v1.Query/Params");
+                assert_eq!(req.params_type, "deposit");
+
+                Ok(v1::QueryParamsResponse {
+                    deposit_params: Some(v1::DepositParams::default()),
+                    params: Some(v1::Params {
+                        min_deposit: vec![ProtoCoin {
+                            denom: "utest".to_string(),
+                            amount: "1000".to_string(),
+                        }],
+                        max_deposit_period: Some(prost_types::Duration {
+                            seconds: 172800,
+                            nanos: 0,
+                        }),
+                        voting_period: Some(prost_types::Duration {
+                            seconds: 432000,
+                            nanos: 0,
+                        }),
+                        quorum: "0.334000000000000000".to_string(),
+                        threshold: "0.500000000000000000".to_string(),
+                        veto_threshold: "0.334000000000000000".to_string(),
+                    }),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let params = cosm_tome
+            .gov_query_params(GovVersion::V1)
+            .await
+            .unwrap()
+            .params;
+
+        assert_eq!(
+            params.min_deposit,
+            vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 1000
+            }]
+        );
+        assert_eq!(params.max_deposit_period, Some(Duration::from_secs(172800)));
+        assert_eq!(params.voting_period, Some(Duration::from_secs(432000)));
+        assert_eq!(params.quorum, "0.334".parse().unwrap());
+        assert_eq!(params.threshold, "0.5".parse().unwrap());
+        assert_eq!(params.veto_threshold, "0.334".parse().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_gov_query_proposal_v1_legacy_content() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: Some("localhost".to_string()),
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<v1::QueryProposalRequest, v1::QueryProposalResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmos.gov.v1.Query/Proposal");
+                assert_eq!(req.proposal_id, 7);
+
+                let content = Any {
+                    type_url: "/cosmos.gov.v1beta1.TextProposal".to_string(),
+                    value: TextProposal {
+                        title: "title".to_string(),
+                        description: "description".to_string(),
+                    }
+                    .encode_to_vec(),
+                };
+
+                Ok(v1::QueryProposalResponse {
+                    proposal: Some(v1::Proposal {
+                        id: 7,
+                        messages: vec![Any {
+                            type_url: "/cosmos.gov.v1.MsgExecLegacyContent".to_string(),
+                            value: v1::MsgExecLegacyContent {
+                                content: Some(content),
+                                authority: "juno10d07y265gmmuvt4z0w9aw880jnsr700jvss730"
+                                    .to_string(),
+                            }
+                            .encode_to_vec(),
+                        }],
+                        status: 2,
+                        final_tally_result: Some(v1::TallyResult {
+                            yes_count: "10".to_string(),
+                            abstain_count: "0".to_string(),
+                            no_count: "3".to_string(),
+                            no_with_veto_count: "0".to_string(),
+                        }),
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let proposal = cosm_tome
+            .gov_query_proposal(GovVersion::V1, 7)
+            .await
+            .unwrap()
+            .proposal;
+
+        assert_eq!(proposal.id, 7);
+        assert_eq!(proposal.status, ProposalStatus::VotingPeriod);
+        assert_eq!(proposal.messages.len(), 1);
+        assert_eq!(proposal.final_tally_result.unwrap().yes, 10);
+        assert_eq!(
+            proposal.content,
+            Some(ProposalContent::Text {
+                title: "title".to_string(),
+                description: "description".to_string(),
+            })
+        );
+    }
+}
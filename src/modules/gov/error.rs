@@ -0,0 +1,45 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum GovError {
+    #[error("Cannot deposit 0 amount of a token")]
+    EmptyAmount,
+
+    #[error("Weighted vote must contain at least 1 option")]
+    EmptyVoteOptions,
+
+    #[error("unsupported ProposalStatus: {i:?}")]
+    ProposalStatus { i: i32 },
+
+    #[error("unsupported VoteOption: {i:?}")]
+    VoteOption { i: i32 },
+
+    #[error("proposal missing from chain response")]
+    MissingProposal,
+
+    #[error("vote missing from chain response")]
+    MissingVote,
+
+    #[error("deposit missing from chain response")]
+    MissingDeposit,
+
+    #[error("tally result missing from chain response")]
+    MissingTally,
+
+    #[error("missing event from chain response")]
+    MissingEvent,
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
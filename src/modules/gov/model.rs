@@ -0,0 +1,957 @@
+use std::time::{Duration, SystemTime};
+
+use cosmrs::proto::cosmos::{
+    distribution::v1beta1::CommunityPoolSpendProposal,
+    gov::v1beta1::{
+        Deposit as ProtoDeposit, DepositParams as ProtoDepositParams, Proposal as ProtoProposal,
+        TallyParams as ProtoTallyParams, TallyResult as ProtoTallyResult, TextProposal,
+        Vote as ProtoVote, VotingParams as ProtoVotingParams,
+        WeightedVoteOption as ProtoWeightedVoteOption,
+    },
+    params::v1beta1::{ParamChange as ProtoParamChange, ParameterChangeProposal},
+    upgrade::v1beta1::{CancelSoftwareUpgradeProposal, Plan as ProtoPlan, SoftwareUpgradeProposal},
+};
+use cosmrs::proto::traits::TypeUrl;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::parse_amount;
+use crate::chain::decimal::Decimal;
+use crate::chain::msg::{AnyMsg, Msg};
+use crate::{
+    chain::{
+        coin::Coin, error::ChainError, request::PaginationResponse, response::ChainTxResponse,
+    },
+    modules::auth::model::Address,
+};
+
+use super::error::GovError;
+use super::proto::{v1, v1beta1};
+
+/// Which version of the gov module's Query service to use.
+///
+/// `V1` is only available on cosmos-sdk 0.46+ chains, but is required to read proposals containing arbitrary msgs.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum GovVersion {
+    #[default]
+    V1Beta1,
+    V1,
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum ProposalStatus {
+    Unspecified = 0,
+    /// proposal is still collecting its minimum deposit
+    DepositPeriod = 1,
+    /// proposal is open for voting
+    VotingPeriod = 2,
+    Passed = 3,
+    Rejected = 4,
+    /// proposal passed but failed when it was executed
+    Failed = 5,
+}
+
+impl TryFrom<i32> for ProposalStatus {
+    type Error = GovError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ProposalStatus::Unspecified as i32 => Ok(ProposalStatus::Unspecified),
+            x if x == ProposalStatus::DepositPeriod as i32 => Ok(ProposalStatus::DepositPeriod),
+            x if x == ProposalStatus::VotingPeriod as i32 => Ok(ProposalStatus::VotingPeriod),
+            x if x == ProposalStatus::Passed as i32 => Ok(ProposalStatus::Passed),
+            x if x == ProposalStatus::Rejected as i32 => Ok(ProposalStatus::Rejected),
+            x if x == ProposalStatus::Failed as i32 => Ok(ProposalStatus::Failed),
+            _ => Err(GovError::ProposalStatus { i: v }),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum VoteOption {
+    Unspecified = 0,
+    Yes = 1,
+    Abstain = 2,
+    No = 3,
+    NoWithVeto = 4,
+}
+
+impl TryFrom<i32> for VoteOption {
+    type Error = GovError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == VoteOption::Unspecified as i32 => Ok(VoteOption::Unspecified),
+            x if x == VoteOption::Yes as i32 => Ok(VoteOption::Yes),
+            x if x == VoteOption::Abstain as i32 => Ok(VoteOption::Abstain),
+            x if x == VoteOption::No as i32 => Ok(VoteOption::No),
+            x if x == VoteOption::NoWithVeto as i32 => Ok(VoteOption::NoWithVeto),
+            _ => Err(GovError::VoteOption { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+
+    /// weights of all options in a vote must sum to 1
+    pub weight: Decimal,
+}
+
+impl TryFrom<ProtoWeightedVoteOption> for WeightedVoteOption {
+    type Error = GovError;
+
+    fn try_from(o: ProtoWeightedVoteOption) -> Result<Self, Self::Error> {
+        Ok(Self {
+            option: o.option.try_into()?,
+            weight: Decimal::from_proto(&o.weight)?,
+        })
+    }
+}
+
+impl TryFrom<v1::WeightedVoteOption> for WeightedVoteOption {
+    type Error = GovError;
+
+    fn try_from(o: v1::WeightedVoteOption) -> Result<Self, Self::Error> {
+        Ok(Self {
+            option: o.option.try_into()?,
+            weight: Decimal::from_proto(&o.weight)?,
+        })
+    }
+}
+
+impl From<WeightedVoteOption> for ProtoWeightedVoteOption {
+    fn from(o: WeightedVoteOption) -> Self {
+        Self {
+            option: o.option as i32,
+            weight: o.weight.to_proto(),
+        }
+    }
+}
+
+/// Typed legacy (v1beta1) proposal content
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalContent {
+    Text {
+        title: String,
+        description: String,
+    },
+    CommunityPoolSpend {
+        title: String,
+        description: String,
+        recipient: Address,
+        amount: Vec<Coin>,
+    },
+    ParameterChange {
+        title: String,
+        description: String,
+        changes: Vec<ParamChange>,
+    },
+    SoftwareUpgrade {
+        title: String,
+        description: String,
+        plan: Plan,
+    },
+    CancelSoftwareUpgrade {
+        title: String,
+        description: String,
+    },
+    /// Content type without a typed representation here (ie: wasm or ibc client proposals)
+    Other(AnyMsg),
+}
+
+impl ProposalContent {
+    const TEXT: &'static str = "/cosmos.gov.v1beta1.TextProposal";
+    const COMMUNITY_POOL_SPEND: &'static str =
+        "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal";
+    const PARAMETER_CHANGE: &'static str = "/cosmos.params.v1beta1.ParameterChangeProposal";
+    const SOFTWARE_UPGRADE: &'static str = "/cosmos.upgrade.v1beta1.SoftwareUpgradeProposal";
+    const CANCEL_SOFTWARE_UPGRADE: &'static str =
+        "/cosmos.upgrade.v1beta1.CancelSoftwareUpgradeProposal";
+}
+
+impl TryFrom<AnyMsg> for ProposalContent {
+    type Error = GovError;
+
+    fn try_from(msg: AnyMsg) -> Result<Self, Self::Error> {
+        let content = match msg.type_url.as_str() {
+            Self::TEXT => {
+                let p: TextProposal = msg.to_proto()?;
+                ProposalContent::Text {
+                    title: p.title,
+                    description: p.description,
+                }
+            }
+            Self::COMMUNITY_POOL_SPEND => {
+                let p: CommunityPoolSpendProposal = msg.to_proto()?;
+                ProposalContent::CommunityPoolSpend {
+                    title: p.title,
+                    description: p.description,
+                    recipient: p.recipient.parse()?,
+                    amount: p
+                        .amount
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            Self::PARAMETER_CHANGE => {
+                let p: ParameterChangeProposal = msg.to_proto()?;
+                ProposalContent::ParameterChange {
+                    title: p.title,
+                    description: p.description,
+                    changes: p.changes.into_iter().map(Into::into).collect(),
+                }
+            }
+            Self::SOFTWARE_UPGRADE => {
+                let p: SoftwareUpgradeProposal = msg.to_proto()?;
+                ProposalContent::SoftwareUpgrade {
+                    title: p.title,
+                    description: p.description,
+                    plan: p.plan.map(Into::into).unwrap_or_default(),
+                }
+            }
+            Self::CANCEL_SOFTWARE_UPGRADE => {
+                let p: CancelSoftwareUpgradeProposal = msg.to_proto()?;
+                ProposalContent::CancelSoftwareUpgrade {
+                    title: p.title,
+                    description: p.description,
+                }
+            }
+            _ => ProposalContent::Other(msg),
+        };
+
+        Ok(content)
+    }
+}
+
+impl From<ProposalContent> for AnyMsg {
+    fn from(content: ProposalContent) -> Self {
+        match content {
+            ProposalContent::Text { title, description } => {
+                AnyMsg::from_proto(ProposalContent::TEXT, TextProposal { title, description })
+            }
+            ProposalContent::CommunityPoolSpend {
+                title,
+                description,
+                recipient,
+                amount,
+            } => AnyMsg::from_proto(
+                ProposalContent::COMMUNITY_POOL_SPEND,
+                CommunityPoolSpendProposal {
+                    title,
+                    description,
+                    recipient: recipient.into(),
+                    amount: amount.into_iter().map(Into::into).collect(),
+                },
+            ),
+            ProposalContent::ParameterChange {
+                title,
+                description,
+                changes,
+            } => AnyMsg::from_proto(
+                ProposalContent::PARAMETER_CHANGE,
+                ParameterChangeProposal {
+                    title,
+                    description,
+                    changes: changes.into_iter().map(Into::into).collect(),
+                },
+            ),
+            ProposalContent::SoftwareUpgrade {
+                title,
+                description,
+                plan,
+            } => AnyMsg::from_proto(
+                ProposalContent::SOFTWARE_UPGRADE,
+                SoftwareUpgradeProposal {
+                    title,
+                    description,
+                    plan: Some(plan.into()),
+                },
+            ),
+            ProposalContent::CancelSoftwareUpgrade { title, description } => AnyMsg::from_proto(
+                ProposalContent::CANCEL_SOFTWARE_UPGRADE,
+                CancelSoftwareUpgradeProposal { title, description },
+            ),
+            ProposalContent::Other(msg) => msg,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ParamChange {
+    /// example: "staking"
+    pub subspace: String,
+
+    /// example: "MaxValidators"
+    pub key: String,
+
+    /// JSON encoded value, example: "100"
+    pub value: String,
+}
+
+impl From<ProtoParamChange> for ParamChange {
+    fn from(c: ProtoParamChange) -> Self {
+        Self {
+            subspace: c.subspace,
+            key: c.key,
+            value: c.value,
+        }
+    }
+}
+
+impl From<ParamChange> for ProtoParamChange {
+    fn from(c: ParamChange) -> Self {
+        Self {
+            subspace: c.subspace,
+            key: c.key,
+            value: c.value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Plan {
+    /// name of the upgrade handler the new binary will run
+    pub name: String,
+
+    /// block height the upgrade will happen at
+    pub height: u64,
+
+    /// any application specific upgrade info (ie: binary download links)
+    pub info: String,
+}
+
+impl From<ProtoPlan> for Plan {
+    fn from(p: ProtoPlan) -> Self {
+        Self {
+            name: p.name,
+            height: p.height as u64,
+            info: p.info,
+        }
+    }
+}
+
+impl From<Plan> for ProtoPlan {
+    fn from(p: Plan) -> Self {
+        #[allow(deprecated)]
+        Self {
+            name: p.name,
+            height: p.height as i64,
+            info: p.info,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+
+    /// Legacy proposal content.
+    ///
+    /// For v1 proposals this is decoded from a `MsgExecLegacyContent` msg when present
+    pub content: Option<ProposalContent>,
+
+    /// Msgs executed by the gov module if the proposal passes (v1 only)
+    pub messages: Vec<AnyMsg>,
+
+    pub status: ProposalStatus,
+
+    /// only populated once voting has ended
+    pub final_tally_result: Option<TallyResult>,
+
+    pub submit_time: Option<SystemTime>,
+
+    pub deposit_end_time: Option<SystemTime>,
+
+    pub total_deposit: Vec<Coin>,
+
+    pub voting_start_time: Option<SystemTime>,
+
+    pub voting_end_time: Option<SystemTime>,
+
+    /// arbitrary metadata attached to the proposal (v1 only)
+    pub metadata: String,
+
+    /// Since: cosmos-sdk 0.47
+    pub title: String,
+
+    /// Since: cosmos-sdk 0.47
+    pub summary: String,
+}
+
+fn time(ts: Option<prost_types::Timestamp>) -> Result<Option<SystemTime>, ChainError> {
+    ts.map(SystemTime::try_from)
+        .transpose()
+        .map_err(ChainError::proto_time)
+}
+
+fn coins(coins: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>) -> Result<Vec<Coin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+impl TryFrom<ProtoProposal> for Proposal {
+    type Error = GovError;
+
+    fn try_from(p: ProtoProposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: p.proposal_id,
+            content: p.content.map(|c| AnyMsg::from(c).try_into()).transpose()?,
+            messages: vec![],
+            status: p.status.try_into()?,
+            final_tally_result: p.final_tally_result.map(TryInto::try_into).transpose()?,
+            submit_time: time(p.submit_time)?,
+            deposit_end_time: time(p.deposit_end_time)?,
+            total_deposit: coins(p.total_deposit)?,
+            voting_start_time: time(p.voting_start_time)?,
+            voting_end_time: time(p.voting_end_time)?,
+            metadata: String::new(),
+            title: String::new(),
+            summary: String::new(),
+        })
+    }
+}
+
+impl TryFrom<v1::Proposal> for Proposal {
+    type Error = GovError;
+
+    fn try_from(p: v1::Proposal) -> Result<Self, Self::Error> {
+        let messages: Vec<AnyMsg> = p.messages.into_iter().map(Into::into).collect();
+
+        let content = messages
+            .iter()
+            .find(|m| m.type_url == v1::MsgExecLegacyContent::TYPE_URL)
+            .map(AnyMsg::to_proto::<v1::MsgExecLegacyContent>)
+            .transpose()?
+            .and_then(|m| m.content)
+            .map(|c| AnyMsg::from(c).try_into())
+            .transpose()?;
+
+        Ok(Self {
+            id: p.id,
+            content,
+            messages,
+            status: p.status.try_into()?,
+            final_tally_result: p.final_tally_result.map(TryInto::try_into).transpose()?,
+            submit_time: time(p.submit_time)?,
+            deposit_end_time: time(p.deposit_end_time)?,
+            total_deposit: coins(p.total_deposit)?,
+            voting_start_time: time(p.voting_start_time)?,
+            voting_end_time: time(p.voting_end_time)?,
+            metadata: p.metadata,
+            title: p.title,
+            summary: p.summary,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct TallyResult {
+    pub yes: u128,
+    pub abstain: u128,
+    pub no: u128,
+    pub no_with_veto: u128,
+}
+
+impl TryFrom<ProtoTallyResult> for TallyResult {
+    type Error = ChainError;
+
+    fn try_from(t: ProtoTallyResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            yes: parse_amount(&t.yes)?,
+            abstain: parse_amount(&t.abstain)?,
+            no: parse_amount(&t.no)?,
+            no_with_veto: parse_amount(&t.no_with_veto)?,
+        })
+    }
+}
+
+impl TryFrom<v1::TallyResult> for TallyResult {
+    type Error = ChainError;
+
+    fn try_from(t: v1::TallyResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            yes: parse_amount(&t.yes_count)?,
+            abstain: parse_amount(&t.abstain_count)?,
+            no: parse_amount(&t.no_count)?,
+            no_with_veto: parse_amount(&t.no_with_veto_count)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Vote {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub options: Vec<WeightedVoteOption>,
+
+    /// arbitrary metadata attached to the vote (v1 only)
+    pub metadata: String,
+}
+
+impl TryFrom<ProtoVote> for Vote {
+    type Error = GovError;
+
+    #[allow(deprecated)]
+    fn try_from(v: ProtoVote) -> Result<Self, Self::Error> {
+        let options = if v.options.is_empty() {
+            // votes cast before cosmos-sdk 0.43 only set the deprecated `option` field
+            vec![WeightedVoteOption {
+                option: v.option.try_into()?,
+                weight: Decimal::from_int(1)?,
+            }]
+        } else {
+            v.options
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(Self {
+            proposal_id: v.proposal_id,
+            voter: v.voter.parse()?,
+            options,
+            metadata: String::new(),
+        })
+    }
+}
+
+impl TryFrom<v1::Vote> for Vote {
+    type Error = GovError;
+
+    fn try_from(v: v1::Vote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: v.proposal_id,
+            voter: v.voter.parse()?,
+            options: v
+                .options
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            metadata: v.metadata,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Deposit {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: Vec<Coin>,
+}
+
+impl TryFrom<ProtoDeposit> for Deposit {
+    type Error = GovError;
+
+    fn try_from(d: ProtoDeposit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: d.proposal_id,
+            depositor: d.depositor.parse()?,
+            amount: coins(d.amount)?,
+        })
+    }
+}
+
+impl TryFrom<v1::Deposit> for Deposit {
+    type Error = GovError;
+
+    fn try_from(d: v1::Deposit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: d.proposal_id,
+            depositor: d.depositor.parse()?,
+            amount: coins(d.amount)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    /// minimum deposit for a proposal to enter the voting period
+    pub min_deposit: Vec<Coin>,
+
+    /// maximum period for holders to deposit on a proposal
+    pub max_deposit_period: Option<Duration>,
+
+    /// length of the voting period
+    pub voting_period: Option<Duration>,
+
+    /// minimum percentage of total stake needed to vote for a result to be considered valid
+    pub quorum: Decimal,
+
+    /// minimum proportion of Yes votes for proposal to pass
+    pub threshold: Decimal,
+
+    /// minimum proportion of NoWithVeto votes for the proposal to be vetoed
+    pub veto_threshold: Decimal,
+}
+
+fn duration(d: Option<prost_types::Duration>) -> Result<Option<Duration>, ChainError> {
+    d.map(Duration::try_from)
+        .transpose()
+        .map_err(ChainError::proto_time)
+}
+
+/// v1beta1 `TallyParams` store their `sdk.Dec`s as the bytes of the usual string encoding
+fn dec_bytes(b: Vec<u8>) -> Result<Decimal, ChainError> {
+    let s = String::from_utf8(b).map_err(|e| ChainError::ProtoDecoding {
+        message: e.to_string(),
+    })?;
+    Decimal::from_proto(&s)
+}
+
+impl Params {
+    pub(crate) fn from_v1beta1(
+        deposit: Option<ProtoDepositParams>,
+        voting: Option<ProtoVotingParams>,
+        tally: Option<ProtoTallyParams>,
+    ) -> Result<Self, ChainError> {
+        let deposit = deposit.unwrap_or_default();
+        let tally = tally.unwrap_or_default();
+
+        Ok(Self {
+            min_deposit: coins(deposit.min_deposit)?,
+            max_deposit_period: duration(deposit.max_deposit_period)?,
+            voting_period: duration(voting.unwrap_or_default().voting_period)?,
+            quorum: dec_bytes(tally.quorum)?,
+            threshold: dec_bytes(tally.threshold)?,
+            veto_threshold: dec_bytes(tally.veto_threshold)?,
+        })
+    }
+
+    pub(crate) fn from_v1_params(params: v1::Params) -> Result<Self, ChainError> {
+        Ok(Self {
+            min_deposit: coins(params.min_deposit)?,
+            max_deposit_period: duration(params.max_deposit_period)?,
+            voting_period: duration(params.voting_period)?,
+            quorum: Decimal::from_proto(&params.quorum)?,
+            threshold: Decimal::from_proto(&params.threshold)?,
+            veto_threshold: Decimal::from_proto(&params.veto_threshold)?,
+        })
+    }
+
+    pub(crate) fn from_v1(
+        deposit: Option<v1::DepositParams>,
+        voting: Option<v1::VotingParams>,
+        tally: Option<v1::TallyParams>,
+    ) -> Result<Self, ChainError> {
+        let deposit = deposit.unwrap_or_default();
+        let tally = tally.unwrap_or_default();
+
+        Ok(Self {
+            min_deposit: coins(deposit.min_deposit)?,
+            max_deposit_period: duration(deposit.max_deposit_period)?,
+            voting_period: duration(voting.unwrap_or_default().voting_period)?,
+            quorum: Decimal::from_proto(&tally.quorum)?,
+            threshold: Decimal::from_proto(&tally.threshold)?,
+            veto_threshold: Decimal::from_proto(&tally.veto_threshold)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalResponse {
+    pub proposal: Proposal,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalsResponse {
+    pub proposals: Vec<Proposal>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalVoteResponse {
+    pub vote: Vote,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalVotesResponse {
+    pub votes: Vec<Vote>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalDepositResponse {
+    pub deposit: Deposit,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ProposalDepositsResponse {
+    pub deposits: Vec<Deposit>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct TallyResultResponse {
+    pub tally: TallyResult,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ParamsResponse {
+    pub params: Params,
+}
+
+/// Submit a gov v1 proposal that executes `messages` if it passes (cosmos-sdk 0.46+).
+///
+/// The signer of each msg must be the gov module account.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubmitProposalRequest {
+    pub proposer: Address,
+    pub messages: Vec<AnyMsg>,
+    pub initial_deposit: Vec<Coin>,
+    pub metadata: String,
+
+    /// Since: cosmos-sdk 0.47, must be empty for older chains
+    pub title: String,
+
+    /// Since: cosmos-sdk 0.47, must be empty for older chains
+    pub summary: String,
+}
+
+pub type SubmitProposalRequestProto = SubmitProposalRequest;
+
+impl Msg for SubmitProposalRequestProto {
+    type Proto = v1::MsgSubmitProposal;
+    type Err = GovError;
+}
+
+impl TryFrom<v1::MsgSubmitProposal> for SubmitProposalRequest {
+    type Error = GovError;
+
+    fn try_from(msg: v1::MsgSubmitProposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposer: msg.proposer.parse()?,
+            messages: msg.messages.into_iter().map(Into::into).collect(),
+            initial_deposit: coins(msg.initial_deposit)?,
+            metadata: msg.metadata,
+            title: msg.title,
+            summary: msg.summary,
+        })
+    }
+}
+
+impl TryFrom<SubmitProposalRequest> for v1::MsgSubmitProposal {
+    type Error = GovError;
+
+    fn try_from(req: SubmitProposalRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            messages: req.messages.into_iter().map(Into::into).collect(),
+            initial_deposit: req.initial_deposit.into_iter().map(Into::into).collect(),
+            proposer: req.proposer.into(),
+            metadata: req.metadata,
+            title: req.title,
+            summary: req.summary,
+        })
+    }
+}
+
+/// Submit a gov v1beta1 proposal with legacy `content`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubmitLegacyProposalRequest {
+    pub proposer: Address,
+    pub content: ProposalContent,
+    pub initial_deposit: Vec<Coin>,
+}
+
+pub type SubmitLegacyProposalRequestProto = SubmitLegacyProposalRequest;
+
+impl Msg for SubmitLegacyProposalRequestProto {
+    type Proto = v1beta1::MsgSubmitProposal;
+    type Err = GovError;
+}
+
+impl TryFrom<v1beta1::MsgSubmitProposal> for SubmitLegacyProposalRequest {
+    type Error = GovError;
+
+    fn try_from(msg: v1beta1::MsgSubmitProposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposer: msg.proposer.parse()?,
+            content: AnyMsg::from(msg.content.ok_or(GovError::MissingProposal)?).try_into()?,
+            initial_deposit: coins(msg.initial_deposit)?,
+        })
+    }
+}
+
+impl TryFrom<SubmitLegacyProposalRequest> for v1beta1::MsgSubmitProposal {
+    type Error = GovError;
+
+    fn try_from(req: SubmitLegacyProposalRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: Some(AnyMsg::from(req.content).into()),
+            initial_deposit: req.initial_deposit.into_iter().map(Into::into).collect(),
+            proposer: req.proposer.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteRequest {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub option: VoteOption,
+}
+
+pub type VoteRequestProto = VoteRequest;
+
+impl Msg for VoteRequestProto {
+    type Proto = v1beta1::MsgVote;
+    type Err = GovError;
+}
+
+impl TryFrom<v1beta1::MsgVote> for VoteRequest {
+    type Error = GovError;
+
+    fn try_from(msg: v1beta1::MsgVote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            voter: msg.voter.parse()?,
+            option: msg.option.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<VoteRequest> for v1beta1::MsgVote {
+    type Error = GovError;
+
+    fn try_from(req: VoteRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: req.proposal_id,
+            voter: req.voter.into(),
+            option: req.option as i32,
+        })
+    }
+}
+
+/// Split a vote across multiple options, whose weights must sum to 1
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteWeightedRequest {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub options: Vec<WeightedVoteOption>,
+}
+
+pub type VoteWeightedRequestProto = VoteWeightedRequest;
+
+impl Msg for VoteWeightedRequestProto {
+    type Proto = v1beta1::MsgVoteWeighted;
+    type Err = GovError;
+}
+
+impl TryFrom<v1beta1::MsgVoteWeighted> for VoteWeightedRequest {
+    type Error = GovError;
+
+    fn try_from(msg: v1beta1::MsgVoteWeighted) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            voter: msg.voter.parse()?,
+            options: msg
+                .options
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<VoteWeightedRequest> for v1beta1::MsgVoteWeighted {
+    type Error = GovError;
+
+    fn try_from(req: VoteWeightedRequest) -> Result<Self, Self::Error> {
+        if req.options.is_empty() {
+            return Err(GovError::EmptyVoteOptions);
+        }
+
+        Ok(Self {
+            proposal_id: req.proposal_id,
+            voter: req.voter.into(),
+            options: req.options.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DepositRequest {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: Vec<Coin>,
+}
+
+pub type DepositRequestProto = DepositRequest;
+
+impl Msg for DepositRequestProto {
+    type Proto = v1beta1::MsgDeposit;
+    type Err = GovError;
+}
+
+impl TryFrom<v1beta1::MsgDeposit> for DepositRequest {
+    type Error = GovError;
+
+    fn try_from(msg: v1beta1::MsgDeposit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            depositor: msg.depositor.parse()?,
+            amount: coins(msg.amount)?,
+        })
+    }
+}
+
+impl TryFrom<DepositRequest> for v1beta1::MsgDeposit {
+    type Error = GovError;
+
+    fn try_from(req: DepositRequest) -> Result<Self, Self::Error> {
+        if req.amount.is_empty() {
+            return Err(GovError::EmptyAmount);
+        }
+
+        for amount in &req.amount {
+            if amount.amount == 0 {
+                return Err(GovError::EmptyAmount);
+            }
+        }
+
+        Ok(Self {
+            proposal_id: req.proposal_id,
+            depositor: req.depositor.into(),
+            amount: req.amount.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SubmitProposalResponse {
+    pub proposal_id: u64,
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SubmitProposalBatchResponse {
+    pub proposal_ids: Vec<u64>,
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VoteResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VoteWeightedResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DepositResponse {
+    pub res: ChainTxResponse,
+}
@@ -0,0 +1,1041 @@
+use std::{collections::HashMap, time::SystemTime};
+
+use cosmrs::proto::ibc::{
+    applications::transfer::v1::{
+        QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest,
+        QueryDenomTraceResponse, QueryDenomTracesRequest, QueryDenomTracesResponse,
+    },
+    core::{
+        channel::v1::{
+            QueryChannelClientStateRequest, QueryChannelClientStateResponse,
+            QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse,
+            QueryChannelRequest, QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
+            QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
+            QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
+            QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+            QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+            QueryPacketCommitmentRequest, QueryPacketCommitmentResponse,
+            QueryPacketCommitmentsRequest, QueryPacketCommitmentsResponse,
+            QueryPacketReceiptRequest, QueryPacketReceiptResponse, QueryUnreceivedAcksRequest,
+            QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+            QueryUnreceivedPacketsResponse,
+        },
+        client::v1::{
+            QueryClientStateRequest, QueryClientStateResponse, QueryClientStatesRequest,
+            QueryClientStatesResponse, QueryClientStatusRequest, QueryClientStatusResponse,
+            QueryConsensusStateRequest, QueryConsensusStateResponse, QueryConsensusStatesRequest,
+            QueryConsensusStatesResponse,
+        },
+        connection::v1::{
+            QueryClientConnectionsRequest, QueryClientConnectionsResponse, QueryConnectionRequest,
+            QueryConnectionResponse, QueryConnectionsRequest, QueryConnectionsResponse,
+        },
+    },
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::IbcError,
+    model::{
+        Channel, ChannelClientStateResponse, ChannelConsensusStateResponse, ChannelResponse,
+        ChannelsResponse, ClientConnectionsResponse, ClientStateResponse, ClientStatesResponse,
+        ClientStatusResponse, Connection, ConnectionResponse, ConnectionsResponse,
+        ConsensusStateResponse, ConsensusStatesResponse, DenomHashResponse, DenomTraceResponse,
+        DenomTracesResponse, EscrowAddressResponse, IbcHeight, NextSequenceReceiveResponse,
+        PacketAcknowledgementResponse, PacketAcknowledgementsResponse, PacketCommitmentResponse,
+        PacketCommitmentsResponse, PacketReceiptResponse, TransferRequest, TransferResponse,
+        UnreceivedAcksResponse, UnreceivedPacketsResponse,
+    },
+    proto::{QueryEscrowAddressRequest, QueryEscrowAddressResponse},
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Send tokens to another chain over IBC (ICS-20)
+    pub async fn ibc_transfer(
+        &self,
+        req: TransferRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TransferResponse, IbcError> {
+        self.ibc_transfer_batch(vec![req], key, tx_options).await
+    }
+
+    /// Send several IBC transfers in one tx. Transfers over the same channel have their timeouts computed
+    /// from the same counterparty height and time.
+    pub async fn ibc_transfer_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TransferResponse, IbcError>
+    where
+        I: IntoIterator<Item = TransferRequest>,
+    {
+        self.ibc_transfer_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `ibc_transfer_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn ibc_transfer_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<TransferResponse, IbcError>, IbcError>
+    where
+        I: IntoIterator<Item = TransferRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let mut counterparties = HashMap::new();
+        let mut msgs = vec![];
+
+        for req in reqs {
+            let channel = (req.source_port.clone(), req.source_channel.clone());
+
+            let (height, time) = match counterparties.get(&channel) {
+                Some(latest) => *latest,
+                None => {
+                    let latest = self
+                        .ibc_counterparty_height_and_time(channel.0.clone(), channel.1.clone())
+                        .await?;
+                    *counterparties.entry(channel).or_insert(latest)
+                }
+            };
+
+            msgs.push(req.to_proto(sender_addr.clone(), height, time)?);
+        }
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(TransferResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query the denom trace of an IBC voucher, by its `ibc/<HASH>` denom or bare hash
+    pub async fn ibc_query_denom_trace(
+        &self,
+        hash: String,
+    ) -> Result<DenomTraceResponse, IbcError> {
+        let req = QueryDenomTraceRequest {
+            hash: hash.trim_start_matches("ibc/").to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomTraceResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomTrace",
+            )
+            .await?;
+
+        Ok(DenomTraceResponse {
+            denom_trace: res.denom_trace.ok_or(IbcError::MissingDenomTrace)?.into(),
+        })
+    }
+
+    /// Query all the denom traces of IBC vouchers on this chain
+    pub async fn ibc_query_denom_traces(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DenomTracesResponse, IbcError> {
+        let req = QueryDenomTracesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomTracesResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomTraces",
+            )
+            .await?;
+
+        Ok(DenomTracesResponse {
+            denom_traces: res.denom_traces.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the hash of a full denom trace path, ie: `transfer/channel-0/uatom`.
+    /// Use `Denom::from_ibc_trace()` to compute it locally instead.
+    pub async fn ibc_query_denom_hash(&self, trace: String) -> Result<DenomHashResponse, IbcError> {
+        let req = QueryDenomHashRequest { trace };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomHashResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomHash",
+            )
+            .await?;
+
+        Ok(DenomHashResponse { hash: res.hash })
+    }
+
+    /// Query the address holding the tokens escrowed for transfers out of `port_id`/`channel_id` (ibc-go v4+)
+    pub async fn ibc_query_escrow_address(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<EscrowAddressResponse, IbcError> {
+        let req = QueryEscrowAddressRequest {
+            port_id,
+            channel_id,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryEscrowAddressResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/EscrowAddress",
+            )
+            .await?;
+
+        Ok(EscrowAddressResponse {
+            address: res.escrow_address.parse()?,
+        })
+    }
+
+    /// Query the light client state of `client_id`
+    pub async fn ibc_query_client_state(
+        &self,
+        client_id: String,
+    ) -> Result<ClientStateResponse, IbcError> {
+        let req = QueryClientStateRequest { client_id };
+
+        let res = self
+            .client
+            .query::<_, QueryClientStateResponse>(req, "/ibc.core.client.v1.Query/ClientState")
+            .await?;
+
+        Ok(ClientStateResponse {
+            client_state: res.client_state.ok_or(IbcError::MissingClientState)?.into(),
+        })
+    }
+
+    /// Query the light client states of all clients
+    pub async fn ibc_query_client_states(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ClientStatesResponse, IbcError> {
+        let req = QueryClientStatesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryClientStatesResponse>(req, "/ibc.core.client.v1.Query/ClientStates")
+            .await?;
+
+        let client_states = res
+            .client_states
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ClientStatesResponse {
+            client_states,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query whether `client_id` is active, frozen or expired
+    pub async fn ibc_query_client_status(
+        &self,
+        client_id: String,
+    ) -> Result<ClientStatusResponse, IbcError> {
+        let req = QueryClientStatusRequest { client_id };
+
+        let res = self
+            .client
+            .query::<_, QueryClientStatusResponse>(req, "/ibc.core.client.v1.Query/ClientStatus")
+            .await?;
+
+        Ok(ClientStatusResponse {
+            status: res.status.as_str().into(),
+        })
+    }
+
+    /// Query the consensus state of `client_id` at `height`, or at its latest height if `None`
+    pub async fn ibc_query_consensus_state(
+        &self,
+        client_id: String,
+        height: Option<IbcHeight>,
+    ) -> Result<ConsensusStateResponse, IbcError> {
+        let req = QueryConsensusStateRequest {
+            client_id,
+            revision_number: height.map(|h| h.revision_number).unwrap_or_default(),
+            revision_height: height.map(|h| h.revision_height).unwrap_or_default(),
+            latest_height: height.is_none(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConsensusStateResponse>(
+                req,
+                "/ibc.core.client.v1.Query/ConsensusState",
+            )
+            .await?;
+
+        Ok(ConsensusStateResponse {
+            consensus_state: res
+                .consensus_state
+                .ok_or(IbcError::MissingConsensusState)?
+                .into(),
+        })
+    }
+
+    /// Query all the stored consensus states of `client_id`
+    pub async fn ibc_query_consensus_states(
+        &self,
+        client_id: String,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ConsensusStatesResponse, IbcError> {
+        let req = QueryConsensusStatesRequest {
+            client_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConsensusStatesResponse>(
+                req,
+                "/ibc.core.client.v1.Query/ConsensusStates",
+            )
+            .await?;
+
+        let consensus_states = res
+            .consensus_states
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ConsensusStatesResponse {
+            consensus_states,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn ibc_query_connection(
+        &self,
+        connection_id: String,
+    ) -> Result<ConnectionResponse, IbcError> {
+        let req = QueryConnectionRequest {
+            connection_id: connection_id.clone(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionResponse>(req, "/ibc.core.connection.v1.Query/Connection")
+            .await?;
+
+        Ok(ConnectionResponse {
+            connection: Connection::from_end(
+                connection_id,
+                res.connection.ok_or(IbcError::MissingConnection)?,
+            )?,
+        })
+    }
+
+    pub async fn ibc_query_connections(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ConnectionsResponse, IbcError> {
+        let req = QueryConnectionsRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionsResponse>(req, "/ibc.core.connection.v1.Query/Connections")
+            .await?;
+
+        let connections = res
+            .connections
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ConnectionsResponse {
+            connections,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the ids of the connections built on top of `client_id`
+    pub async fn ibc_query_client_connections(
+        &self,
+        client_id: String,
+    ) -> Result<ClientConnectionsResponse, IbcError> {
+        let req = QueryClientConnectionsRequest { client_id };
+
+        let res = self
+            .client
+            .query::<_, QueryClientConnectionsResponse>(
+                req,
+                "/ibc.core.connection.v1.Query/ClientConnections",
+            )
+            .await?;
+
+        Ok(ClientConnectionsResponse {
+            connection_ids: res.connection_paths,
+        })
+    }
+
+    pub async fn ibc_query_channel(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<ChannelResponse, IbcError> {
+        let req = QueryChannelRequest {
+            port_id: port_id.clone(),
+            channel_id: channel_id.clone(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelResponse>(req, "/ibc.core.channel.v1.Query/Channel")
+            .await?;
+
+        Ok(ChannelResponse {
+            channel: Channel::from_end(
+                port_id,
+                channel_id,
+                res.channel.ok_or(IbcError::MissingChannel)?,
+            )?,
+        })
+    }
+
+    pub async fn ibc_query_channels(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ChannelsResponse, IbcError> {
+        let req = QueryChannelsRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelsResponse>(req, "/ibc.core.channel.v1.Query/Channels")
+            .await?;
+
+        let channels = res
+            .channels
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ChannelsResponse {
+            channels,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the channels opened over `connection_id`
+    pub async fn ibc_query_connection_channels(
+        &self,
+        connection_id: String,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ChannelsResponse, IbcError> {
+        let req = QueryConnectionChannelsRequest {
+            connection: connection_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionChannelsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/ConnectionChannels",
+            )
+            .await?;
+
+        let channels = res
+            .channels
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ChannelsResponse {
+            channels,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the light client state of the counterparty chain at the other end of `port_id`/`channel_id`
+    pub async fn ibc_query_channel_client_state(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<ChannelClientStateResponse, IbcError> {
+        let req = QueryChannelClientStateRequest {
+            port_id,
+            channel_id,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelClientStateResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/ChannelClientState",
+            )
+            .await?;
+
+        Ok(ChannelClientStateResponse {
+            client_state: res
+                .identified_client_state
+                .ok_or(IbcError::MissingClientState)?
+                .try_into()?,
+        })
+    }
+
+    /// Query the light client consensus state at `height` of the counterparty chain at the other end of `port_id`/`channel_id`
+    pub async fn ibc_query_channel_consensus_state(
+        &self,
+        port_id: String,
+        channel_id: String,
+        height: IbcHeight,
+    ) -> Result<ChannelConsensusStateResponse, IbcError> {
+        let req = QueryChannelConsensusStateRequest {
+            port_id,
+            channel_id,
+            revision_number: height.revision_number,
+            revision_height: height.revision_height,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelConsensusStateResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/ChannelConsensusState",
+            )
+            .await?;
+
+        Ok(ChannelConsensusStateResponse {
+            client_id: res.client_id,
+            consensus_state: res
+                .consensus_state
+                .ok_or(IbcError::MissingConsensusState)?
+                .into(),
+        })
+    }
+
+    /// Query the commitment of a packet sent from this chain, which exists until the packet is acknowledged or timed out
+    pub async fn ibc_query_packet_commitment(
+        &self,
+        port_id: String,
+        channel_id: String,
+        sequence: u64,
+    ) -> Result<PacketCommitmentResponse, IbcError> {
+        let req = QueryPacketCommitmentRequest {
+            port_id,
+            channel_id,
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketCommitmentResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketCommitment",
+            )
+            .await?;
+
+        Ok(PacketCommitmentResponse {
+            commitment: res.commitment,
+        })
+    }
+
+    /// Query the commitments of all packets sent from this chain over a channel that are still in flight
+    pub async fn ibc_query_packet_commitments(
+        &self,
+        port_id: String,
+        channel_id: String,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PacketCommitmentsResponse, IbcError> {
+        let req = QueryPacketCommitmentsRequest {
+            port_id,
+            channel_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketCommitmentsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketCommitments",
+            )
+            .await?;
+
+        Ok(PacketCommitmentsResponse {
+            commitments: res.commitments.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query whether this chain received the packet `sequence`. Only meaningful for unordered channels.
+    pub async fn ibc_query_packet_receipt(
+        &self,
+        port_id: String,
+        channel_id: String,
+        sequence: u64,
+    ) -> Result<PacketReceiptResponse, IbcError> {
+        let req = QueryPacketReceiptRequest {
+            port_id,
+            channel_id,
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketReceiptResponse>(req, "/ibc.core.channel.v1.Query/PacketReceipt")
+            .await?;
+
+        Ok(PacketReceiptResponse {
+            received: res.received,
+        })
+    }
+
+    /// Query the acknowledgement this chain wrote for the received packet `sequence`
+    pub async fn ibc_query_packet_acknowledgement(
+        &self,
+        port_id: String,
+        channel_id: String,
+        sequence: u64,
+    ) -> Result<PacketAcknowledgementResponse, IbcError> {
+        let req = QueryPacketAcknowledgementRequest {
+            port_id,
+            channel_id,
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketAcknowledgementResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketAcknowledgement",
+            )
+            .await?;
+
+        Ok(PacketAcknowledgementResponse {
+            acknowledgement: res.acknowledgement,
+        })
+    }
+
+    /// Query the acknowledgements this chain wrote for received packets,
+    /// optionally limited to `packet_commitment_sequences`
+    pub async fn ibc_query_packet_acknowledgements(
+        &self,
+        port_id: String,
+        channel_id: String,
+        packet_commitment_sequences: Vec<u64>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PacketAcknowledgementsResponse, IbcError> {
+        let req = QueryPacketAcknowledgementsRequest {
+            port_id,
+            channel_id,
+            pagination: pagination.map(Into::into),
+            packet_commitment_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketAcknowledgementsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketAcknowledgements",
+            )
+            .await?;
+
+        Ok(PacketAcknowledgementsResponse {
+            acknowledgements: res.acknowledgements.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Given the sequences of packets committed on the counterparty, returns the ones this chain has not received yet
+    pub async fn ibc_query_unreceived_packets(
+        &self,
+        port_id: String,
+        channel_id: String,
+        packet_commitment_sequences: Vec<u64>,
+    ) -> Result<UnreceivedPacketsResponse, IbcError> {
+        let req = QueryUnreceivedPacketsRequest {
+            port_id,
+            channel_id,
+            packet_commitment_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnreceivedPacketsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/UnreceivedPackets",
+            )
+            .await?;
+
+        Ok(UnreceivedPacketsResponse {
+            sequences: res.sequences,
+        })
+    }
+
+    /// Given the sequences of packets acknowledged on the counterparty, returns the ones
+    /// whose acknowledgement has not been relayed back to this chain yet
+    pub async fn ibc_query_unreceived_acks(
+        &self,
+        port_id: String,
+        channel_id: String,
+        packet_ack_sequences: Vec<u64>,
+    ) -> Result<UnreceivedAcksResponse, IbcError> {
+        let req = QueryUnreceivedAcksRequest {
+            port_id,
+            channel_id,
+            packet_ack_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnreceivedAcksResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/UnreceivedAcks",
+            )
+            .await?;
+
+        Ok(UnreceivedAcksResponse {
+            sequences: res.sequences,
+        })
+    }
+
+    /// Query the sequence of the next packet this chain expects to receive. Only meaningful for ordered channels.
+    pub async fn ibc_query_next_sequence_receive(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<NextSequenceReceiveResponse, IbcError> {
+        let req = QueryNextSequenceReceiveRequest {
+            port_id,
+            channel_id,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryNextSequenceReceiveResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/NextSequenceReceive",
+            )
+            .await?;
+
+        Ok(NextSequenceReceiveResponse {
+            next_sequence_receive: res.next_sequence_receive,
+        })
+    }
+
+    /// Latest height and time of the chain at the other end of `port_id`/`channel_id`, as tracked by the
+    /// channel's light client. ibc-go checks transfer timeouts against these, not the sending chain's.
+    async fn ibc_counterparty_height_and_time(
+        &self,
+        port_id: String,
+        channel_id: String,
+    ) -> Result<(IbcHeight, SystemTime), IbcError> {
+        let height = self
+            .ibc_query_channel_client_state(port_id.clone(), channel_id.clone())
+            .await?
+            .client_state
+            .latest_height()?;
+
+        let time = self
+            .ibc_query_channel_consensus_state(port_id, channel_id, height)
+            .await?
+            .timestamp()?;
+
+        Ok((height, time))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::{
+        cosmos::{
+            auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+            tx::v1beta1::{TxBody, TxRaw},
+        },
+        ibc::{
+            applications::transfer::v1::MsgTransfer,
+            core::{
+                channel::v1::{
+                    Counterparty, IdentifiedChannel, QueryChannelClientStateRequest,
+                    QueryChannelClientStateResponse, QueryChannelConsensusStateRequest,
+                    QueryChannelConsensusStateResponse, QueryConnectionChannelsRequest,
+                    QueryConnectionChannelsResponse,
+                },
+                client::v1::{Height, IdentifiedClientState},
+            },
+            lightclients::tendermint::v1::{ClientState, ConsensusState},
+        },
+        traits::{Message, MessageExt},
+    };
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::{OffsetParams, PageID, PaginationRequest, TxOptions},
+            response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::ibc::model::{
+            ChannelOrder, ChannelState, TransferRequest, TransferTimeout, TRANSFER_PORT,
+        },
+        signing_key::key::SigningKey,
+    };
+
+    #[tokio::test]
+    async fn test_ibc_transfer_counterparty_timeouts() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // both transfers go over channel-0, so its light client is only queried once
+        mock_client
+            .expect_query::<QueryChannelClientStateRequest, QueryChannelClientStateResponse>()
+            .times(1)
+            .returning(|req, t: &str| {
+                assert_eq!(t, "/ibc.core.channel.v1.Query/ChannelClientState");
+                assert_eq!(req.port_id, "transfer");
+                assert_eq!(req.channel_id, "channel-0");
+
+                Ok(QueryChannelClientStateResponse {
+                    identified_client_state: Some(IdentifiedClientState {
+                        client_id: "07-tendermint-0".to_string(),
+                        client_state: Some(cosmrs::proto::Any {
+                            type_url: "/ibc.lightclients.tendermint.v1.ClientState".to_string(),
+                            value: ClientState {
+                                chain_id: "osmosis-4".to_string(),
+                                latest_height: Some(Height {
+                                    revision_number: 4,
+                                    revision_height: 9_000_000,
+                                }),
+                                ..Default::default()
+                            }
+                            .to_bytes()
+                            .unwrap(),
+                        }),
+                    }),
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        mock_client
+            .expect_query::<QueryChannelConsensusStateRequest, QueryChannelConsensusStateResponse>()
+            .times(1)
+            .returning(|req, t: &str| {
+                assert_eq!(t, "/ibc.core.channel.v1.Query/ChannelConsensusState");
+                assert_eq!(req.revision_number, 4);
+                assert_eq!(req.revision_height, 9_000_000);
+
+                Ok(QueryChannelConsensusStateResponse {
+                    consensus_state: Some(cosmrs::proto::Any {
+                        type_url: "/ibc.lightclients.tendermint.v1.ConsensusState".to_string(),
+                        value: ConsensusState {
+                            timestamp: Some(prost_types::Timestamp {
+                                seconds: 1_700_000_000,
+                                nanos: 0,
+                            }),
+                            root: None,
+                            next_validators_hash: vec![],
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                    client_id: "07-tendermint-0".to_string(),
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(move |tx, _| {
+                let tx: TxRaw = tx.clone().into();
+                let msgs = TxBody::decode(tx.body_bytes.as_slice()).unwrap().messages;
+                assert_eq!(msgs.len(), 2);
+
+                for msg in msgs {
+                    assert_eq!(msg.type_url, "/ibc.applications.transfer.v1.MsgTransfer");
+
+                    let msg = MsgTransfer::decode(msg.value.as_slice()).unwrap();
+                    assert_eq!(
+                        msg.timeout_height,
+                        Some(Height {
+                            revision_number: 4,
+                            revision_height: 9_000_010,
+                        })
+                    );
+                    assert_eq!(msg.timeout_timestamp, 1_700_000_600_000_000_000);
+                }
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        mock_client
+            .expect_wait_for_tx()
+            .times(1)
+            .returning(|tx_hash| {
+                Ok(ChainTxResponse {
+                    tx_hash: tx_hash.to_string(),
+                    height: 1337,
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let transfer = TransferRequest {
+            source_port: TRANSFER_PORT.to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            },
+            receiver: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            timeout: TransferTimeout {
+                height_offset: Some(10),
+                timestamp_offset: Some(Duration::from_secs(600)),
+            },
+        };
+
+        let res = cosm_tome
+            .ibc_transfer_batch(
+                vec![transfer.clone(), transfer],
+                &key,
+                &TxOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+
+    #[tokio::test]
+    async fn test_ibc_query_connection_channels() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryConnectionChannelsRequest, QueryConnectionChannelsResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/ibc.core.channel.v1.Query/ConnectionChannels");
+                assert_eq!(req.connection, "connection-0");
+                assert_eq!(req.pagination.unwrap().limit, 10);
+
+                Ok(QueryConnectionChannelsResponse {
+                    channels: vec![IdentifiedChannel {
+                        state: 3,
+                        ordering: 1,
+                        counterparty: Some(Counterparty {
+                            port_id: "transfer".to_string(),
+                            channel_id: "channel-141".to_string(),
+                        }),
+                        connection_hops: vec!["connection-0".to_string()],
+                        version: "ics20-1".to_string(),
+                        port_id: "transfer".to_string(),
+                        channel_id: "channel-0".to_string(),
+                    }],
+                    pagination: None,
+                    height: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .ibc_query_connection_channels(
+                "connection-0".to_string(),
+                Some(PaginationRequest {
+                    page: PageID::Offset(OffsetParams::default()),
+                    limit: 10,
+                    reverse: false,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.channels.len(), 1);
+
+        let channel = &res.channels[0];
+        assert_eq!(channel.channel_id, "channel-0");
+        assert_eq!(channel.state, ChannelState::Open);
+        assert_eq!(channel.ordering, ChannelOrder::Unordered);
+        assert_eq!(channel.counterparty.channel_id, "channel-141");
+    }
+}
@@ -0,0 +1,63 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum IbcError {
+    #[error("IBC transfer requires a timeout height or timeout timestamp")]
+    MissingTimeout,
+
+    #[error("IBC transfer timeout overflows")]
+    TimeoutOverflow,
+
+    #[error("unsupported light client type: {type_url}")]
+    UnsupportedClientType { type_url: String },
+
+    #[error("MsgTransfer missing token")]
+    MissingToken,
+
+    #[error("denom trace missing from chain response")]
+    MissingDenomTrace,
+
+    #[error("client state missing from chain response")]
+    MissingClientState,
+
+    #[error("consensus state missing from chain response")]
+    MissingConsensusState,
+
+    #[error("timestamp missing from consensus state")]
+    MissingTimestamp,
+
+    #[error("height missing from chain response")]
+    MissingHeight,
+
+    #[error("connection missing from chain response")]
+    MissingConnection,
+
+    #[error("channel missing from chain response")]
+    MissingChannel,
+
+    #[error("counterparty missing from chain response")]
+    MissingCounterparty,
+
+    #[error("unsupported ConnectionState: {i:?}")]
+    ConnectionState { i: i32 },
+
+    #[error("unsupported ChannelState: {i:?}")]
+    ChannelState { i: i32 },
+
+    #[error("unsupported ChannelOrder: {i:?}")]
+    ChannelOrder { i: i32 },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
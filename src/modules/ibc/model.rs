@@ -0,0 +1,946 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use cosmrs::proto::ibc::{
+    applications::transfer::v1::{DenomTrace as ProtoDenomTrace, MsgTransfer},
+    core::{
+        channel::v1::{
+            Channel as ProtoChannel, Counterparty as ProtoChannelCounterparty, IdentifiedChannel,
+            PacketState as ProtoPacketState,
+        },
+        client::v1::{
+            ConsensusStateWithHeight as ProtoConsensusStateWithHeight, Height,
+            IdentifiedClientState as ProtoIdentifiedClientState,
+        },
+        connection::v1::{
+            ConnectionEnd, Counterparty as ProtoConnectionCounterparty, IdentifiedConnection,
+            Version as ProtoConnectionVersion,
+        },
+    },
+    lightclients::tendermint::v1::{
+        ClientState as TmClientState, ConsensusState as TmConsensusState,
+    },
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chain::{
+        coin::{Coin, Denom},
+        error::ChainError,
+        msg::{AnyMsg, Msg},
+        request::PaginationResponse,
+        response::ChainTxResponse,
+    },
+    modules::auth::model::Address,
+};
+
+use super::error::IbcError;
+
+/// Default port of the ICS-20 transfer module
+pub const TRANSFER_PORT: &str = "transfer";
+
+/// Height on an IBC chain. The revision number is the suffix of the chain id, ie: 4 for `osmosis-4`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+pub struct IbcHeight {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl IbcHeight {
+    /// Parses the revision number out of a `{chain_name}-{revision_number}` chain id,
+    /// defaulting to 0 for chain ids that are not in that format, like ibc-go does.
+    pub fn revision_number(chain_id: &str) -> u64 {
+        chain_id
+            .rsplit_once('-')
+            .and_then(|(_, rev)| rev.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+impl From<Height> for IbcHeight {
+    fn from(h: Height) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+impl From<IbcHeight> for Height {
+    fn from(h: IbcHeight) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+/// Relative timeouts for an IBC transfer. At least one of them must be set.
+///
+/// Like the ibc-go cli, both are added to the counterparty chain's latest height and time,
+/// as tracked by the light client of the transfer's channel when the transfer is signed.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct TransferTimeout {
+    /// number of blocks past the counterparty chain's latest height
+    pub height_offset: Option<u64>,
+
+    /// time past the counterparty chain's latest time
+    pub timestamp_offset: Option<Duration>,
+}
+
+impl Default for TransferTimeout {
+    /// 10 minute timestamp timeout, same as the ibc-go cli
+    fn default() -> Self {
+        Self {
+            height_offset: None,
+            timestamp_offset: Some(Duration::from_secs(600)),
+        }
+    }
+}
+
+/// Send `token` to `receiver` on the chain at the other end of `source_channel`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferRequest {
+    /// port on the sending chain, normally `TRANSFER_PORT`
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: Coin,
+    pub receiver: Address,
+    pub timeout: TransferTimeout,
+}
+
+impl TransferRequest {
+    /// Computes the absolute timeouts relative to the counterparty chain's `counterparty_height` and `counterparty_time`
+    pub fn to_proto(
+        self,
+        signer_addr: Address,
+        counterparty_height: IbcHeight,
+        counterparty_time: SystemTime,
+    ) -> Result<TransferRequestProto, IbcError> {
+        if self.timeout.height_offset.is_none() && self.timeout.timestamp_offset.is_none() {
+            return Err(IbcError::MissingTimeout);
+        }
+
+        let timeout_height = self
+            .timeout
+            .height_offset
+            .map(|offset| {
+                Ok::<_, IbcError>(IbcHeight {
+                    revision_number: counterparty_height.revision_number,
+                    revision_height: counterparty_height
+                        .revision_height
+                        .checked_add(offset)
+                        .ok_or(IbcError::TimeoutOverflow)?,
+                })
+            })
+            .transpose()?;
+
+        let timeout_timestamp = self
+            .timeout
+            .timestamp_offset
+            .map(|offset| {
+                let timeout = counterparty_time
+                    .checked_add(offset)
+                    .ok_or(IbcError::TimeoutOverflow)?
+                    .duration_since(UNIX_EPOCH)
+                    .map_err(ChainError::proto_time)?;
+
+                u64::try_from(timeout.as_nanos()).map_err(|_| IbcError::TimeoutOverflow)
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(TransferRequestProto {
+            signer_addr,
+            source_port: self.source_port,
+            source_channel: self.source_channel,
+            token: self.token,
+            receiver: self.receiver,
+            timeout_height,
+            timeout_timestamp,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferRequestProto {
+    pub signer_addr: Address,
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: Coin,
+    pub receiver: Address,
+    pub timeout_height: Option<IbcHeight>,
+
+    /// unix time in nanoseconds, or 0 for no timestamp timeout
+    pub timeout_timestamp: u64,
+}
+
+impl Msg for TransferRequestProto {
+    type Proto = MsgTransfer;
+    type Err = IbcError;
+}
+
+impl TryFrom<MsgTransfer> for TransferRequestProto {
+    type Error = IbcError;
+
+    fn try_from(msg: MsgTransfer) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signer_addr: msg.sender.parse()?,
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            token: msg.token.ok_or(IbcError::MissingToken)?.try_into()?,
+            receiver: msg.receiver.parse()?,
+            timeout_height: msg
+                .timeout_height
+                .map(Into::into)
+                .filter(|h: &IbcHeight| *h != IbcHeight::default()),
+            timeout_timestamp: msg.timeout_timestamp,
+        })
+    }
+}
+
+impl TryFrom<TransferRequestProto> for MsgTransfer {
+    type Error = IbcError;
+
+    fn try_from(req: TransferRequestProto) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_port: req.source_port,
+            source_channel: req.source_channel,
+            token: Some(req.token.into()),
+            sender: req.signer_addr.into(),
+            receiver: req.receiver.into(),
+            // a zero height disables the height timeout
+            timeout_height: Some(req.timeout_height.unwrap_or_default().into()),
+            timeout_timestamp: req.timeout_timestamp,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct TransferResponse {
+    pub res: ChainTxResponse,
+}
+
+/// Path a token took over IBC to reach this chain
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct DenomTrace {
+    /// `{port}/{channel}` hops the token took, ie: `transfer/channel-0`. Empty for native tokens.
+    pub path: String,
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Full trace path, ie: `transfer/channel-0/uatom`
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Denom of the token on this chain, which is `ibc/<HASH>` for tokens that came over IBC
+    pub fn ibc_denom(&self) -> Result<Denom, ChainError> {
+        if self.path.is_empty() {
+            self.base_denom.parse()
+        } else {
+            Ok(Denom::from_ibc_trace(&self.full_path()))
+        }
+    }
+}
+
+impl From<ProtoDenomTrace> for DenomTrace {
+    fn from(trace: ProtoDenomTrace) -> Self {
+        Self {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+impl From<DenomTrace> for ProtoDenomTrace {
+    fn from(trace: DenomTrace) -> Self {
+        Self {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DenomTraceResponse {
+    pub denom_trace: DenomTrace,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DenomTracesResponse {
+    pub denom_traces: Vec<DenomTrace>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DenomHashResponse {
+    /// hex encoded hash, without the `ibc/` prefix
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct EscrowAddressResponse {
+    pub address: Address,
+}
+
+/// Light client state of a counterparty chain, ie: an `/ibc.lightclients.tendermint.v1.ClientState`
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct IdentifiedClientState {
+    pub client_id: String,
+    pub client_state: AnyMsg,
+}
+
+impl TryFrom<ProtoIdentifiedClientState> for IdentifiedClientState {
+    type Error = IbcError;
+
+    fn try_from(state: ProtoIdentifiedClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: state.client_id,
+            client_state: state
+                .client_state
+                .ok_or(IbcError::MissingClientState)?
+                .into(),
+        })
+    }
+}
+
+impl IdentifiedClientState {
+    const TENDERMINT: &'static str = "/ibc.lightclients.tendermint.v1.ClientState";
+
+    /// Latest height of the counterparty chain known to the light client.
+    /// Only tendermint light clients are supported.
+    pub fn latest_height(&self) -> Result<IbcHeight, IbcError> {
+        if self.client_state.type_url != Self::TENDERMINT {
+            return Err(IbcError::UnsupportedClientType {
+                type_url: self.client_state.type_url.clone(),
+            });
+        }
+
+        let state: TmClientState = self.client_state.to_proto()?;
+
+        Ok(state.latest_height.ok_or(IbcError::MissingHeight)?.into())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ClientStateResponse {
+    pub client_state: AnyMsg,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ClientStatesResponse {
+    pub client_states: Vec<IdentifiedClientState>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ClientStatus {
+    Active,
+    Frozen,
+    Expired,
+    Unknown,
+}
+
+impl From<&str> for ClientStatus {
+    fn from(s: &str) -> Self {
+        match s {
+            "Active" => ClientStatus::Active,
+            "Frozen" => ClientStatus::Frozen,
+            "Expired" => ClientStatus::Expired,
+            _ => ClientStatus::Unknown,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ClientStatusResponse {
+    pub status: ClientStatus,
+}
+
+/// Light client consensus state at `height`, ie: an `/ibc.lightclients.tendermint.v1.ConsensusState`
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ConsensusStateWithHeight {
+    pub height: IbcHeight,
+    pub consensus_state: AnyMsg,
+}
+
+impl TryFrom<ProtoConsensusStateWithHeight> for ConsensusStateWithHeight {
+    type Error = IbcError;
+
+    fn try_from(state: ProtoConsensusStateWithHeight) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: state.height.ok_or(IbcError::MissingHeight)?.into(),
+            consensus_state: state
+                .consensus_state
+                .ok_or(IbcError::MissingConsensusState)?
+                .into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ConsensusStateResponse {
+    pub consensus_state: AnyMsg,
+}
+
+/// Light client consensus state of the counterparty chain at the other end of a channel
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ChannelConsensusStateResponse {
+    pub client_id: String,
+    pub consensus_state: AnyMsg,
+}
+
+impl ChannelConsensusStateResponse {
+    const TENDERMINT: &'static str = "/ibc.lightclients.tendermint.v1.ConsensusState";
+
+    /// Block time of the counterparty chain the consensus state was stored for.
+    /// Only tendermint light clients are supported.
+    pub fn timestamp(&self) -> Result<SystemTime, IbcError> {
+        if self.consensus_state.type_url != Self::TENDERMINT {
+            return Err(IbcError::UnsupportedClientType {
+                type_url: self.consensus_state.type_url.clone(),
+            });
+        }
+
+        let state: TmConsensusState = self.consensus_state.to_proto()?;
+
+        Ok(state
+            .timestamp
+            .map(SystemTime::try_from)
+            .ok_or(IbcError::MissingTimestamp)?
+            .map_err(ChainError::proto_time)?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ConsensusStatesResponse {
+    pub consensus_states: Vec<ConsensusStateWithHeight>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum ConnectionState {
+    Uninitialized = 0,
+    Init = 1,
+    TryOpen = 2,
+    Open = 3,
+}
+
+impl TryFrom<i32> for ConnectionState {
+    type Error = IbcError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ConnectionState::Uninitialized as i32 => Ok(ConnectionState::Uninitialized),
+            x if x == ConnectionState::Init as i32 => Ok(ConnectionState::Init),
+            x if x == ConnectionState::TryOpen as i32 => Ok(ConnectionState::TryOpen),
+            x if x == ConnectionState::Open as i32 => Ok(ConnectionState::Open),
+            _ => Err(IbcError::ConnectionState { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ConnectionVersion {
+    pub identifier: String,
+    pub features: Vec<String>,
+}
+
+impl From<ProtoConnectionVersion> for ConnectionVersion {
+    fn from(v: ProtoConnectionVersion) -> Self {
+        Self {
+            identifier: v.identifier,
+            features: v.features,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ConnectionCounterparty {
+    pub client_id: String,
+
+    /// empty while the connection handshake is still in `Init`
+    pub connection_id: String,
+
+    /// commitment store prefix on the counterparty, normally `ibc`
+    pub prefix: Vec<u8>,
+}
+
+impl From<ProtoConnectionCounterparty> for ConnectionCounterparty {
+    fn from(c: ProtoConnectionCounterparty) -> Self {
+        Self {
+            client_id: c.client_id,
+            connection_id: c.connection_id,
+            prefix: c.prefix.map(|p| p.key_prefix).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Connection {
+    pub id: String,
+    pub client_id: String,
+    pub versions: Vec<ConnectionVersion>,
+    pub state: ConnectionState,
+    pub counterparty: ConnectionCounterparty,
+
+    /// delay in nanoseconds before packets can be relayed over this connection
+    pub delay_period: u64,
+}
+
+impl Connection {
+    pub(crate) fn from_end(id: String, end: ConnectionEnd) -> Result<Self, IbcError> {
+        Ok(Self {
+            id,
+            client_id: end.client_id,
+            versions: end.versions.into_iter().map(Into::into).collect(),
+            state: end.state.try_into()?,
+            counterparty: end
+                .counterparty
+                .ok_or(IbcError::MissingCounterparty)?
+                .into(),
+            delay_period: end.delay_period,
+        })
+    }
+}
+
+impl TryFrom<IdentifiedConnection> for Connection {
+    type Error = IbcError;
+
+    fn try_from(conn: IdentifiedConnection) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: conn.id,
+            client_id: conn.client_id,
+            versions: conn.versions.into_iter().map(Into::into).collect(),
+            state: conn.state.try_into()?,
+            counterparty: conn
+                .counterparty
+                .ok_or(IbcError::MissingCounterparty)?
+                .into(),
+            delay_period: conn.delay_period,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ConnectionResponse {
+    pub connection: Connection,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ConnectionsResponse {
+    pub connections: Vec<Connection>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ClientConnectionsResponse {
+    pub connection_ids: Vec<String>,
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum ChannelState {
+    Uninitialized = 0,
+    Init = 1,
+    TryOpen = 2,
+    Open = 3,
+    Closed = 4,
+}
+
+impl TryFrom<i32> for ChannelState {
+    type Error = IbcError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ChannelState::Uninitialized as i32 => Ok(ChannelState::Uninitialized),
+            x if x == ChannelState::Init as i32 => Ok(ChannelState::Init),
+            x if x == ChannelState::TryOpen as i32 => Ok(ChannelState::TryOpen),
+            x if x == ChannelState::Open as i32 => Ok(ChannelState::Open),
+            x if x == ChannelState::Closed as i32 => Ok(ChannelState::Closed),
+            _ => Err(IbcError::ChannelState { i: v }),
+        }
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum ChannelOrder {
+    Unspecified = 0,
+    Unordered = 1,
+    Ordered = 2,
+}
+
+impl TryFrom<i32> for ChannelOrder {
+    type Error = IbcError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ChannelOrder::Unspecified as i32 => Ok(ChannelOrder::Unspecified),
+            x if x == ChannelOrder::Unordered as i32 => Ok(ChannelOrder::Unordered),
+            x if x == ChannelOrder::Ordered as i32 => Ok(ChannelOrder::Ordered),
+            _ => Err(IbcError::ChannelOrder { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ChannelCounterparty {
+    pub port_id: String,
+
+    /// empty while the channel handshake is still in `Init`
+    pub channel_id: String,
+}
+
+impl From<ProtoChannelCounterparty> for ChannelCounterparty {
+    fn from(c: ProtoChannelCounterparty) -> Self {
+        Self {
+            port_id: c.port_id,
+            channel_id: c.channel_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Channel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub state: ChannelState,
+    pub ordering: ChannelOrder,
+    pub counterparty: ChannelCounterparty,
+
+    /// connections the channel's packets travel over, currently always a single connection
+    pub connection_hops: Vec<String>,
+    pub version: String,
+}
+
+impl Channel {
+    pub(crate) fn from_end(
+        port_id: String,
+        channel_id: String,
+        end: ProtoChannel,
+    ) -> Result<Self, IbcError> {
+        Ok(Self {
+            port_id,
+            channel_id,
+            state: end.state.try_into()?,
+            ordering: end.ordering.try_into()?,
+            counterparty: end
+                .counterparty
+                .ok_or(IbcError::MissingCounterparty)?
+                .into(),
+            connection_hops: end.connection_hops,
+            version: end.version,
+        })
+    }
+}
+
+impl TryFrom<IdentifiedChannel> for Channel {
+    type Error = IbcError;
+
+    fn try_from(c: IdentifiedChannel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: c.port_id,
+            channel_id: c.channel_id,
+            state: c.state.try_into()?,
+            ordering: c.ordering.try_into()?,
+            counterparty: c.counterparty.ok_or(IbcError::MissingCounterparty)?.into(),
+            connection_hops: c.connection_hops,
+            version: c.version,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ChannelResponse {
+    pub channel: Channel,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ChannelClientStateResponse {
+    pub client_state: IdentifiedClientState,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ChannelsResponse {
+    pub channels: Vec<Channel>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Packet commitment, receipt or acknowledgement stored for `sequence` on a channel end
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketState {
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+    pub data: Vec<u8>,
+}
+
+impl From<ProtoPacketState> for PacketState {
+    fn from(p: ProtoPacketState) -> Self {
+        Self {
+            port_id: p.port_id,
+            channel_id: p.channel_id,
+            sequence: p.sequence,
+            data: p.data,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketCommitmentResponse {
+    /// hash of the packet, or empty if it was already acknowledged or timed out
+    pub commitment: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PacketCommitmentsResponse {
+    pub commitments: Vec<PacketState>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketReceiptResponse {
+    pub received: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketAcknowledgementResponse {
+    /// hash of the acknowledgement written by the receiving chain
+    pub acknowledgement: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PacketAcknowledgementsResponse {
+    pub acknowledgements: Vec<PacketState>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct UnreceivedPacketsResponse {
+    pub sequences: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct UnreceivedAcksResponse {
+    pub sequences: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct NextSequenceReceiveResponse {
+    pub next_sequence_receive: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use cosmrs::proto::ibc::lightclients::tendermint::v1::ConsensusState;
+
+    use crate::chain::{coin::Coin, msg::AnyMsg};
+    use crate::modules::ibc::error::IbcError;
+
+    use super::{
+        ChannelConsensusStateResponse, IbcHeight, TransferRequest, TransferTimeout, TRANSFER_PORT,
+    };
+
+    fn transfer(timeout: TransferTimeout) -> TransferRequest {
+        TransferRequest {
+            source_port: TRANSFER_PORT.to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Coin {
+                denom: "uatom".parse().unwrap(),
+                amount: 10,
+            },
+            receiver: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            timeout,
+        }
+    }
+
+    #[test]
+    fn test_transfer_timeouts() {
+        let counterparty_height = IbcHeight {
+            revision_number: 4,
+            revision_height: 100,
+        };
+        let counterparty_time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let req = transfer(TransferTimeout {
+            height_offset: Some(10),
+            timestamp_offset: Some(Duration::from_secs(600)),
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap();
+
+        assert_eq!(
+            req.timeout_height,
+            Some(IbcHeight {
+                revision_number: 4,
+                revision_height: 110,
+            })
+        );
+        assert_eq!(req.timeout_timestamp, 1_700_000_600_000_000_000);
+
+        let req = transfer(TransferTimeout {
+            height_offset: Some(10),
+            timestamp_offset: None,
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap();
+
+        assert_eq!(req.timeout_timestamp, 0);
+
+        let err = transfer(TransferTimeout {
+            height_offset: None,
+            timestamp_offset: None,
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, IbcError::MissingTimeout));
+    }
+
+    #[test]
+    fn test_transfer_timeout_overflow() {
+        let counterparty_height = IbcHeight {
+            revision_number: 4,
+            revision_height: u64::MAX - 5,
+        };
+        let counterparty_time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let err = transfer(TransferTimeout {
+            height_offset: Some(10),
+            timestamp_offset: None,
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, IbcError::TimeoutOverflow));
+
+        // unix nanoseconds overflow u64 in 2554
+        let err = transfer(TransferTimeout {
+            height_offset: None,
+            timestamp_offset: Some(Duration::from_secs(600 * 365 * 24 * 60 * 60)),
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, IbcError::TimeoutOverflow));
+
+        let err = transfer(TransferTimeout {
+            height_offset: None,
+            timestamp_offset: Some(Duration::MAX),
+        })
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            counterparty_height,
+            counterparty_time,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, IbcError::TimeoutOverflow));
+    }
+
+    #[test]
+    fn test_consensus_state_timestamp() {
+        let res = ChannelConsensusStateResponse {
+            client_id: "07-tendermint-0".to_string(),
+            consensus_state: AnyMsg::from_proto(
+                "/ibc.lightclients.tendermint.v1.ConsensusState",
+                ConsensusState {
+                    timestamp: Some(prost_types::Timestamp {
+                        seconds: 1_700_000_000,
+                        nanos: 5,
+                    }),
+                    root: None,
+                    next_validators_hash: vec![],
+                },
+            ),
+        };
+
+        assert_eq!(
+            res.timestamp().unwrap(),
+            UNIX_EPOCH + Duration::new(1_700_000_000, 5)
+        );
+
+        let res = ChannelConsensusStateResponse {
+            consensus_state: AnyMsg {
+                type_url: "/ibc.lightclients.solomachine.v2.ConsensusState".to_string(),
+                value: vec![],
+            },
+            ..res
+        };
+
+        assert!(matches!(
+            res.timestamp().unwrap_err(),
+            IbcError::UnsupportedClientType { .. }
+        ));
+    }
+}
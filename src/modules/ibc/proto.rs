@@ -0,0 +1,18 @@
+//! IBC transfer protos missing from `cosmos-sdk-proto`.
+//!
+//! `cosmos-sdk-proto` is generated from ibc-go v3.0.0, which predates the `EscrowAddress` query,
+//! so these mirror the upstream `.proto` definitions field for field.
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEscrowAddressRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEscrowAddressResponse {
+    #[prost(string, tag = "1")]
+    pub escrow_address: String,
+}
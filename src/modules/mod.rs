@@ -1,9 +1,25 @@
 pub mod auth;
 
+pub mod authz;
+
 pub mod bank;
 
 pub mod cosmwasm;
 
+pub mod cw20;
+
+pub mod cw721;
+
+pub mod distribution;
+
+pub mod feegrant;
+
+pub mod gov;
+
+pub mod ibc;
+
+pub mod staking;
+
 pub mod tx;
 
 pub mod tendermint;
@@ -0,0 +1,643 @@
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    QueryDelegationRequest, QueryDelegationResponse, QueryDelegatorDelegationsRequest,
+    QueryDelegatorDelegationsResponse, QueryDelegatorUnbondingDelegationsRequest,
+    QueryDelegatorUnbondingDelegationsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryPoolRequest, QueryPoolResponse, QueryRedelegationsRequest, QueryRedelegationsResponse,
+    QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorUnbondingDelegationsRequest,
+    QueryValidatorUnbondingDelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+};
+
+use crate::modules::tx::model::{BroadcastMode, PendingTx};
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::StakingError,
+    model::{
+        BondStatus, CreateValidatorRequest, CreateValidatorRequestProto, CreateValidatorResponse,
+        DelegateRequest, DelegateRequestProto, DelegateResponse, DelegationResponse,
+        DelegationsResponse, EditValidatorRequest, EditValidatorRequestProto,
+        EditValidatorResponse, ParamsResponse, PoolResponse, RedelegateRequest,
+        RedelegateRequestProto, RedelegateResponse, RedelegationsResponse,
+        UnbondingDelegationResponse, UnbondingDelegationsResponse, UndelegateRequest,
+        UndelegateRequestProto, UndelegateResponse, ValidatorResponse, ValidatorsResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Delegate `amount` of tokens from `delegator_address` to `validator_address`
+    pub async fn staking_delegate(
+        &self,
+        req: DelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DelegateResponse, StakingError> {
+        self.staking_delegate_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn staking_delegate_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DelegateResponse, StakingError>
+    where
+        I: IntoIterator<Item = DelegateRequest>,
+    {
+        self.staking_delegate_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `staking_delegate_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn staking_delegate_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<DelegateResponse, StakingError>, StakingError>
+    where
+        I: IntoIterator<Item = DelegateRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<DelegateRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(DelegateResponse { res })
+            })
+            .await?)
+    }
+
+    /// Begin unbonding `amount` of tokens delegated from `delegator_address` to `validator_address`
+    pub async fn staking_undelegate(
+        &self,
+        req: UndelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<UndelegateResponse, StakingError> {
+        self.staking_undelegate_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn staking_undelegate_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<UndelegateResponse, StakingError>
+    where
+        I: IntoIterator<Item = UndelegateRequest>,
+    {
+        self.staking_undelegate_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `staking_undelegate_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn staking_undelegate_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<UndelegateResponse, StakingError>, StakingError>
+    where
+        I: IntoIterator<Item = UndelegateRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<UndelegateRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(UndelegateResponse { res })
+            })
+            .await?)
+    }
+
+    /// Move `amount` of delegated tokens from `validator_src_address` to `validator_dst_address`
+    pub async fn staking_redelegate(
+        &self,
+        req: RedelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RedelegateResponse, StakingError> {
+        self.staking_redelegate_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn staking_redelegate_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RedelegateResponse, StakingError>
+    where
+        I: IntoIterator<Item = RedelegateRequest>,
+    {
+        self.staking_redelegate_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `staking_redelegate_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn staking_redelegate_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<RedelegateResponse, StakingError>, StakingError>
+    where
+        I: IntoIterator<Item = RedelegateRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<RedelegateRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(RedelegateResponse { res })
+            })
+            .await?)
+    }
+
+    /// Create a new validator, self delegating `value` tokens
+    pub async fn staking_create_validator(
+        &self,
+        req: CreateValidatorRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<CreateValidatorResponse, StakingError> {
+        self.staking_create_validator_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn staking_create_validator_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<CreateValidatorResponse, StakingError>
+    where
+        I: IntoIterator<Item = CreateValidatorRequest>,
+    {
+        self.staking_create_validator_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `staking_create_validator_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn staking_create_validator_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<CreateValidatorResponse, StakingError>, StakingError>
+    where
+        I: IntoIterator<Item = CreateValidatorRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs
+            .into_iter()
+            .collect::<Vec<CreateValidatorRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(CreateValidatorResponse { res })
+            })
+            .await?)
+    }
+
+    /// Edit an existing validator's description, commission rate or min self delegation
+    pub async fn staking_edit_validator(
+        &self,
+        req: EditValidatorRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<EditValidatorResponse, StakingError> {
+        self.staking_edit_validator_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    pub async fn staking_edit_validator_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<EditValidatorResponse, StakingError>
+    where
+        I: IntoIterator<Item = EditValidatorRequest>,
+    {
+        self.staking_edit_validator_batch_async(reqs, key, tx_options, BroadcastMode::Sync)
+            .await?
+            .wait_with_retries(self, key)
+            .await
+    }
+
+    /// Non-blocking `staking_edit_validator_batch()` that returns once the tx is broadcast with `mode`
+    pub async fn staking_edit_validator_batch_async<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+    ) -> Result<PendingTx<EditValidatorResponse, StakingError>, StakingError>
+    where
+        I: IntoIterator<Item = EditValidatorRequest>,
+    {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        let msgs = reqs.into_iter().collect::<Vec<EditValidatorRequestProto>>();
+
+        Ok(self
+            .tx_broadcast_pending(msgs, sender_addr, key, tx_options, mode, |res| {
+                Ok(EditValidatorResponse { res })
+            })
+            .await?)
+    }
+
+    /// Query all validators, optionally filtered by their bond `status`
+    pub async fn staking_query_validators(
+        &self,
+        status: Option<BondStatus>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ValidatorsResponse, StakingError> {
+        let req = QueryValidatorsRequest {
+            status: status.map(|s| s.as_ref().to_string()).unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorsResponse>(req, "/cosmos.staking.v1beta1.Query/Validators")
+            .await?;
+
+        let validators = res
+            .validators
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ValidatorsResponse {
+            validators,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query a single validator by its operator address
+    pub async fn staking_query_validator(
+        &self,
+        validator_address: Address,
+    ) -> Result<ValidatorResponse, StakingError> {
+        let req = QueryValidatorRequest {
+            validator_addr: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorResponse>(req, "/cosmos.staking.v1beta1.Query/Validator")
+            .await?;
+
+        let validator = res
+            .validator
+            .ok_or(StakingError::MissingValidator)?
+            .try_into()?;
+
+        Ok(ValidatorResponse { validator })
+    }
+
+    /// Query all delegations made to a validator
+    pub async fn staking_query_validator_delegations(
+        &self,
+        validator_address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DelegationsResponse, StakingError> {
+        let req = QueryValidatorDelegationsRequest {
+            validator_addr: validator_address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/ValidatorDelegations",
+            )
+            .await?;
+
+        let delegations = res
+            .delegation_responses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DelegationsResponse {
+            delegations,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all unbonding delegations from a validator
+    pub async fn staking_query_validator_unbonding_delegations(
+        &self,
+        validator_address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<UnbondingDelegationsResponse, StakingError> {
+        let req = QueryValidatorUnbondingDelegationsRequest {
+            validator_addr: validator_address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorUnbondingDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations",
+            )
+            .await?;
+
+        let unbonding_delegations = res
+            .unbonding_responses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(UnbondingDelegationsResponse {
+            unbonding_delegations,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the delegation from `delegator_address` to `validator_address`
+    pub async fn staking_query_delegation(
+        &self,
+        delegator_address: Address,
+        validator_address: Address,
+    ) -> Result<DelegationResponse, StakingError> {
+        let req = QueryDelegationRequest {
+            delegator_addr: delegator_address.into(),
+            validator_addr: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationResponse>(req, "/cosmos.staking.v1beta1.Query/Delegation")
+            .await?;
+
+        let delegation = res
+            .delegation_response
+            .ok_or(StakingError::MissingDelegation)?
+            .try_into()?;
+
+        Ok(DelegationResponse { delegation })
+    }
+
+    /// Query the unbonding delegation from `delegator_address` to `validator_address`
+    pub async fn staking_query_unbonding_delegation(
+        &self,
+        delegator_address: Address,
+        validator_address: Address,
+    ) -> Result<UnbondingDelegationResponse, StakingError> {
+        let req = QueryUnbondingDelegationRequest {
+            delegator_addr: delegator_address.into(),
+            validator_addr: validator_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnbondingDelegationResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/UnbondingDelegation",
+            )
+            .await?;
+
+        let unbonding_delegation = res
+            .unbond
+            .ok_or(StakingError::MissingUnbondingDelegation)?
+            .try_into()?;
+
+        Ok(UnbondingDelegationResponse {
+            unbonding_delegation,
+        })
+    }
+
+    /// Query all delegations made by `delegator_address`
+    pub async fn staking_query_delegator_delegations(
+        &self,
+        delegator_address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DelegationsResponse, StakingError> {
+        let req = QueryDelegatorDelegationsRequest {
+            delegator_addr: delegator_address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/DelegatorDelegations",
+            )
+            .await?;
+
+        let delegations = res
+            .delegation_responses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(DelegationsResponse {
+            delegations,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all unbonding delegations made by `delegator_address`
+    pub async fn staking_query_delegator_unbonding_delegations(
+        &self,
+        delegator_address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<UnbondingDelegationsResponse, StakingError> {
+        let req = QueryDelegatorUnbondingDelegationsRequest {
+            delegator_addr: delegator_address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorUnbondingDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations",
+            )
+            .await?;
+
+        let unbonding_delegations = res
+            .unbonding_responses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(UnbondingDelegationsResponse {
+            unbonding_delegations,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query redelegations made by `delegator_address`,
+    /// optionally filtered by source and/or destination validator
+    pub async fn staking_query_redelegations(
+        &self,
+        delegator_address: Address,
+        src_validator_address: Option<Address>,
+        dst_validator_address: Option<Address>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<RedelegationsResponse, StakingError> {
+        let req = QueryRedelegationsRequest {
+            delegator_addr: delegator_address.into(),
+            src_validator_addr: src_validator_address.map(Into::into).unwrap_or_default(),
+            dst_validator_addr: dst_validator_address.map(Into::into).unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryRedelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/Redelegations",
+            )
+            .await?;
+
+        let redelegations = res
+            .redelegation_responses
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(RedelegationsResponse {
+            redelegations,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the amount of bonded and not bonded tokens in the staking pool
+    pub async fn staking_query_pool(&self) -> Result<PoolResponse, StakingError> {
+        let req = QueryPoolRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryPoolResponse>(req, "/cosmos.staking.v1beta1.Query/Pool")
+            .await?;
+
+        let pool = res.pool.ok_or(StakingError::MissingPool)?.try_into()?;
+
+        Ok(PoolResponse { pool })
+    }
+
+    /// Query staking module's params
+    pub async fn staking_query_params(&self) -> Result<ParamsResponse, StakingError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.staking.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::staking::v1beta1::{
+        Commission, CommissionRates, QueryValidatorsRequest, QueryValidatorsResponse, Validator,
+    };
+
+    use crate::{
+        chain::decimal::Decimal,
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::staking::model::BondStatus,
+    };
+
+    #[tokio::test]
+    async fn test_staking_query_validators() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: Some("localhost".to_string()),
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryValidatorsRequest, QueryValidatorsResponse>()
+            .times(1)
+            .returning(move |req, t: &str| {
+                assert_eq!(t, "/cosmos.staking.v1beta1.Query/Validators");
+                assert_eq!(req.status, "BOND_STATUS_BONDED");
+
+                Ok(QueryValidatorsResponse {
+                    validators: vec![Validator {
+                        operator_address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                        status: 3,
+                        tokens: "1000000".to_string(),
+                        delegator_shares: "1000000000000000000000000".to_string(),
+                        commission: Some(Commission {
+                            commission_rates: Some(CommissionRates {
+                                rate: "50000000000000000".to_string(),
+                                max_rate: "200000000000000000".to_string(),
+                                max_change_rate: "10000000000000000".to_string(),
+                            }),
+                            update_time: None,
+                        }),
+                        min_self_delegation: "1".to_string(),
+                        ..Default::default()
+                    }],
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .staking_query_validators(Some(BondStatus::Bonded), None)
+            .await
+            .unwrap();
+
+        let val = &res.validators[0];
+        assert_eq!(val.status, BondStatus::Bonded);
+        assert_eq!(val.tokens, 1_000_000);
+        assert_eq!(val.delegator_shares, Decimal::from_int(1_000_000).unwrap());
+        assert_eq!(
+            val.commission.as_ref().unwrap().rates.rate,
+            "0.05".parse().unwrap()
+        );
+        assert!(res.next.is_none());
+    }
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum StakingError {
+    #[error("Cannot delegate 0 amount of a token")]
+    EmptyAmount,
+
+    #[error("unsupported BondStatus: {i:?}")]
+    BondStatus { i: i32 },
+
+    #[error("validator missing from chain response")]
+    MissingValidator,
+
+    #[error("delegation missing from chain response")]
+    MissingDelegation,
+
+    #[error("unbonding delegation missing from chain response")]
+    MissingUnbondingDelegation,
+
+    #[error("pool missing from chain response")]
+    MissingPool,
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
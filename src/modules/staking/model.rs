@@ -0,0 +1,940 @@
+use std::time::{Duration, SystemTime};
+
+use cosmrs::crypto::PublicKey;
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    BondStatus as ProtoBondStatus, Commission as ProtoCommission,
+    CommissionRates as ProtoCommissionRates, DelegationResponse as ProtoDelegationResponse,
+    Description as ProtoDescription, MsgBeginRedelegate, MsgDelegate, MsgUndelegate,
+    Params as ProtoParams, Pool as ProtoPool, RedelegationResponse as ProtoRedelegationResponse,
+    UnbondingDelegation as ProtoUnbondingDelegation,
+    UnbondingDelegationEntry as ProtoUnbondingDelegationEntry, Validator as ProtoValidator,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{parse_amount, Denom};
+use crate::chain::decimal::Decimal;
+use crate::chain::msg::Msg;
+use crate::{
+    chain::{
+        coin::Coin, error::ChainError, request::PaginationResponse, response::ChainTxResponse,
+    },
+    modules::auth::model::Address,
+};
+
+use super::error::StakingError;
+use super::proto::{MsgCreateValidator, MsgEditValidator};
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum BondStatus {
+    /// BOND_STATUS_UNSPECIFIED defines an invalid validator status
+    Unspecified = 0,
+    /// BOND_STATUS_UNBONDED validator is not in the active set and has no tokens locked in the bonded pool
+    Unbonded = 1,
+    /// BOND_STATUS_UNBONDING validator left the active set and its delegations are being unbonded
+    Unbonding = 2,
+    /// BOND_STATUS_BONDED validator is in the active set and participating in consensus
+    Bonded = 3,
+}
+
+impl AsRef<str> for BondStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            BondStatus::Unspecified => "BOND_STATUS_UNSPECIFIED",
+            BondStatus::Unbonded => "BOND_STATUS_UNBONDED",
+            BondStatus::Unbonding => "BOND_STATUS_UNBONDING",
+            BondStatus::Bonded => "BOND_STATUS_BONDED",
+        }
+    }
+}
+
+impl TryFrom<i32> for BondStatus {
+    type Error = StakingError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == BondStatus::Unspecified as i32 => Ok(BondStatus::Unspecified),
+            x if x == BondStatus::Unbonded as i32 => Ok(BondStatus::Unbonded),
+            x if x == BondStatus::Unbonding as i32 => Ok(BondStatus::Unbonding),
+            x if x == BondStatus::Bonded as i32 => Ok(BondStatus::Bonded),
+            _ => Err(StakingError::BondStatus { i: v }),
+        }
+    }
+}
+
+impl From<BondStatus> for ProtoBondStatus {
+    fn from(status: BondStatus) -> Self {
+        match status {
+            BondStatus::Unspecified => ProtoBondStatus::Unspecified,
+            BondStatus::Unbonded => ProtoBondStatus::Unbonded,
+            BondStatus::Unbonding => ProtoBondStatus::Unbonding,
+            BondStatus::Bonded => ProtoBondStatus::Bonded,
+        }
+    }
+}
+
+impl From<ProtoBondStatus> for BondStatus {
+    fn from(status: ProtoBondStatus) -> Self {
+        match status {
+            ProtoBondStatus::Unspecified => BondStatus::Unspecified,
+            ProtoBondStatus::Unbonded => BondStatus::Unbonded,
+            ProtoBondStatus::Unbonding => BondStatus::Unbonding,
+            ProtoBondStatus::Bonded => BondStatus::Bonded,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Description {
+    /// human-readable name for the validator
+    pub moniker: String,
+
+    /// optional identity signature (ex. UPort or Keybase)
+    pub identity: String,
+
+    pub website: String,
+
+    pub security_contact: String,
+
+    pub details: String,
+}
+
+impl Description {
+    /// Fields set to this value are left unchanged by `staking_edit_validator()`
+    pub const DO_NOT_MODIFY: &'static str = "[do-not-modify]";
+
+    /// Description that leaves every field unchanged when used in an `EditValidatorRequest`
+    pub fn do_not_modify() -> Self {
+        Self {
+            moniker: Self::DO_NOT_MODIFY.to_string(),
+            identity: Self::DO_NOT_MODIFY.to_string(),
+            website: Self::DO_NOT_MODIFY.to_string(),
+            security_contact: Self::DO_NOT_MODIFY.to_string(),
+            details: Self::DO_NOT_MODIFY.to_string(),
+        }
+    }
+}
+
+impl From<ProtoDescription> for Description {
+    fn from(d: ProtoDescription) -> Self {
+        Self {
+            moniker: d.moniker,
+            identity: d.identity,
+            website: d.website,
+            security_contact: d.security_contact,
+            details: d.details,
+        }
+    }
+}
+
+impl From<Description> for ProtoDescription {
+    fn from(d: Description) -> Self {
+        Self {
+            moniker: d.moniker,
+            identity: d.identity,
+            website: d.website,
+            security_contact: d.security_contact,
+            details: d.details,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct CommissionRates {
+    /// the commission rate charged to delegators
+    pub rate: Decimal,
+
+    /// maximum commission rate which the validator can ever charge
+    pub max_rate: Decimal,
+
+    /// maximum daily increase of the validator commission
+    pub max_change_rate: Decimal,
+}
+
+impl TryFrom<ProtoCommissionRates> for CommissionRates {
+    type Error = ChainError;
+
+    fn try_from(rates: ProtoCommissionRates) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rate: Decimal::from_proto(&rates.rate)?,
+            max_rate: Decimal::from_proto(&rates.max_rate)?,
+            max_change_rate: Decimal::from_proto(&rates.max_change_rate)?,
+        })
+    }
+}
+
+impl From<CommissionRates> for ProtoCommissionRates {
+    fn from(rates: CommissionRates) -> Self {
+        Self {
+            rate: rates.rate.to_proto(),
+            max_rate: rates.max_rate.to_proto(),
+            max_change_rate: rates.max_change_rate.to_proto(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Commission {
+    pub rates: CommissionRates,
+
+    /// the last time the commission rate was changed
+    pub update_time: Option<SystemTime>,
+}
+
+impl TryFrom<ProtoCommission> for Commission {
+    type Error = ChainError;
+
+    fn try_from(c: ProtoCommission) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rates: c
+                .commission_rates
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            update_time: c
+                .update_time
+                .map(SystemTime::try_from)
+                .transpose()
+                .map_err(ChainError::proto_time)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Validator {
+    /// the validator operator address (ie: "cosmosvaloper1...")
+    pub operator_address: Address,
+
+    pub consensus_pubkey: Option<PublicKey>,
+
+    /// whether the validator has been jailed from the active set
+    pub jailed: bool,
+
+    pub status: BondStatus,
+
+    /// amount of bonded tokens held by the validator
+    pub tokens: u128,
+
+    /// total shares issued to the validator's delegators
+    pub delegator_shares: Decimal,
+
+    pub description: Description,
+
+    /// height at which this validator has begun unbonding
+    pub unbonding_height: u64,
+
+    /// minimum time at which unbonding will complete
+    pub unbonding_time: Option<SystemTime>,
+
+    pub commission: Option<Commission>,
+
+    /// the minimum self delegation the validator has committed to
+    pub min_self_delegation: u128,
+}
+
+impl TryFrom<ProtoValidator> for Validator {
+    type Error = StakingError;
+
+    fn try_from(val: ProtoValidator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operator_address: val.operator_address.parse()?,
+            consensus_pubkey: val
+                .consensus_pubkey
+                .map(PublicKey::try_from)
+                .transpose()
+                .map_err(ChainError::crypto)?,
+            jailed: val.jailed,
+            status: val.status.try_into()?,
+            tokens: parse_amount(&val.tokens)?,
+            delegator_shares: Decimal::from_proto(&val.delegator_shares)?,
+            description: val.description.map(Into::into).unwrap_or_default(),
+            unbonding_height: val.unbonding_height as u64,
+            unbonding_time: val
+                .unbonding_time
+                .map(SystemTime::try_from)
+                .transpose()
+                .map_err(ChainError::proto_time)?,
+            commission: val.commission.map(TryInto::try_into).transpose()?,
+            min_self_delegation: parse_amount(&val.min_self_delegation)?,
+        })
+    }
+}
+
+/// Delegation of tokens from a delegator to a validator, along with its current balance
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Delegation {
+    pub delegator_address: Address,
+    pub validator_address: Address,
+    pub shares: Decimal,
+    pub balance: Option<Coin>,
+}
+
+impl TryFrom<ProtoDelegationResponse> for Delegation {
+    type Error = StakingError;
+
+    fn try_from(res: ProtoDelegationResponse) -> Result<Self, Self::Error> {
+        let delegation = res.delegation.ok_or(StakingError::MissingDelegation)?;
+
+        Ok(Self {
+            delegator_address: delegation.delegator_address.parse()?,
+            validator_address: delegation.validator_address.parse()?,
+            shares: Decimal::from_proto(&delegation.shares)?,
+            balance: res.balance.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UnbondingDelegation {
+    pub delegator_address: Address,
+    pub validator_address: Address,
+    pub entries: Vec<UnbondingDelegationEntry>,
+}
+
+impl TryFrom<ProtoUnbondingDelegation> for UnbondingDelegation {
+    type Error = StakingError;
+
+    fn try_from(ubd: ProtoUnbondingDelegation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: ubd.delegator_address.parse()?,
+            validator_address: ubd.validator_address.parse()?,
+            entries: ubd
+                .entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct UnbondingDelegationEntry {
+    /// height at which the unbonding took place
+    pub creation_height: u64,
+
+    /// time at which the unbonding delegation will complete
+    pub completion_time: Option<SystemTime>,
+
+    /// tokens initially scheduled to receive at completion
+    pub initial_balance: u128,
+
+    /// tokens to receive at completion
+    pub balance: u128,
+}
+
+impl TryFrom<ProtoUnbondingDelegationEntry> for UnbondingDelegationEntry {
+    type Error = ChainError;
+
+    fn try_from(entry: ProtoUnbondingDelegationEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            creation_height: entry.creation_height as u64,
+            completion_time: entry
+                .completion_time
+                .map(SystemTime::try_from)
+                .transpose()
+                .map_err(ChainError::proto_time)?,
+            initial_balance: parse_amount(&entry.initial_balance)?,
+            balance: parse_amount(&entry.balance)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Redelegation {
+    pub delegator_address: Address,
+    pub validator_src_address: Address,
+    pub validator_dst_address: Address,
+    pub entries: Vec<RedelegationEntry>,
+}
+
+impl TryFrom<ProtoRedelegationResponse> for Redelegation {
+    type Error = StakingError;
+
+    fn try_from(res: ProtoRedelegationResponse) -> Result<Self, Self::Error> {
+        let red = res.redelegation.ok_or(StakingError::MissingDelegation)?;
+
+        Ok(Self {
+            delegator_address: red.delegator_address.parse()?,
+            validator_src_address: red.validator_src_address.parse()?,
+            validator_dst_address: red.validator_dst_address.parse()?,
+            entries: res
+                .entries
+                .into_iter()
+                .map(|e| {
+                    let entry = e.redelegation_entry.unwrap_or_default();
+
+                    Ok(RedelegationEntry {
+                        creation_height: entry.creation_height as u64,
+                        completion_time: entry
+                            .completion_time
+                            .map(SystemTime::try_from)
+                            .transpose()
+                            .map_err(ChainError::proto_time)?,
+                        initial_balance: parse_amount(&entry.initial_balance)?,
+                        shares_dst: Decimal::from_proto(&entry.shares_dst)?,
+                        balance: parse_amount(&e.balance)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, ChainError>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct RedelegationEntry {
+    /// height at which the redelegation took place
+    pub creation_height: u64,
+
+    /// time at which the redelegation will complete
+    pub completion_time: Option<SystemTime>,
+
+    /// initial balance when redelegation started
+    pub initial_balance: u128,
+
+    /// amount of destination-validator shares created by redelegation
+    pub shares_dst: Decimal,
+
+    /// current balance of the redelegation
+    pub balance: u128,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Pool {
+    pub not_bonded_tokens: u128,
+    pub bonded_tokens: u128,
+}
+
+impl TryFrom<ProtoPool> for Pool {
+    type Error = ChainError;
+
+    fn try_from(pool: ProtoPool) -> Result<Self, Self::Error> {
+        Ok(Self {
+            not_bonded_tokens: parse_amount(&pool.not_bonded_tokens)?,
+            bonded_tokens: parse_amount(&pool.bonded_tokens)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    /// time duration of unbonding
+    pub unbonding_time: Option<Duration>,
+
+    /// maximum number of validators in the active set
+    pub max_validators: u32,
+
+    /// max entries for either unbonding delegation or redelegation (per pair/trio)
+    pub max_entries: u32,
+
+    /// number of historical entries to persist
+    pub historical_entries: u32,
+
+    /// the staking token denom
+    pub bond_denom: Denom,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unbonding_time: p
+                .unbonding_time
+                .map(Duration::try_from)
+                .transpose()
+                .map_err(ChainError::proto_time)?,
+            max_validators: p.max_validators,
+            max_entries: p.max_entries,
+            historical_entries: p.historical_entries,
+            bond_denom: p.bond_denom.parse()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ValidatorResponse {
+    pub validator: Validator,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ValidatorsResponse {
+    pub validators: Vec<Validator>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegationResponse {
+    pub delegation: Delegation,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegationsResponse {
+    pub delegations: Vec<Delegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UnbondingDelegationResponse {
+    pub unbonding_delegation: UnbondingDelegation,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UnbondingDelegationsResponse {
+    pub unbonding_delegations: Vec<UnbondingDelegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RedelegationsResponse {
+    pub redelegations: Vec<Redelegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PoolResponse {
+    pub pool: Pool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+/// Delegate `amount` of tokens from `delegator_address` to `validator_address`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegateRequest {
+    pub delegator_address: Address,
+    pub validator_address: Address,
+    pub amount: Coin,
+}
+
+pub type DelegateRequestProto = DelegateRequest;
+
+impl Msg for DelegateRequestProto {
+    type Proto = MsgDelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgDelegate> for DelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgDelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: msg.delegator_address.parse()?,
+            validator_address: msg.validator_address.parse()?,
+            amount: msg.amount.ok_or(StakingError::EmptyAmount)?.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<DelegateRequest> for MsgDelegate {
+    type Error = StakingError;
+
+    fn try_from(req: DelegateRequest) -> Result<Self, Self::Error> {
+        if req.amount.amount == 0 {
+            return Err(StakingError::EmptyAmount);
+        }
+
+        Ok(Self {
+            delegator_address: req.delegator_address.into(),
+            validator_address: req.validator_address.into(),
+            amount: Some(req.amount.into()),
+        })
+    }
+}
+
+/// Undelegate `amount` of tokens that `delegator_address` has delegated to `validator_address`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UndelegateRequest {
+    pub delegator_address: Address,
+    pub validator_address: Address,
+    pub amount: Coin,
+}
+
+pub type UndelegateRequestProto = UndelegateRequest;
+
+impl Msg for UndelegateRequestProto {
+    type Proto = MsgUndelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgUndelegate> for UndelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgUndelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: msg.delegator_address.parse()?,
+            validator_address: msg.validator_address.parse()?,
+            amount: msg.amount.ok_or(StakingError::EmptyAmount)?.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<UndelegateRequest> for MsgUndelegate {
+    type Error = StakingError;
+
+    fn try_from(req: UndelegateRequest) -> Result<Self, Self::Error> {
+        if req.amount.amount == 0 {
+            return Err(StakingError::EmptyAmount);
+        }
+
+        Ok(Self {
+            delegator_address: req.delegator_address.into(),
+            validator_address: req.validator_address.into(),
+            amount: Some(req.amount.into()),
+        })
+    }
+}
+
+/// Move `amount` of delegated tokens from `validator_src_address` to `validator_dst_address`
+/// without waiting for the unbonding period
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RedelegateRequest {
+    pub delegator_address: Address,
+    pub validator_src_address: Address,
+    pub validator_dst_address: Address,
+    pub amount: Coin,
+}
+
+pub type RedelegateRequestProto = RedelegateRequest;
+
+impl Msg for RedelegateRequestProto {
+    type Proto = MsgBeginRedelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgBeginRedelegate> for RedelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgBeginRedelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: msg.delegator_address.parse()?,
+            validator_src_address: msg.validator_src_address.parse()?,
+            validator_dst_address: msg.validator_dst_address.parse()?,
+            amount: msg.amount.ok_or(StakingError::EmptyAmount)?.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<RedelegateRequest> for MsgBeginRedelegate {
+    type Error = StakingError;
+
+    fn try_from(req: RedelegateRequest) -> Result<Self, Self::Error> {
+        if req.amount.amount == 0 {
+            return Err(StakingError::EmptyAmount);
+        }
+
+        Ok(Self {
+            delegator_address: req.delegator_address.into(),
+            validator_src_address: req.validator_src_address.into(),
+            validator_dst_address: req.validator_dst_address.into(),
+            amount: Some(req.amount.into()),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateValidatorRequest {
+    pub description: Description,
+    pub commission: CommissionRates,
+    pub min_self_delegation: u128,
+
+    /// account address of the validator operator
+    pub delegator_address: Address,
+
+    /// validator operator address (ie: "cosmosvaloper1...")
+    pub validator_address: Address,
+
+    /// tendermint consensus public key of the validator node
+    pub pubkey: PublicKey,
+
+    /// initial self delegation
+    pub value: Coin,
+}
+
+pub type CreateValidatorRequestProto = CreateValidatorRequest;
+
+impl Msg for CreateValidatorRequestProto {
+    type Proto = MsgCreateValidator;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgCreateValidator> for CreateValidatorRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgCreateValidator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: msg.description.map(Into::into).unwrap_or_default(),
+            commission: msg
+                .commission
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            min_self_delegation: parse_amount(&msg.min_self_delegation)?,
+            delegator_address: msg.delegator_address.parse()?,
+            validator_address: msg.validator_address.parse()?,
+            pubkey: msg
+                .pubkey
+                .ok_or(StakingError::MissingValidator)?
+                .try_into()
+                .map_err(ChainError::crypto)?,
+            value: msg.value.ok_or(StakingError::EmptyAmount)?.try_into()?,
+        })
+    }
+}
+
+impl TryFrom<CreateValidatorRequest> for MsgCreateValidator {
+    type Error = StakingError;
+
+    fn try_from(req: CreateValidatorRequest) -> Result<Self, Self::Error> {
+        if req.value.amount == 0 {
+            return Err(StakingError::EmptyAmount);
+        }
+
+        Ok(Self {
+            description: Some(req.description.into()),
+            commission: Some(req.commission.into()),
+            min_self_delegation: req.min_self_delegation.to_string(),
+            delegator_address: req.delegator_address.into(),
+            validator_address: req.validator_address.into(),
+            pubkey: Some(req.pubkey.into()),
+            value: Some(req.value.into()),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct EditValidatorRequest {
+    /// Use `Description::DO_NOT_MODIFY` for any fields that should be left unchanged
+    pub description: Description,
+
+    /// validator operator address (ie: "cosmosvaloper1...")
+    pub validator_address: Address,
+
+    /// New commission rate, or `None` to leave it unchanged
+    pub commission_rate: Option<Decimal>,
+
+    /// New minimum self delegation, or `None` to leave it unchanged
+    pub min_self_delegation: Option<u128>,
+}
+
+pub type EditValidatorRequestProto = EditValidatorRequest;
+
+impl Msg for EditValidatorRequestProto {
+    type Proto = MsgEditValidator;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgEditValidator> for EditValidatorRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgEditValidator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: msg.description.map(Into::into).unwrap_or_default(),
+            validator_address: msg.validator_address.parse()?,
+            commission_rate: if msg.commission_rate.is_empty() {
+                None
+            } else {
+                Some(Decimal::from_proto(&msg.commission_rate)?)
+            },
+            min_self_delegation: if msg.min_self_delegation.is_empty() {
+                None
+            } else {
+                Some(parse_amount(&msg.min_self_delegation)?)
+            },
+        })
+    }
+}
+
+impl TryFrom<EditValidatorRequest> for MsgEditValidator {
+    type Error = StakingError;
+
+    fn try_from(req: EditValidatorRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            description: Some(req.description.into()),
+            validator_address: req.validator_address.into(),
+            commission_rate: req
+                .commission_rate
+                .map(Decimal::to_proto)
+                .unwrap_or_default(),
+            min_self_delegation: req
+                .min_self_delegation
+                .map(|m| m.to_string())
+                .unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DelegateResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UndelegateResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RedelegateResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CreateValidatorResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct EditValidatorResponse {
+    pub res: ChainTxResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::staking::v1beta1::{
+        MsgCreateValidator as SdkMsgCreateValidator, MsgEditValidator as SdkMsgEditValidator,
+    };
+    use cosmrs::proto::traits::Message;
+
+    use crate::chain::{coin::Coin, decimal::Decimal, msg::Msg};
+    use crate::modules::auth::model::Address;
+
+    use super::{
+        CommissionRates, CreateValidatorRequest, Description, EditValidatorRequest,
+        RedelegateRequest,
+    };
+
+    #[test]
+    fn test_msg_round_trip() {
+        let req = RedelegateRequest {
+            delegator_address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            validator_src_address: Address::new("junovaloper", &[7; 20]).unwrap(),
+            validator_dst_address: Address::new("junovaloper", &[8; 20]).unwrap(),
+            amount: Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            },
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.staking.v1beta1.MsgBeginRedelegate");
+        assert_eq!(RedelegateRequest::from_any(&any).unwrap(), req);
+
+        let req = CreateValidatorRequest {
+            description: Description {
+                moniker: "validator".to_string(),
+                website: "https://example.com".to_string(),
+                ..Default::default()
+            },
+            commission: CommissionRates {
+                rate: "0.1".parse().unwrap(),
+                max_rate: "0.2".parse().unwrap(),
+                max_change_rate: "0.01".parse().unwrap(),
+            },
+            min_self_delegation: 1,
+            delegator_address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            validator_address: Address::new("junovaloper", &[7; 20]).unwrap(),
+            pubkey: cosmrs::tendermint::PublicKey::from_raw_ed25519(&[1; 32])
+                .unwrap()
+                .into(),
+            value: Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            },
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.staking.v1beta1.MsgCreateValidator");
+        assert_eq!(CreateValidatorRequest::from_any(&any).unwrap(), req);
+
+        // the hand written proto is wire compatible with the cosmos-sdk one
+        let msg = SdkMsgCreateValidator::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.description.unwrap().moniker, "validator");
+        assert_eq!(msg.commission.unwrap().rate, "100000000000000000");
+        assert_eq!(msg.min_self_delegation, "1");
+        assert_eq!(
+            msg.delegator_address,
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+        );
+        assert_eq!(
+            msg.pubkey.unwrap().type_url,
+            "/cosmos.crypto.ed25519.PubKey"
+        );
+        assert_eq!(msg.value.unwrap().amount, "10");
+
+        // empty self delegations are rejected before encoding
+        let req = CreateValidatorRequest {
+            value: Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 0,
+            },
+            ..req
+        };
+        assert!(req.to_any().is_err());
+    }
+
+    #[test]
+    fn test_edit_validator_round_trip() {
+        let req = EditValidatorRequest {
+            description: Description::do_not_modify(),
+            validator_address: Address::new("junovaloper", &[7; 20]).unwrap(),
+            commission_rate: None,
+            min_self_delegation: None,
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.staking.v1beta1.MsgEditValidator");
+        assert_eq!(EditValidatorRequest::from_any(&any).unwrap(), req);
+
+        // unchanged fields are sent as the sentinels the cosmos-sdk expects
+        let msg = SdkMsgEditValidator::decode(any.value.as_slice()).unwrap();
+        let description = msg.description.unwrap();
+        assert_eq!(description.moniker, "[do-not-modify]");
+        assert_eq!(description.details, "[do-not-modify]");
+        assert_eq!(msg.commission_rate, "");
+        assert_eq!(msg.min_self_delegation, "");
+
+        let req = EditValidatorRequest {
+            description: Description {
+                moniker: "validator".to_string(),
+                ..Description::do_not_modify()
+            },
+            commission_rate: Some("0.05".parse().unwrap()),
+            min_self_delegation: Some(100),
+            ..req
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(EditValidatorRequest::from_any(&any).unwrap(), req);
+
+        let msg = SdkMsgEditValidator::decode(any.value.as_slice()).unwrap();
+        let description = msg.description.unwrap();
+        assert_eq!(description.moniker, "validator");
+        assert_eq!(description.identity, "[do-not-modify]");
+        assert_eq!(msg.commission_rate, "50000000000000000");
+        assert_eq!(msg.min_self_delegation, "100");
+
+        // a zero commission rate is still a change
+        let req = EditValidatorRequest {
+            commission_rate: Some(Decimal::default()),
+            ..req
+        };
+
+        let any = req.to_any().unwrap();
+        let msg = SdkMsgEditValidator::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.commission_rate, "0");
+        assert_eq!(EditValidatorRequest::from_any(&any).unwrap(), req);
+    }
+}
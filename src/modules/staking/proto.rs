@@ -0,0 +1,52 @@
+//! Staking tx messages that `cosmos-sdk-proto` ships without a `TypeUrl` impl.
+//!
+//! These mirror `cosmos.staking.v1beta1.MsgCreateValidator` and `cosmos.staking.v1beta1.MsgEditValidator` field for field.
+
+use cosmrs::proto::{
+    cosmos::{
+        base::v1beta1::Coin,
+        staking::v1beta1::{CommissionRates, Description},
+    },
+    traits::TypeUrl,
+};
+use cosmrs::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateValidator {
+    #[prost(message, optional, tag = "1")]
+    pub description: Option<Description>,
+    #[prost(message, optional, tag = "2")]
+    pub commission: Option<CommissionRates>,
+    #[prost(string, tag = "3")]
+    pub min_self_delegation: String,
+    #[prost(string, tag = "4")]
+    pub delegator_address: String,
+    #[prost(string, tag = "5")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "6")]
+    pub pubkey: Option<Any>,
+    #[prost(message, optional, tag = "7")]
+    pub value: Option<Coin>,
+}
+
+impl TypeUrl for MsgCreateValidator {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgCreateValidator";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgEditValidator {
+    #[prost(message, optional, tag = "1")]
+    pub description: Option<Description>,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    /// empty string leaves the commission rate unchanged
+    #[prost(string, tag = "3")]
+    pub commission_rate: String,
+    /// empty string leaves the min self delegation unchanged
+    #[prost(string, tag = "4")]
+    pub min_self_delegation: String,
+}
+
+impl TypeUrl for MsgEditValidator {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgEditValidator";
+}
@@ -1,4 +1,8 @@
-use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    GetBlockWithTxsRequest, GetBlockWithTxsResponse, GetTxRequest,
+    GetTxResponse as ProtoGetTxResponse, GetTxsEventRequest,
+    GetTxsEventResponse as ProtoGetTxsEventResponse, TxRaw,
+};
 use cosmrs::tx::Body;
 use cosmrs::tx::SignerInfo;
 use serde::Serialize;
@@ -6,6 +10,7 @@ use serde::Serialize;
 use crate::chain::coin::{Coin, Denom};
 use crate::chain::error::ChainError;
 use crate::chain::msg::Msg;
+use crate::chain::request::PaginationRequest;
 use crate::chain::response::AsyncChainTxResponse;
 use crate::modules::auth::model::{Account, Address};
 use crate::{
@@ -15,12 +20,12 @@ use crate::{
 };
 
 use super::error::TxError;
-use super::model::{BroadcastMode, RawTx};
-
-// TODO: Query endpoints
-// * tx_query_get_tx()
-// * tx_query_get_txs_event()
-// * tx_query_get_block_with_txs()
+use super::model::{
+    BlockWithTxsResponse, BroadcastMode, EventQuery, GetTxResponse, GetTxsEventResponse, OrderBy,
+    PendingTx, RawTx,
+};
+use super::retry::{RetryReason, TxRetry};
+use super::sequence::expected_sequence;
 
 impl<T: CosmosClient> CosmTome<T> {
     pub async fn tx_sign(
@@ -36,48 +41,130 @@ impl<T: CosmosClient> CosmTome<T> {
             key.to_addr(&self.cfg.prefix).await?
         };
 
+        let anys = msgs
+            .iter()
+            .map(|m| m.to_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.tx_sign_any(anys, sender_addr, key, tx_options, self.cfg.gas_adjustment)
+            .await
+    }
+
+    /// Signs msgs that are already encoded as `Any`, simulating the fee with `gas_adjustment`
+    pub(crate) async fn tx_sign_any(
+        &self,
+        anys: Vec<Any>,
+        sender_addr: Address,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        gas_adjustment: f64,
+    ) -> Result<RawTx, TxError> {
         let timeout_height = tx_options.timeout_height.unwrap_or_default();
-        let mut account = self.auth_query_account(sender_addr).await?.account;
+        let mut account = self.auth_query_account(sender_addr.clone()).await?.account;
 
         if let Some(sequence) = &tx_options.sequence {
             account.sequence = *sequence;
         }
 
         // even if the user is supplying their own `Fee`, we will simulate the tx to ensure its valid
-        let sim_fee = self
-            .tx_simulate(
-                msgs.iter()
-                    .map(|m| m.to_any())
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|e| ChainError::ProtoEncoding {
-                        message: e.to_string(),
-                    })?,
-                &account,
-            )
-            .await?;
+        let sim_fee = match self
+            .simulate(anys.clone(), &account, tx_options, gas_adjustment)
+            .await
+        {
+            // the queried sequence lags behind while earlier txs from this key are still in the mempool,
+            // the gas used doesn't depend on it so we simulate again with the sequence the chain expects
+            Err(e) => match expected_sequence(&e) {
+                Some(sequence) => {
+                    let account = Account {
+                        sequence,
+                        ..account.clone()
+                    };
 
-        let fee = if let Some(fee) = &tx_options.fee {
+                    self.simulate(anys.clone(), &account, tx_options, gas_adjustment)
+                        .await?
+                }
+                None => return Err(e),
+            },
+            Ok(fee) => fee,
+        };
+
+        let mut fee = if let Some(fee) = &tx_options.fee {
             fee.clone()
         } else {
             sim_fee
         };
 
+        if fee.granter.is_none() {
+            fee.granter = tx_options.fee_granter.clone();
+        }
+
+        if fee.payer.is_none() {
+            fee.payer = tx_options.fee_payer.clone();
+        }
+
+        // reserved last, so a tx that fails to sign gives its sequence back
+        let sequences = match (tx_options.sequence, &self.sequences) {
+            (None, Some(sequences)) => {
+                account.sequence = sequences.reserve(&sender_addr, account.sequence);
+                Some(sequences)
+            }
+            _ => None,
+        };
+        let sequence = account.sequence;
+
         let raw = key
-            .sign(
-                msgs,
+            .sign_any(
+                anys,
                 timeout_height,
                 &tx_options.memo,
                 account,
                 fee,
                 &self.cfg,
             )
-            .await?;
-        Ok(raw)
+            .await;
+
+        if let (Err(_), Some(sequences)) = (&raw, sequences) {
+            sequences.release(&sender_addr, sequence);
+        }
+
+        Ok(raw?)
     }
 
     // Sends tx with an empty public_key / signature, like they do in the cosmos-sdk:
     // https://github.com/cosmos/cosmos-sdk/blob/main/client/tx/tx.go#L133
     pub async fn tx_simulate<I>(&self, msgs: I, account: &Account) -> Result<Fee, TxError>
+    where
+        I: IntoIterator<Item = Any>,
+    {
+        self.tx_simulate_with_options(msgs, account, &TxOptions::default())
+            .await
+    }
+
+    /// Simulates the tx with the fee granter and payer from `tx_options`,
+    /// returning a `Fee` that carries them.
+    pub async fn tx_simulate_with_options<I>(
+        &self,
+        msgs: I,
+        account: &Account,
+        tx_options: &TxOptions,
+    ) -> Result<Fee, TxError>
+    where
+        I: IntoIterator<Item = Any>,
+    {
+        self.simulate(msgs, account, tx_options, self.cfg.gas_adjustment)
+            .await
+    }
+
+    async fn simulate<I>(
+        &self,
+        msgs: I,
+        account: &Account,
+        tx_options: &TxOptions,
+        gas_adjustment: f64,
+    ) -> Result<Fee, TxError>
     where
         I: IntoIterator<Item = Any>,
     {
@@ -91,8 +178,8 @@ impl<T: CosmosClient> CosmTome<T> {
                 amount: 0u128,
             },
             0u64,
-            None,
-            None,
+            tx_options.fee_payer.clone(),
+            tx_options.fee_granter.clone(),
         );
 
         let auth_info =
@@ -107,13 +194,18 @@ impl<T: CosmosClient> CosmTome<T> {
         let gas_info = self.client.simulate_tx(&tx_raw.into()).await?;
 
         // TODO: clean up this gas conversion code to be clearer
-        let gas_limit = (gas_info.gas_used.value() as f64 * self.cfg.gas_adjustment).ceil();
+        let gas_limit = (gas_info.gas_used.value() as f64 * gas_adjustment).ceil();
         let amount = Coin {
             denom,
             amount: ((gas_limit * self.cfg.gas_price).ceil() as u64).into(),
         };
 
-        let fee = Fee::new(amount, gas_limit as u64, None, None);
+        let fee = Fee::new(
+            amount,
+            gas_limit as u64,
+            tx_options.fee_payer.clone(),
+            tx_options.fee_granter.clone(),
+        );
 
         Ok(fee)
     }
@@ -131,4 +223,736 @@ impl<T: CosmosClient> CosmTome<T> {
     pub async fn tx_broadcast_block(&self, tx: &RawTx) -> Result<ChainTxResponse, TxError> {
         Ok(self.client.broadcast_tx_block(tx).await?)
     }
+
+    /// Polls the chain until the tx with the hex encoded `tx_hash` has been committed in a block.
+    pub async fn tx_wait(&self, tx_hash: &str) -> Result<ChainTxResponse, TxError> {
+        Ok(self.client.wait_for_tx(tx_hash).await?)
+    }
+
+    /// Signs and broadcasts `msgs` without waiting for the tx to be committed, returning a `PendingTx`
+    /// that `decode`s the tx into a module response once it is.
+    ///
+    /// Broadcasts that fail for an account sequence mismatch or lack of gas are retried
+    /// according to the `RetryPolicy` and `SequenceManager`.
+    pub(crate) async fn tx_broadcast_pending<M, R, E>(
+        &self,
+        msgs: Vec<M>,
+        sender_addr: Address,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+        decode: fn(ChainTxResponse) -> Result<R, E>,
+    ) -> Result<PendingTx<R, E>, TxError>
+    where
+        M: Msg + Serialize,
+        E: From<TxError>,
+    {
+        let anys = msgs
+            .iter()
+            .map(|m| m.to_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        let mut retry = TxRetry::new(anys, sender_addr, tx_options, mode, self.cfg.gas_adjustment);
+
+        let res = self.tx_broadcast_retrying(&mut retry, key).await?;
+
+        Ok(PendingTx::new(res, decode, retry))
+    }
+
+    /// Signs and broadcasts the tx in `retry` until it passes `CheckTx` or can't be retried anymore
+    pub(crate) async fn tx_broadcast_retrying(
+        &self,
+        retry: &mut TxRetry,
+        key: &SigningKey,
+    ) -> Result<AsyncChainTxResponse, TxError> {
+        loop {
+            let tx_raw = self
+                .tx_sign_any(
+                    retry.msgs.clone(),
+                    retry.sender_addr.clone(),
+                    key,
+                    &retry.tx_options,
+                    retry.gas_adjustment,
+                )
+                .await?;
+
+            match self.tx_broadcast(&tx_raw, retry.mode).await {
+                Ok(res) => return Ok(res),
+                Err(e) => self.tx_prepare_retry(retry, e, true)?,
+            }
+        }
+    }
+
+    /// Records the failed attempt `e` and adjusts `retry` so the next attempt can succeed,
+    /// or returns the error to give up with.
+    /// `rejected` is true if the tx never made it into a block, so its sequence is still unused.
+    pub(crate) fn tx_prepare_retry(
+        &self,
+        retry: &mut TxRetry,
+        e: TxError,
+        rejected: bool,
+    ) -> Result<(), TxError> {
+        let reason = RetryReason::of(&e);
+        let policy_retries = self.retry.map(|p| p.max_retries).unwrap_or_default();
+
+        let max_retries = match (&reason, &self.sequences) {
+            (Some(RetryReason::OutOfGas), _) if retry.tx_options.fee.is_none() => policy_retries,
+
+            (Some(RetryReason::SequenceMismatch { .. }), Some(sequences))
+                if !retry.user_sequence =>
+            {
+                policy_retries.max(sequences.options.max_retries)
+            }
+
+            (Some(RetryReason::SequenceMismatch { .. }), None) if !retry.user_sequence => {
+                policy_retries
+            }
+
+            _ => 0,
+        };
+
+        if let (false, Some(sequences)) = (retry.user_sequence, &self.sequences) {
+            if rejected || matches!(reason, Some(RetryReason::SequenceMismatch { .. })) {
+                sequences.on_broadcast_error(&retry.sender_addr, &e);
+            }
+        }
+
+        // a tx included in a block used up its sequence, so one pinned by an earlier mismatch retry is stale
+        if !rejected && !retry.user_sequence {
+            retry.tx_options.sequence = None;
+        }
+
+        retry.attempts.push(e);
+
+        if retry.attempts.len() as u32 > max_retries {
+            return Err(retry.give_up());
+        }
+
+        match reason {
+            Some(RetryReason::OutOfGas) => {
+                retry.gas_adjustment += self.retry.unwrap_or_default().gas_adjustment_step;
+            }
+            // the sequence manager was already resynced above
+            Some(RetryReason::SequenceMismatch { expected }) if self.sequences.is_none() => {
+                retry.tx_options.sequence = expected;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Query a committed tx by its hex encoded `tx_hash`
+    pub async fn tx_query_get_tx(&self, tx_hash: String) -> Result<GetTxResponse, TxError> {
+        let req = GetTxRequest { hash: tx_hash };
+
+        let res = self
+            .client
+            .query::<_, ProtoGetTxResponse>(req, "/cosmos.tx.v1beta1.Service/GetTx")
+            .await?;
+
+        res.try_into()
+    }
+
+    /// Search for committed txs matching all of the `query` event conditions
+    pub async fn tx_query_get_txs_event(
+        &self,
+        query: EventQuery,
+        order_by: OrderBy,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GetTxsEventResponse, TxError> {
+        let req = GetTxsEventRequest {
+            events: query.into(),
+            pagination: pagination.map(Into::into),
+            order_by: order_by as i32,
+        };
+
+        let res = self
+            .client
+            .query::<_, ProtoGetTxsEventResponse>(req, "/cosmos.tx.v1beta1.Service/GetTxsEvent")
+            .await?;
+
+        res.try_into()
+    }
+
+    /// Query the block at `block_height` along with all of its decoded txs
+    pub async fn tx_query_get_block_with_txs(
+        &self,
+        block_height: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<BlockWithTxsResponse, TxError> {
+        let req = GetBlockWithTxsRequest {
+            height: block_height as i64,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, GetBlockWithTxsResponse>(req, "/cosmos.tx.v1beta1.Service/GetBlockWithTxs")
+            .await?;
+
+        res.try_into()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use cosmrs::proto::cosmos::auth::v1beta1::{
+        BaseAccount, QueryAccountRequest, QueryAccountResponse,
+    };
+    use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+    use cosmrs::proto::cosmos::tx::v1beta1::{
+        AuthInfo, Fee, GetTxsEventRequest, GetTxsEventResponse, Tx, TxBody, TxRaw,
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            error::{ChainError, SdkError},
+            fee::{self, GasInfo},
+            request::TxOptions,
+            response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::{
+            bank::{error::BankError, model::SendRequest},
+            tx::{
+                error::TxError,
+                model::{BroadcastMode, EventQuery, OrderBy, RawTx},
+                retry::RetryPolicy,
+                sequence::SequenceOptions,
+            },
+        },
+        signing_key::key::SigningKey,
+    };
+
+    fn cfg() -> ChainConfig {
+        ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        }
+    }
+
+    fn send_request() -> SendRequest {
+        SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            }],
+        }
+    }
+
+    // the chain always reports sequence 5 and 100 gas used for every simulation
+    fn mock_account_and_simulation(mock_client: &mut MockCosmosClient) {
+        mock_account(mock_client);
+
+        mock_client.expect_simulate_tx().returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+    }
+
+    fn mock_account(mock_client: &mut MockCosmosClient) {
+        mock_account_sequence(mock_client, Arc::new(Mutex::new(5)));
+    }
+
+    // the chain reports the account's current `sequence`
+    fn mock_account_sequence(mock_client: &mut MockCosmosClient, sequence: Arc<Mutex<u64>>) {
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .returning(move |_, t: &str| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: t.to_owned(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: *sequence.lock().unwrap(),
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+    }
+
+    fn signed_auth_info(tx: &RawTx) -> AuthInfo {
+        let tx: TxRaw = tx.clone().into();
+
+        AuthInfo::decode(tx.auth_info_bytes.as_slice()).unwrap()
+    }
+
+    fn sdk_error(code: u32, log: &str) -> ChainError {
+        ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(code),
+                data: None,
+                log: log.to_string(),
+                codespace: "sdk".to_string(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fee_granter() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+        let granter = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string();
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account(&mut mock_client);
+
+        let expected = granter.clone();
+        mock_client
+            .expect_simulate_tx()
+            .times(1)
+            .returning(move |tx| {
+                assert_eq!(signed_auth_info(tx).fee.unwrap().granter, expected);
+
+                Ok(GasInfo {
+                    gas_wanted: 200u16.into(),
+                    gas_used: 100u16.into(),
+                })
+            });
+
+        let expected = granter.clone();
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(move |tx, _| {
+                let fee = signed_auth_info(tx).fee.unwrap();
+                assert_eq!(fee.granter, expected);
+                assert_eq!(fee.gas_limit, 150);
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client);
+
+        let tx_options = TxOptions {
+            fee_granter: Some(granter.parse().unwrap()),
+            ..Default::default()
+        };
+
+        cosm_tome
+            .bank_send_batch_async(vec![send_request()], &key, &tx_options, BroadcastMode::Sync)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fee_granter_user_fee() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account_and_simulation(&mut mock_client);
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(1)
+            .returning(|tx, _| {
+                let fee = signed_auth_info(tx).fee.unwrap();
+                assert_eq!(fee.granter, "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg");
+                assert_eq!(fee.gas_limit, 500);
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client);
+
+        // the granter of the user supplied fee wins over `TxOptions::fee_granter`
+        let tx_options = TxOptions {
+            fee: Some(fee::Fee::new(
+                Coin {
+                    denom: "utest".parse().unwrap(),
+                    amount: 50,
+                },
+                500u64,
+                None,
+                Some(
+                    "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                        .parse()
+                        .unwrap(),
+                ),
+            )),
+            fee_granter: Some(
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+            ),
+            ..Default::default()
+        };
+
+        cosm_tome
+            .bank_send_batch_async(vec![send_request()], &key, &tx_options, BroadcastMode::Sync)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_retry_sequence_mismatch() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account_and_simulation(&mut mock_client);
+
+        let sequences = Arc::new(Mutex::new(vec![]));
+        let signed = sequences.clone();
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(2)
+            .returning(move |tx, _| {
+                let mut signed = signed.lock().unwrap();
+                signed.push(signed_auth_info(tx).signer_infos[0].sequence);
+
+                if signed.len() == 1 {
+                    return Err(sdk_error(
+                        32,
+                        "account sequence mismatch, expected 7, got 5: incorrect account sequence",
+                    ));
+                }
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client).with_retry_policy(RetryPolicy::default());
+
+        let res = cosm_tome
+            .bank_send_batch_async(
+                vec![send_request()],
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Sync,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash(), "TX_HASH_0");
+        assert_eq!(*sequences.lock().unwrap(), vec![5, 7]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exhausted() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account_and_simulation(&mut mock_client);
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(3)
+            .returning(|tx, _| {
+                let sequence = signed_auth_info(tx).signer_infos[0].sequence;
+
+                Err(sdk_error(
+                    32,
+                    &format!("account sequence mismatch, expected {}, got {sequence}: incorrect account sequence", sequence + 2),
+                ))
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client).with_retry_policy(RetryPolicy {
+            max_retries: 2,
+            ..Default::default()
+        });
+
+        let err = cosm_tome
+            .bank_send_batch_async(
+                vec![send_request()],
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Sync,
+            )
+            .await
+            .err()
+            .unwrap();
+
+        let BankError::TxError(TxError::Retries { attempts }) = err else {
+            panic!("expected retries error, got {err:?}");
+        };
+
+        assert_eq!(
+            attempts.iter().map(TxError::sdk_error).collect::<Vec<_>>(),
+            vec![
+                Some(SdkError::WrongSequence { expected: Some(7) }),
+                Some(SdkError::WrongSequence { expected: Some(9) }),
+                Some(SdkError::WrongSequence { expected: Some(11) }),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_out_of_gas() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account_and_simulation(&mut mock_client);
+
+        let gas_limits = Arc::new(Mutex::new(vec![]));
+        let signed = gas_limits.clone();
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(2)
+            .returning(move |tx, _| {
+                let mut signed = signed.lock().unwrap();
+                signed.push(signed_auth_info(tx).fee.unwrap().gas_limit);
+
+                if signed.len() == 1 {
+                    return Err(sdk_error(
+                        11,
+                        "out of gas in location: WritePerByte; gasWanted: 150, gasUsed: 180: out of gas",
+                    ));
+                }
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: "TX_HASH_0".to_string(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client).with_retry_policy(RetryPolicy {
+            max_retries: 1,
+            gas_adjustment_step: 0.5,
+        });
+
+        cosm_tome
+            .bank_send_batch_async(
+                vec![send_request()],
+                &key,
+                &TxOptions::default(),
+                BroadcastMode::Sync,
+            )
+            .await
+            .unwrap();
+
+        // simulated 100 gas * `gas_adjustment` 1.5, then 100 * (1.5 + 0.5)
+        assert_eq!(*gas_limits.lock().unwrap(), vec![150, 200]);
+    }
+
+    #[tokio::test]
+    async fn test_retry_out_of_gas_after_sequence_mismatch() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let chain_sequence = Arc::new(Mutex::new(5));
+        mock_account_sequence(&mut mock_client, chain_sequence.clone());
+
+        mock_client.expect_simulate_tx().returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let sequences = Arc::new(Mutex::new(vec![]));
+        let signed = sequences.clone();
+
+        mock_client
+            .expect_broadcast_tx()
+            .times(3)
+            .returning(move |tx, _| {
+                let mut signed = signed.lock().unwrap();
+                signed.push(signed_auth_info(tx).signer_infos[0].sequence);
+
+                if signed.len() == 1 {
+                    return Err(sdk_error(
+                        32,
+                        "account sequence mismatch, expected 7, got 5: incorrect account sequence",
+                    ));
+                }
+
+                Ok(AsyncChainTxResponse {
+                    res: ChainResponse::default(),
+                    tx_hash: format!("TX_HASH_{}", signed.len()),
+                })
+            });
+
+        // the re-signed tx is included in a block but runs out of gas, using up sequence 7
+        mock_client
+            .expect_wait_for_tx()
+            .times(2)
+            .returning(move |tx_hash| {
+                if tx_hash == "TX_HASH_2" {
+                    *chain_sequence.lock().unwrap() = 8;
+
+                    return Err(sdk_error(
+                        11,
+                        "out of gas in location: WritePerByte; gasWanted: 150, gasUsed: 180: out of gas",
+                    ));
+                }
+
+                Ok(ChainTxResponse {
+                    tx_hash: tx_hash.to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg(), mock_client).with_retry_policy(RetryPolicy::default());
+
+        let res = cosm_tome
+            .bank_send_batch(vec![send_request()], &key, &TxOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_3");
+        assert_eq!(*sequences.lock().unwrap(), vec![5, 7, 8]);
+    }
+
+    #[tokio::test]
+    async fn test_sign_error_releases_sequence() {
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg().derivation_path);
+
+        let mut mock_client = MockCosmosClient::new();
+        mock_account_and_simulation(&mut mock_client);
+
+        let cosm_tome =
+            CosmTome::new(cfg(), mock_client).with_sequence_manager(SequenceOptions::default());
+
+        // tendermint heights must fit in an i64, so signing fails after the sequence is reserved
+        cosm_tome
+            .tx_sign(
+                vec![send_request()],
+                None,
+                &key,
+                &TxOptions {
+                    timeout_height: Some(u64::MAX),
+                    ..Default::default()
+                },
+            )
+            .await
+            .err()
+            .unwrap();
+
+        let tx = cosm_tome
+            .tx_sign(vec![send_request()], None, &key, &TxOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed_auth_info(&tx).signer_infos[0].sequence, 5);
+
+        let tx = cosm_tome
+            .tx_sign(vec![send_request()], None, &key, &TxOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(signed_auth_info(&tx).signer_infos[0].sequence, 6);
+    }
+
+    #[tokio::test]
+    async fn test_tx_query_get_txs_event() {
+        let cfg = ChainConfig {
+            denom: "utest".to_string(),
+            prefix: "test".to_string(),
+            chain_id: "test-1".to_string(),
+            derivation_path: "m/44'/118'/0'/0/0".to_string(),
+            rpc_endpoint: None,
+            grpc_endpoint: None,
+            rest_endpoint: None,
+            gas_price: 0.1,
+            gas_adjustment: 1.5,
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<GetTxsEventRequest, GetTxsEventResponse>()
+            .times(1)
+            .returning(move |req, _| {
+                assert_eq!(
+                    req.events,
+                    vec![
+                        "message.sender=juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                        "tx.height=1337".to_string()
+                    ]
+                );
+                assert_eq!(req.order_by, OrderBy::Desc as i32);
+
+                Ok(GetTxsEventResponse {
+                    txs: vec![Tx {
+                        body: Some(TxBody {
+                            memo: "memo".to_string(),
+                            ..Default::default()
+                        }),
+                        auth_info: Some(AuthInfo {
+                            signer_infos: vec![],
+                            fee: Some(Fee {
+                                gas_limit: 200,
+                                ..Default::default()
+                            }),
+                        }),
+                        signatures: vec![vec![1, 2, 3]],
+                    }],
+                    tx_responses: vec![TxResponse {
+                        height: 1337,
+                        txhash: "TX_HASH_0".to_string(),
+                        gas_wanted: 200,
+                        gas_used: 100,
+                        timestamp: "2022-10-10T00:00:00Z".to_string(),
+                        ..Default::default()
+                    }],
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let query = EventQuery::new()
+            .message_sender(
+                &"juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+            )
+            .height(1337);
+
+        let res = cosm_tome
+            .tx_query_get_txs_event(query, OrderBy::Desc, None)
+            .await
+            .unwrap();
+
+        assert_eq!(res.txs.len(), 1);
+
+        let tx = &res.txs[0];
+        assert_eq!(tx.res.res.code, Code::Ok);
+        assert_eq!(tx.res.tx_hash, "TX_HASH_0");
+        assert_eq!(tx.res.height, 1337);
+        assert_eq!(tx.timestamp, "2022-10-10T00:00:00Z");
+
+        let decoded = tx.tx.as_ref().unwrap();
+        assert_eq!(decoded.body.memo, "memo");
+        assert_eq!(decoded.auth_info.fee.gas_limit.value(), 200);
+    }
 }
@@ -1,15 +1,47 @@
 use thiserror::Error;
 
-use crate::{chain::error::ChainError, modules::auth::error::AccountError};
+use crate::{
+    chain::error::{ChainError, SdkError},
+    modules::auth::error::AccountError,
+};
 
 #[derive(Error, Debug)]
 pub enum TxError {
     #[error("unsupported BroadcastMode: {i:?}")]
     BroadcastMode { i: i32 },
 
+    #[error("unsupported OrderBy: {i:?}")]
+    OrderBy { i: i32 },
+
+    #[error("tx missing from chain response")]
+    MissingTx,
+
+    #[error("block missing from chain response")]
+    MissingBlock,
+
+    #[error("blockId missing from chain response")]
+    MissingBlockId,
+
+    #[error("tx failed after {} attempts, last error: {}", attempts.len(), attempts.last().map(ToString::to_string).unwrap_or_default())]
+    Retries { attempts: Vec<TxError> },
+
     #[error(transparent)]
     AccountError(#[from] AccountError),
 
     #[error(transparent)]
     ChainError(#[from] ChainError),
 }
+
+impl TxError {
+    /// Classifies a failed tx into a known cosmos-sdk or wasmd error.
+    /// For `TxError::Retries` the last attempt is classified.
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self {
+            TxError::ChainError(e) | TxError::AccountError(AccountError::ChainError(e)) => {
+                e.sdk_error()
+            }
+            TxError::Retries { attempts } => attempts.last().and_then(TxError::sdk_error),
+            _ => None,
+        }
+    }
+}
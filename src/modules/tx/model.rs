@@ -1,15 +1,31 @@
+use std::fmt;
+
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse as CosmosResponse;
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    GetBlockWithTxsResponse, GetTxResponse as ProtoGetTxResponse,
+    GetTxsEventResponse as ProtoGetTxsEventResponse, OrderBy as ProtoOrderBy, Tx as ProtoTx,
+};
+use cosmrs::proto::tendermint::types::{Block, BlockId};
 use cosmrs::proto::traits::MessageExt;
 use cosmrs::proto::{
     cosmos::tx::v1beta1::{BroadcastMode as ProtoBroadcastMode, TxRaw},
     traits::Message,
 };
-use cosmrs::tx::Raw;
+use cosmrs::tx::{Raw, SignerPublicKey};
+use cosmrs::Any;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::chain::error::ChainError;
+use crate::chain::fee::Fee;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse};
+use crate::clients::client::{CosmTome, CosmosClient};
+use crate::modules::auth::model::Address;
+use crate::signing_key::key::SigningKey;
 
 use super::error::TxError;
+use super::retry::TxRetry;
 
 /// `BroadcastMode::Block` is deprecated and removed from latest version of cosmos-sdk.
 /// `BroadcastMode` only contains the non-deprecated async broadcasting modes, starting from 2
@@ -108,3 +124,326 @@ impl From<Raw> for RawTx {
         RawTx(tx.into())
     }
 }
+
+/// Handle to a module tx that was broadcast without waiting for it to be committed in a block.
+///
+/// Returned from the `*_batch_async()` module apis. `wait()` resolves it into the same response
+/// the blocking api returns, eg. the new code ids for `wasm_store_batch_async()`.
+#[derive(Debug)]
+pub struct PendingTx<R, E> {
+    pub res: AsyncChainTxResponse,
+    decode: fn(ChainTxResponse) -> Result<R, E>,
+    retry: TxRetry,
+}
+
+impl<R, E: From<TxError>> PendingTx<R, E> {
+    pub(crate) fn new(
+        res: AsyncChainTxResponse,
+        decode: fn(ChainTxResponse) -> Result<R, E>,
+        retry: TxRetry,
+    ) -> Self {
+        Self { res, decode, retry }
+    }
+
+    pub fn tx_hash(&self) -> &str {
+        &self.res.tx_hash
+    }
+
+    /// Polls the chain until the tx has been committed, then decodes it into the module response
+    pub async fn wait<T: CosmosClient>(&self, cosm_tome: &CosmTome<T>) -> Result<R, E> {
+        let res = cosm_tome.tx_wait(&self.res.tx_hash).await?;
+
+        (self.decode)(res)
+    }
+
+    /// Like `wait()`, but a tx that ran out of gas in its block is re-signed with `key` and re-broadcast
+    /// according to `cosm_tome`'s `RetryPolicy`
+    pub async fn wait_with_retries<T: CosmosClient>(
+        mut self,
+        cosm_tome: &CosmTome<T>,
+        key: &SigningKey,
+    ) -> Result<R, E> {
+        loop {
+            match cosm_tome.tx_wait(&self.res.tx_hash).await {
+                Ok(res) => return (self.decode)(res),
+                Err(e) => {
+                    cosm_tome.tx_prepare_retry(&mut self.retry, e, false)?;
+                    self.res = cosm_tome
+                        .tx_broadcast_retrying(&mut self.retry, key)
+                        .await?;
+                }
+            }
+        }
+    }
+}
+
+/// Decoded cosmos-sdk transaction
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tx {
+    pub body: TxBody,
+    pub auth_info: AuthInfo,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl TryFrom<ProtoTx> for Tx {
+    type Error = TxError;
+
+    fn try_from(tx: ProtoTx) -> Result<Self, Self::Error> {
+        let tx = cosmrs::Tx::try_from(tx).map_err(ChainError::proto_decoding)?;
+
+        Ok(Self {
+            body: TxBody {
+                messages: tx.body.messages,
+                memo: tx.body.memo,
+                timeout_height: tx.body.timeout_height.value(),
+            },
+            auth_info: AuthInfo {
+                signer_infos: tx
+                    .auth_info
+                    .signer_infos
+                    .into_iter()
+                    .map(|s| SignerInfo {
+                        public_key: s.public_key,
+                        sequence: s.sequence,
+                    })
+                    .collect(),
+                fee: tx.auth_info.fee.try_into()?,
+            },
+            signatures: tx.signatures,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxBody {
+    /// Proto encoded msgs, use `Msg::from_any()` to decode them into cosm-tome requests
+    pub messages: Vec<Any>,
+
+    pub memo: String,
+
+    /// The block height after which this transaction will not be processed by the chain
+    pub timeout_height: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuthInfo {
+    pub signer_infos: Vec<SignerInfo>,
+    pub fee: Fee,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SignerInfo {
+    /// Only set on the signer's first tx, afterwards the chain uses the stored account pubkey
+    pub public_key: Option<SignerPublicKey>,
+    pub sequence: u64,
+}
+
+/// Transaction that has been committed in a block
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommittedTx {
+    pub tx: Option<Tx>,
+    pub res: ChainTxResponse,
+
+    /// Timestamp of the block the tx was committed in
+    pub timestamp: String,
+}
+
+impl CommittedTx {
+    pub(crate) fn new(tx: Option<ProtoTx>, res: CosmosResponse) -> Result<Self, TxError> {
+        Ok(Self {
+            tx: tx.map(TryInto::try_into).transpose()?,
+            timestamp: res.timestamp.clone(),
+            res: res.try_into()?,
+        })
+    }
+}
+
+impl AsRef<ChainTxResponse> for CommittedTx {
+    fn as_ref(&self) -> &ChainTxResponse {
+        &self.res
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetTxResponse {
+    pub tx: CommittedTx,
+}
+
+impl TryFrom<ProtoGetTxResponse> for GetTxResponse {
+    type Error = TxError;
+
+    fn try_from(res: ProtoGetTxResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            tx: CommittedTx::new(res.tx, res.tx_response.ok_or(TxError::MissingTx)?)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GetTxsEventResponse {
+    pub txs: Vec<CommittedTx>,
+    pub next: Option<PaginationResponse>,
+}
+
+impl TryFrom<ProtoGetTxsEventResponse> for GetTxsEventResponse {
+    type Error = TxError;
+
+    fn try_from(res: ProtoGetTxsEventResponse) -> Result<Self, Self::Error> {
+        let mut txs = res.txs.into_iter();
+
+        Ok(Self {
+            txs: res
+                .tx_responses
+                .into_iter()
+                .map(|r| CommittedTx::new(txs.next(), r))
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockWithTxsResponse {
+    pub id: BlockId,
+    pub block: Block,
+    pub txs: Vec<Tx>,
+    pub next: Option<PaginationResponse>,
+}
+
+impl TryFrom<GetBlockWithTxsResponse> for BlockWithTxsResponse {
+    type Error = TxError;
+
+    fn try_from(res: GetBlockWithTxsResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: res.block_id.ok_or(TxError::MissingBlockId)?,
+            block: res.block.ok_or(TxError::MissingBlock)?,
+            txs: res
+                .txs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+/// Sort order of txs returned from `tx_query_get_txs_event()`
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Eq,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[repr(i32)]
+pub enum OrderBy {
+    /// ORDER_BY_UNSPECIFIED the chain's default ordering (ascending by height)
+    #[default]
+    Unspecified = 0,
+    /// ORDER_BY_ASC oldest txs first
+    Asc = 1,
+    /// ORDER_BY_DESC newest txs first
+    Desc = 2,
+}
+
+impl TryFrom<i32> for OrderBy {
+    type Error = TxError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == OrderBy::Unspecified as i32 => Ok(OrderBy::Unspecified),
+            x if x == OrderBy::Asc as i32 => Ok(OrderBy::Asc),
+            x if x == OrderBy::Desc as i32 => Ok(OrderBy::Desc),
+            _ => Err(TxError::OrderBy { i: v }),
+        }
+    }
+}
+
+impl From<OrderBy> for ProtoOrderBy {
+    fn from(order: OrderBy) -> Self {
+        match order {
+            OrderBy::Unspecified => ProtoOrderBy::Unspecified,
+            OrderBy::Asc => ProtoOrderBy::Asc,
+            OrderBy::Desc => ProtoOrderBy::Desc,
+        }
+    }
+}
+
+impl From<ProtoOrderBy> for OrderBy {
+    fn from(order: ProtoOrderBy) -> Self {
+        match order {
+            ProtoOrderBy::Unspecified => OrderBy::Unspecified,
+            ProtoOrderBy::Asc => OrderBy::Asc,
+            ProtoOrderBy::Desc => OrderBy::Desc,
+        }
+    }
+}
+
+/// Builds the event conditions used to search for txs with `tx_query_get_txs_event()`.
+/// All conditions must match for a tx to be returned.
+///
+/// ```
+/// # use cosm_tome::modules::tx::model::EventQuery;
+/// let query = EventQuery::new()
+///     .message_action("/cosmos.bank.v1beta1.MsgSend")
+///     .eq("transfer.amount", "10uatom");
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct EventQuery {
+    events: Vec<String>,
+}
+
+impl EventQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match txs that emitted an `{event_type}.{attribute}` event attribute equal to `value`.
+    /// `key` is formatted as `{event_type}.{attribute}`, ie: "message.sender"
+    pub fn eq(mut self, key: impl AsRef<str>, value: impl fmt::Display) -> Self {
+        self.events.push(format!("{}={}", key.as_ref(), value));
+        self
+    }
+
+    /// Match txs signed by `sender`
+    pub fn message_sender(self, sender: &Address) -> Self {
+        self.eq("message.sender", sender)
+    }
+
+    /// Match txs containing a msg of `action` type, ie: "/cosmos.bank.v1beta1.MsgSend"
+    pub fn message_action(self, action: impl fmt::Display) -> Self {
+        self.eq("message.action", action)
+    }
+
+    /// Match txs emitted by the `module` cosmos module, ie: "bank"
+    pub fn message_module(self, module: impl fmt::Display) -> Self {
+        self.eq("message.module", module)
+    }
+
+    /// Match txs committed at block `height`
+    pub fn height(self, height: u64) -> Self {
+        self.eq("tx.height", height)
+    }
+
+    /// Match txs executing the cosmwasm contract at `address`
+    pub fn wasm_contract(self, address: &Address) -> Self {
+        self.eq("wasm._contract_address", address)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl From<EventQuery> for Vec<String> {
+    fn from(query: EventQuery) -> Self {
+        query.events
+    }
+}
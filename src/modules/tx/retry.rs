@@ -0,0 +1,141 @@
+use cosmrs::Any;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::{error::SdkError, request::TxOptions};
+use crate::modules::auth::model::Address;
+
+use super::error::TxError;
+use super::model::BroadcastMode;
+use super::sequence::{expected_sequence, is_sequence_mismatch};
+
+/// Re-signs and re-broadcasts module txs that failed for a reason a new attempt can fix:
+/// - out of gas: the tx is simulated again with `gas_adjustment_step` added to the gas adjustment
+/// - account sequence mismatch: the tx is signed again with the sequence the chain expects
+///
+/// Txs with a user supplied `TxOptions::fee` or `TxOptions::sequence` are not retried for the respective error.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RetryPolicy {
+    /// Max number of times a failed tx is re-signed and re-broadcast
+    pub max_retries: u32,
+
+    /// Added to `ChainConfig::gas_adjustment` each time the tx runs out of gas
+    pub gas_adjustment_step: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            gas_adjustment_step: 0.5,
+        }
+    }
+}
+
+/// Why a failed tx can be retried
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum RetryReason {
+    OutOfGas,
+    SequenceMismatch { expected: Option<u64> },
+}
+
+impl RetryReason {
+    pub(crate) fn of(e: &TxError) -> Option<Self> {
+        if is_sequence_mismatch(e) {
+            return Some(RetryReason::SequenceMismatch {
+                expected: expected_sequence(e),
+            });
+        }
+
+        match e.sdk_error() {
+            Some(SdkError::OutOfGas) => Some(RetryReason::OutOfGas),
+            _ => None,
+        }
+    }
+}
+
+/// Everything needed to sign a module tx again, along with its failed attempts so far
+#[derive(Debug)]
+pub(crate) struct TxRetry {
+    pub(crate) msgs: Vec<Any>,
+    pub(crate) sender_addr: Address,
+    pub(crate) tx_options: TxOptions,
+    pub(crate) mode: BroadcastMode,
+    pub(crate) gas_adjustment: f64,
+
+    /// true if the user set `TxOptions::sequence`, which is then never changed by a retry
+    pub(crate) user_sequence: bool,
+    pub(crate) attempts: Vec<TxError>,
+}
+
+impl TxRetry {
+    pub(crate) fn new(
+        msgs: Vec<Any>,
+        sender_addr: Address,
+        tx_options: &TxOptions,
+        mode: BroadcastMode,
+        gas_adjustment: f64,
+    ) -> Self {
+        Self {
+            msgs,
+            sender_addr,
+            user_sequence: tx_options.sequence.is_some(),
+            tx_options: tx_options.clone(),
+            mode,
+            gas_adjustment,
+            attempts: vec![],
+        }
+    }
+
+    /// The error to give up with: the only failure, or every attempt if the tx was retried
+    pub(crate) fn give_up(&mut self) -> TxError {
+        let mut attempts = std::mem::take(&mut self.attempts);
+
+        if attempts.len() == 1 {
+            attempts.pop().unwrap()
+        } else {
+            TxError::Retries { attempts }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chain::{
+        error::ChainError,
+        response::{ChainResponse, Code},
+    };
+    use crate::modules::tx::error::TxError;
+
+    use super::RetryReason;
+
+    fn sdk_error(code: u32, log: &str) -> TxError {
+        TxError::ChainError(ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(code),
+                data: None,
+                log: log.to_string(),
+                codespace: String::new(),
+            },
+        })
+    }
+
+    #[test]
+    fn test_retry_reason() {
+        assert_eq!(
+            RetryReason::of(&sdk_error(
+                11,
+                "out of gas in location: WritePerByte; gasWanted: 100000, gasUsed: 100537: out of gas"
+            )),
+            Some(RetryReason::OutOfGas)
+        );
+        assert_eq!(
+            RetryReason::of(&sdk_error(
+                32,
+                "account sequence mismatch, expected 8, got 7: incorrect account sequence"
+            )),
+            Some(RetryReason::SequenceMismatch { expected: Some(8) })
+        );
+        assert_eq!(RetryReason::of(&sdk_error(5, "insufficient funds")), None);
+        assert_eq!(RetryReason::of(&TxError::MissingTx), None);
+    }
+}
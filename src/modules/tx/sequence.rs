@@ -0,0 +1,153 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::chain::error::SdkError;
+use crate::modules::auth::model::Address;
+
+use super::error::TxError;
+
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct SequenceOptions {
+    /// Number of times a tx rejected with "account sequence mismatch" is re-signed and re-broadcast
+    pub max_retries: u32,
+}
+
+/// Hands out account sequences to concurrent `tx_sign()` calls from the same key.
+///
+/// The chain only bumps an account's sequence once its tx passes `CheckTx`, so txs signed before
+/// the previous one was broadcast would all get the same sequence from `auth_query_account()`.
+/// Instead, the manager caches the next sequence of every signer and increments it for each signed tx.
+///
+/// The cache is resynced with the chain whenever a broadcast fails, since the failed tx's sequence was never used.
+/// Clones share the same cache.
+#[derive(Clone, Debug, Default)]
+pub struct SequenceManager {
+    pub options: SequenceOptions,
+    next: Arc<Mutex<BTreeMap<Address, u64>>>,
+}
+
+impl SequenceManager {
+    pub fn new(options: SequenceOptions) -> Self {
+        Self {
+            options,
+            next: Arc::default(),
+        }
+    }
+
+    /// Reserves the sequence for the next tx signed by `address`.
+    /// `chain_sequence` is the account's sequence on chain, it is used instead of the cached one
+    /// when it's ahead, eg. because another process signed with the same key.
+    pub(crate) fn reserve(&self, address: &Address, chain_sequence: u64) -> u64 {
+        let mut next = self.next.lock().unwrap();
+
+        let entry = next.entry(address.clone()).or_insert(chain_sequence);
+        let sequence = (*entry).max(chain_sequence);
+        *entry = sequence + 1;
+
+        sequence
+    }
+
+    /// Gives back a `sequence` reserved for a tx that failed to sign, so it was never broadcast.
+    /// If later txs already reserved the sequences after it, the cache is resynced instead.
+    pub(crate) fn release(&self, address: &Address, sequence: u64) {
+        let mut next = self.next.lock().unwrap();
+
+        match next.get_mut(address) {
+            Some(entry) if *entry == sequence + 1 => *entry = sequence,
+            _ => {
+                next.remove(address);
+            }
+        }
+    }
+
+    /// Forgets the cached sequence of `address`, so its next tx uses the sequence queried from chain.
+    pub fn resync(&self, address: &Address) {
+        self.next.lock().unwrap().remove(address);
+    }
+
+    /// Resyncs the cached sequence after `address` failed to broadcast a tx.
+    /// Returns true if the tx failed because of an account sequence mismatch.
+    pub(crate) fn on_broadcast_error(&self, address: &Address, e: &TxError) -> bool {
+        match expected_sequence(e) {
+            Some(expected) => {
+                // the error tells us the sequence the chain expects, including txs still in the mempool
+                self.next.lock().unwrap().insert(address.clone(), expected);
+                true
+            }
+            None => {
+                self.resync(address);
+                is_sequence_mismatch(e)
+            }
+        }
+    }
+}
+
+pub(crate) fn is_sequence_mismatch(e: &TxError) -> bool {
+    matches!(e.sdk_error(), Some(SdkError::WrongSequence { .. }))
+}
+
+/// The sequence the chain expected, from an "account sequence mismatch, expected 5, got 3" error
+pub(crate) fn expected_sequence(e: &TxError) -> Option<u64> {
+    match e.sdk_error() {
+        Some(SdkError::WrongSequence { expected }) => expected,
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chain::{
+        error::ChainError,
+        response::{ChainResponse, Code},
+    };
+    use crate::modules::tx::error::TxError;
+
+    use super::{SequenceManager, SequenceOptions};
+
+    fn mismatch(log: &str) -> TxError {
+        TxError::ChainError(ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(32),
+                data: None,
+                log: log.to_string(),
+                codespace: String::new(),
+            },
+        })
+    }
+
+    #[test]
+    fn test_sequence_manager() {
+        let seqs = SequenceManager::new(SequenceOptions::default());
+        let addr = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+
+        // concurrent txs get increasing sequences while the chain still reports the old one
+        assert_eq!(seqs.reserve(&addr, 3), 3);
+        assert_eq!(seqs.reserve(&addr, 3), 4);
+        assert_eq!(seqs.reserve(&addr, 3), 5);
+
+        // chain moved ahead of the cache
+        assert_eq!(seqs.reserve(&addr, 9), 9);
+
+        let err =
+            mismatch("account sequence mismatch, expected 7, got 10: incorrect account sequence");
+        assert!(seqs.on_broadcast_error(&addr, &err));
+        assert_eq!(seqs.reserve(&addr, 6), 7);
+
+        let err = TxError::MissingTx;
+        assert!(!seqs.on_broadcast_error(&addr, &err));
+        assert_eq!(seqs.reserve(&addr, 6), 6);
+
+        // the last reserved sequence is handed out again once released
+        seqs.release(&addr, 6);
+        assert_eq!(seqs.reserve(&addr, 6), 6);
+
+        // a sequence released after later ones were reserved resyncs with the chain
+        assert_eq!(seqs.reserve(&addr, 6), 7);
+        seqs.release(&addr, 6);
+        assert_eq!(seqs.reserve(&addr, 6), 6);
+    }
+}
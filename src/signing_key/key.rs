@@ -3,6 +3,7 @@ use cosmrs::bip32::secp256k1::elliptic_curve::rand_core::OsRng;
 use cosmrs::crypto::{secp256k1, PublicKey};
 use cosmrs::tendermint::block::Height;
 use cosmrs::tx::{Body, SignDoc, SignerInfo};
+use cosmrs::Any;
 
 #[cfg(feature = "os_keyring")]
 use keyring::Entry;
@@ -57,6 +58,28 @@ impl SigningKey {
         account: Account,
         fee: Fee,
         cfg: &ChainConfig,
+    ) -> Result<RawTx, ChainError> {
+        let msgs = msgs
+            .into_iter()
+            .map(|m| m.into_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.sign_any(msgs, timeout_height, memo, account, fee, cfg)
+            .await
+    }
+
+    /// Signs msgs that are already encoded as `Any`
+    pub(crate) async fn sign_any(
+        &self,
+        msgs: Vec<Any>,
+        timeout_height: u64,
+        memo: &str,
+        account: Account,
+        fee: Fee,
+        cfg: &ChainConfig,
     ) -> Result<RawTx, ChainError> {
         let public_key = if account.pubkey.is_none() {
             Some(self.public_key().await?)
@@ -166,7 +189,7 @@ fn raw_bytes_to_signing_key(bytes: &[u8]) -> Result<secp256k1::SigningKey, Chain
 }
 
 fn build_sign_doc(
-    msgs: Vec<impl Msg>,
+    msgs: Vec<Any>,
     timeout_height: u64,
     memo: &str,
     account: &Account,
@@ -176,18 +199,9 @@ fn build_sign_doc(
 ) -> Result<SignDoc, ChainError> {
     let timeout: Height = timeout_height.try_into()?;
 
-    let tx = Body::new(
-        msgs.into_iter()
-            .map(|m| m.into_any())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| ChainError::ProtoEncoding {
-                message: e.to_string(),
-            })?,
-        memo,
-        timeout,
-    );
+    let tx = Body::new(msgs, memo, timeout);
 
-    // NOTE: if we are making requests in parallel with the same key, we need to serialize `account.sequence` to avoid errors
+    // NOTE: parallel requests with the same key need distinct `account.sequence`s, which `CosmTome::with_sequence_manager()` hands out
     let auth_info =
         SignerInfo::single_direct(public_key, account.sequence).auth_info(fee.try_into()?);
 